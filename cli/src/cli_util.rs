@@ -63,6 +63,7 @@ use jj_lib::config::ConfigNamePathBuf;
 use jj_lib::config::ConfigSource;
 use jj_lib::config::StackedConfig;
 use jj_lib::conflicts::ConflictMarkerStyle;
+use jj_lib::default_index::DefaultReadonlyIndex;
 use jj_lib::fileset;
 use jj_lib::fileset::FilesetDiagnostics;
 use jj_lib::fileset::FilesetExpression;
@@ -440,8 +441,32 @@ impl CommandHelper {
     pub fn workspace_helper_with_stats(
         &self,
         ui: &Ui,
+    ) -> Result<(WorkspaceCommandHelper, SnapshotStats), CommandError> {
+        self.workspace_helper_with_stats_impl(ui, true)
+    }
+
+    /// Loads workspace and repo, then snapshots the working copy if allowed,
+    /// but doesn't import refs from the colocated Git repo.
+    ///
+    /// The Git HEAD is still imported. Most commands should use
+    /// `workspace_helper()` instead.
+    #[instrument(skip(self, ui))]
+    pub fn workspace_helper_no_git_refs_import(
+        &self,
+        ui: &Ui,
+    ) -> Result<WorkspaceCommandHelper, CommandError> {
+        let (workspace_command, stats) = self.workspace_helper_with_stats_impl(ui, false)?;
+        print_snapshot_stats(ui, &stats, workspace_command.env().path_converter())?;
+        Ok(workspace_command)
+    }
+
+    fn workspace_helper_with_stats_impl(
+        &self,
+        ui: &Ui,
+        import_git_refs: bool,
     ) -> Result<(WorkspaceCommandHelper, SnapshotStats), CommandError> {
         let mut workspace_command = self.workspace_helper_no_snapshot(ui)?;
+        workspace_command.import_git_refs_on_snapshot = import_git_refs;
 
         let (workspace_command, stats) = match workspace_command.maybe_snapshot_impl(ui) {
             Ok(stats) => (workspace_command, stats),
@@ -1012,6 +1037,7 @@ pub struct WorkspaceCommandHelper {
     op_summary_template_text: String,
     may_update_working_copy: bool,
     working_copy_shared_with_git: bool,
+    import_git_refs_on_snapshot: bool,
 }
 
 enum SnapshotWorkingCopyError {
@@ -1051,6 +1077,21 @@ impl WorkspaceCommandHelper {
             loaded_at_head && !env.command.global_args().ignore_working_copy;
         let working_copy_shared_with_git =
             crate::git_util::is_colocated_git_workspace(&workspace, &repo);
+        let reindexed_index = repo
+            .readonly_index()
+            .downcast_ref::<DefaultReadonlyIndex>()
+            .filter(|index| index.is_freshly_reindexed());
+        if let Some(index) = reindexed_index
+            && settings.get_bool("ui.warn-on-reindex")?
+        {
+            writeln!(
+                ui.warning_default(),
+                "The index was rebuilt at operation {} because {} index files were missing or \
+                 corrupt",
+                short_operation_hash(repo.op_id()),
+                index.num_reindexed_segments()
+            )?;
+        }
 
         let helper = Self {
             workspace,
@@ -1060,6 +1101,7 @@ impl WorkspaceCommandHelper {
             op_summary_template_text,
             may_update_working_copy,
             working_copy_shared_with_git,
+            import_git_refs_on_snapshot: true,
         };
         // Parse commit_summary template early to report error before starting
         // mutable operation.
@@ -1111,7 +1153,7 @@ impl WorkspaceCommandHelper {
 
         // import_git_refs() can rebase the working-copy commit.
         #[cfg(feature = "git")]
-        if self.working_copy_shared_with_git {
+        if self.working_copy_shared_with_git && self.import_git_refs_on_snapshot {
             self.import_git_refs(ui).map_err(snapshot_command_error)?;
         }
         Ok(stats)
@@ -1200,7 +1242,7 @@ impl WorkspaceCommandHelper {
         let git_settings = self.settings().git_settings()?;
         let mut tx = self.start_transaction();
         let stats = jj_lib::git::import_refs(tx.repo_mut(), &git_settings)?;
-        crate::git_util::print_git_import_stats(ui, tx.repo(), &stats, false)?;
+        crate::git_util::print_git_import_stats(ui, tx.repo(), &stats, false, false)?;
         if !tx.repo().has_changes() {
             return Ok(());
         }
@@ -2029,7 +2071,10 @@ See https://jj-vcs.github.io/jj/latest/working-copy/#stale-working-copy \
     }
 
     pub fn start_transaction(&mut self) -> WorkspaceCommandTransaction<'_> {
-        let tx = start_repo_transaction(self.repo(), self.env.command.string_args());
+        let mut tx = start_repo_transaction(self.repo(), self.env.command.string_args());
+        for (key, value) in &self.env.command.global_args().op_tag {
+            tx.set_tag(key.clone(), value.clone());
+        }
         let id_prefix_context = mem::take(&mut self.user_repo.id_prefix_context);
         WorkspaceCommandTransaction {
             helper: self,
@@ -2423,6 +2468,11 @@ impl WorkspaceCommandTransaction<'_> {
         self.tx.repo_mut()
     }
 
+    /// Attaches the `key: value` pair to the operation metadata.
+    pub fn set_tag(&mut self, key: String, value: String) {
+        self.tx.set_tag(key, value);
+    }
+
     pub fn check_out(&mut self, commit: &Commit) -> Result<Commit, CheckOutCommitError> {
         let name = self.helper.workspace_name().to_owned();
         self.id_prefix_context.take(); // invalidate
@@ -2585,6 +2635,17 @@ pub fn start_repo_transaction(repo: &Arc<ReadonlyRepo>, string_args: &[String])
     tx
 }
 
+/// Parses a `KEY=VALUE` operation tag.
+pub fn parse_op_tag(text: &str) -> Result<(String, String), &'static str> {
+    let (key, value) = text
+        .split_once('=')
+        .ok_or("Operation tag must have the form KEY=VALUE")?;
+    if key.is_empty() {
+        return Err("Operation tag key must not be empty");
+    }
+    Ok((key.to_owned(), value.to_owned()))
+}
+
 fn update_stale_working_copy(
     mut locked_ws: LockedWorkspace,
     op_id: OperationId,
@@ -3266,6 +3327,13 @@ pub struct GlobalArgs {
         add = ArgValueCandidates::new(complete::operations),
     )]
     pub at_operation: Option<String>,
+    /// Attach a tag to the operation created by this command (can be repeated)
+    ///
+    /// Tags are stored in the operation metadata and can be used to find the
+    /// operation later, e.g. `jj --op-tag ci-run=1234 new` followed by `jj op
+    /// log --tag ci-run=1234`.
+    #[arg(long, value_name = "KEY=VALUE", global = true, value_parser = parse_op_tag)]
+    pub op_tag: Vec<(String, String)>,
     /// Enable debug logging
     #[arg(long, global = true)]
     pub debug: bool,
@@ -12,17 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use clap_complete::ArgValueCandidates;
 use clap_complete::ArgValueCompleter;
 use itertools::Itertools as _;
 use jj_lib::object_id::ObjectId as _;
 use jj_lib::op_store::RefTarget;
 use jj_lib::ref_name::RefNameBuf;
+use jj_lib::ref_name::RemoteName;
+use jj_lib::ref_name::RemoteNameBuf;
+use jj_lib::repo::Repo as _;
 
 use crate::cli_util::CommandHelper;
 use crate::cli_util::RevisionArg;
+use crate::cli_util::WorkspaceCommandHelper;
 use crate::cli_util::has_tracked_remote_bookmarks;
 use crate::command_error::CommandError;
+use crate::command_error::user_error;
 use crate::command_error::user_error_with_hint;
+#[cfg(feature = "git")]
+use crate::commands::git::GitPushArgs;
+#[cfg(feature = "git")]
+use crate::commands::git::cmd_git_push;
+#[cfg(feature = "git")]
+use crate::commands::git::get_single_remote;
 use crate::complete;
 use crate::revset_util;
 use crate::ui::Ui;
@@ -40,6 +52,31 @@ pub struct BookmarkCreateArgs {
     )]
     revision: RevisionArg,
 
+    /// Create the bookmarks at the remote bookmarks of the same names on this
+    /// remote, and track them
+    #[arg(
+        long,
+        value_name = "REMOTE",
+        conflicts_with = "revision",
+        add = ArgValueCandidates::new(complete::git_remotes),
+    )]
+    at_remote: Option<RemoteNameBuf>,
+
+    /// Push the created bookmarks to this remote
+    ///
+    /// If no remote is given, the bookmarks are pushed to the only existing
+    /// remote.
+    #[cfg(feature = "git")]
+    #[arg(
+        long,
+        value_name = "REMOTE",
+        num_args = 0..=1,
+        require_equals = true,
+        conflicts_with = "at_remote",
+        add = ArgValueCandidates::new(complete::git_remotes),
+    )]
+    push: Option<Option<RemoteNameBuf>>,
+
     /// The bookmarks to create
     #[arg(required = true, value_parser = revset_util::parse_bookmark_name)]
     names: Vec<RefNameBuf>,
@@ -51,7 +88,6 @@ pub fn cmd_bookmark_create(
     args: &BookmarkCreateArgs,
 ) -> Result<(), CommandError> {
     let mut workspace_command = command.workspace_helper(ui)?;
-    let target_commit = workspace_command.resolve_single_rev(ui, &args.revision)?;
     let repo = workspace_command.repo().as_ref();
     let view = repo.view();
     let bookmark_names = &args.names;
@@ -76,6 +112,27 @@ pub fn cmd_bookmark_create(
             ));
         }
     }
+    if let Some(remote) = &args.at_remote {
+        return create_bookmarks_at_remote(ui, workspace_command, bookmark_names, remote);
+    }
+    // Pick the remote before creating the bookmarks so nothing is left behind
+    // if it can't be determined.
+    #[cfg(feature = "git")]
+    let push_remote = match &args.push {
+        Some(Some(remote)) => Some(remote.clone()),
+        Some(None) => Some(
+            get_single_remote(workspace_command.repo().store())?.ok_or_else(|| {
+                user_error_with_hint(
+                    "Cannot determine which remote to push the bookmarks to",
+                    "Use `--push=REMOTE` to specify the remote.",
+                )
+            })?,
+        ),
+        None => None,
+    };
+
+    let target_commit = workspace_command.resolve_single_rev(ui, &args.revision)?;
+    let repo = workspace_command.repo().as_ref();
     if target_commit.is_discardable(repo)? {
         writeln!(ui.warning_default(), "Target revision is empty.")?;
     }
@@ -103,5 +160,54 @@ pub fn cmd_bookmark_create(
             id = target_commit.id().hex()
         ),
     )?;
+    #[cfg(feature = "git")]
+    if let Some(remote) = push_remote {
+        let push_args = GitPushArgs::for_new_bookmarks(remote, bookmark_names);
+        cmd_git_push(ui, command, &push_args)?;
+    }
+    Ok(())
+}
+
+/// Creates the local bookmarks by tracking the remote bookmarks of the same
+/// names.
+fn create_bookmarks_at_remote(
+    ui: &mut Ui,
+    mut workspace_command: WorkspaceCommandHelper,
+    bookmark_names: &[RefNameBuf],
+    remote: &RemoteName,
+) -> Result<(), CommandError> {
+    let view = workspace_command.repo().view();
+    for name in bookmark_names {
+        if view
+            .get_remote_bookmark(name.to_remote_symbol(remote))
+            .is_absent()
+        {
+            return Err(user_error(format!(
+                "No bookmark {name} found on remote {remote}",
+                name = name.as_symbol(),
+                remote = remote.as_symbol()
+            )));
+        }
+    }
+
+    let mut tx = workspace_command.start_transaction();
+    for name in bookmark_names {
+        tx.repo_mut()
+            .track_remote_bookmark(name.to_remote_symbol(remote))?;
+    }
+    writeln!(
+        ui.status(),
+        "Created {} bookmarks tracking remote {}",
+        bookmark_names.len(),
+        remote.as_symbol()
+    )?;
+    tx.finish(
+        ui,
+        format!(
+            "create bookmark {names} tracking remote {remote}",
+            names = bookmark_names.iter().map(|n| n.as_symbol()).join(", "),
+            remote = remote.as_symbol()
+        ),
+    )?;
     Ok(())
 }
@@ -12,28 +12,41 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use clap_complete::ArgValueCandidates;
+use clap_complete::ArgValueCompleter;
 use itertools::Itertools as _;
 use jj_lib::op_store::RefTarget;
+use jj_lib::revset::RevsetExpression;
+use jj_lib::str_util::StringExpression;
 use jj_lib::str_util::StringPattern;
 
 use super::find_local_bookmarks;
+use super::write_bookmark_descriptions;
 use crate::cli_util::CommandHelper;
+use crate::cli_util::RevisionArg;
 use crate::command_error::CommandError;
 use crate::complete;
 use crate::ui::Ui;
 
-/// Delete an existing bookmark and propagate the deletion to remotes on the
-/// next push
-///
-/// Revisions referred to by the deleted bookmarks are not abandoned. To delete
-/// revisions as well as bookmarks, use `jj abandon`. For example, `jj abandon
-/// main..<bookmark>` will abandon revisions belonging to the `<bookmark>`
-/// branch (relative to the `main` branch.)
-///
-/// If you don't want the deletion of the local bookmark to propagate to any
-/// tracked remote bookmarks, use `jj bookmark forget` instead.
+// Using an explicit `doc` attribute prevents rustfmt from rewrapping the
+// paragraphs, which are rendered verbatim to keep the example code block.
+#[doc = r#"Delete an existing bookmark and propagate the deletion to remotes on the next push
+
+Revisions referred to by the deleted bookmarks are not abandoned. To delete revisions as well as bookmarks, use `jj abandon`. For example, `jj abandon main..<bookmark>` will abandon revisions belonging to the `<bookmark>` branch (relative to the `main` branch.)
+
+If you don't want the deletion of the local bookmark to propagate to any tracked remote bookmarks, use `jj bookmark forget` instead.
+
+Example: delete all bookmarks pointing to revisions already merged into trunk
+
+```shell
+$ jj bookmark delete -r '::trunk() & bookmarks()'
+```
+"#]
 #[derive(clap::Args, Clone, Debug)]
+#[command(group(clap::ArgGroup::new("source").required(true)))]
+#[command(verbatim_doc_comment)]
 pub struct BookmarkDeleteArgs {
     /// The bookmarks to delete
     ///
@@ -43,11 +56,20 @@ pub struct BookmarkDeleteArgs {
     /// [wildcard pattern]:
     ///     https://jj-vcs.github.io/jj/latest/revsets/#string-patterns
     #[arg(
-        required = true,
+        group = "source",
         value_parser = StringPattern::parse,
         add = ArgValueCandidates::new(complete::local_bookmarks),
     )]
     names: Vec<StringPattern>,
+
+    /// Delete bookmarks whose local targets are in the given revisions
+    #[arg(
+        long, short,
+        group = "source",
+        value_name = "REVSETS",
+        add = ArgValueCompleter::new(complete::revset_expression_all),
+    )]
+    revisions: Vec<RevisionArg>,
 }
 
 pub fn cmd_bookmark_delete(
@@ -57,12 +79,41 @@ pub fn cmd_bookmark_delete(
 ) -> Result<(), CommandError> {
     let mut workspace_command = command.workspace_helper(ui)?;
     let repo = workspace_command.repo().clone();
-    let matched_bookmarks = find_local_bookmarks(repo.view(), &args.names)?;
+    let matched_bookmarks = if !args.revisions.is_empty() {
+        // Intersects with the set of local bookmark targets to minimize the
+        // lookup space.
+        let mut expression = workspace_command.parse_union_revsets(ui, &args.revisions)?;
+        expression.intersect_with(&RevsetExpression::bookmarks(StringExpression::all()));
+        let filtered_targets: HashSet<_> = expression.evaluate_to_commit_ids()?.try_collect()?;
+        let bookmarks = repo
+            .view()
+            .local_bookmarks()
+            .filter(|(_, target)| target.added_ids().any(|id| filtered_targets.contains(id)))
+            .collect_vec();
+        if bookmarks.is_empty() {
+            writeln!(
+                ui.warning_default(),
+                "No bookmarks point to the specified revisions."
+            )?;
+            writeln!(ui.status(), "Nothing changed.")?;
+            return Ok(());
+        }
+        bookmarks
+    } else {
+        find_local_bookmarks(repo.view(), &args.names)?
+    };
     let mut tx = workspace_command.start_transaction();
     for (name, _) in &matched_bookmarks {
         tx.repo_mut()
             .set_local_bookmark_target(name, RefTarget::absent());
     }
+    if !args.revisions.is_empty()
+        && let Some(mut formatter) = ui.status_formatter()
+    {
+        for (name, _) in &matched_bookmarks {
+            writeln!(formatter, "Deleting bookmark {}", name.as_symbol())?;
+        }
+    }
     writeln!(
         ui.status(),
         "Deleted {} bookmarks.",
@@ -78,5 +129,10 @@ pub fn cmd_bookmark_delete(
                 .join(", ")
         ),
     )?;
+    write_bookmark_descriptions(
+        &workspace_command,
+        matched_bookmarks.iter().map(|(name, _)| *name),
+        None,
+    )?;
     Ok(())
 }
@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use clap_complete::ArgValueCandidates;
 use itertools::Itertools as _;
 use jj_lib::op_store::LocalRemoteRefTarget;
 use jj_lib::op_store::RefTarget;
 use jj_lib::op_store::RemoteRef;
 use jj_lib::ref_name::RefName;
+use jj_lib::ref_name::RemoteName;
 use jj_lib::repo::Repo as _;
 use jj_lib::str_util::StringPattern;
 use jj_lib::view::View;
@@ -26,6 +29,8 @@ use super::find_bookmarks_with;
 use crate::cli_util::CommandHelper;
 use crate::cli_util::default_ignored_remote_name;
 use crate::command_error::CommandError;
+use crate::command_error::user_error;
+use crate::command_error::user_error_with_hint;
 use crate::complete;
 use crate::ui::Ui;
 
@@ -45,6 +50,13 @@ pub struct BookmarkForgetArgs {
     /// forgotten.
     #[arg(long)]
     include_remotes: bool,
+    /// Forget the bookmarks without asking for confirmation
+    ///
+    /// With `--include-remotes`, confirmation is required if more local and
+    /// remote bookmarks than `ui.bookmark-forget-confirm-threshold` would be
+    /// forgotten.
+    #[arg(long)]
+    yes: bool,
     /// The bookmarks to forget
     ///
     /// By default, the specified name matches exactly. Use `glob:` prefix to
@@ -69,6 +81,20 @@ pub fn cmd_bookmark_forget(
     let repo = workspace_command.repo().clone();
     let ignored_remote = default_ignored_remote_name(repo.store());
     let matched_bookmarks = find_forgettable_bookmarks(repo.view(), &args.names)?;
+    if args.include_remotes {
+        print_forget_summary(ui, &matched_bookmarks)?;
+        let num_forgotten = matched_bookmarks.len()
+            + matched_bookmarks
+                .iter()
+                .map(|(_, bookmark_target)| bookmark_target.remote_refs.len())
+                .sum::<usize>();
+        let threshold: usize = workspace_command
+            .settings()
+            .get("ui.bookmark-forget-confirm-threshold")?;
+        if !args.yes && num_forgotten > threshold {
+            confirm_forget(ui, num_forgotten)?;
+        }
+    }
     let mut tx = workspace_command.start_transaction();
     let mut forgotten_remote: usize = 0;
     for (name, bookmark_target) in &matched_bookmarks {
@@ -107,6 +133,55 @@ pub fn cmd_bookmark_forget(
     Ok(())
 }
 
+fn print_forget_summary(
+    ui: &Ui,
+    matched_bookmarks: &[(&RefName, LocalRemoteRefTarget<'_>)],
+) -> Result<(), CommandError> {
+    let Some(mut formatter) = ui.status_formatter() else {
+        return Ok(());
+    };
+    let local_names = matched_bookmarks
+        .iter()
+        .filter(|(_, bookmark_target)| bookmark_target.local_target.is_present())
+        .map(|(name, _)| name.as_symbol())
+        .collect_vec();
+    if !local_names.is_empty() {
+        writeln!(
+            formatter,
+            "Local bookmarks to forget: {}",
+            local_names.iter().join(", ")
+        )?;
+    }
+    let mut remote_names: BTreeMap<&RemoteName, Vec<&RefName>> = BTreeMap::new();
+    for (name, bookmark_target) in matched_bookmarks {
+        for (remote, _) in &bookmark_target.remote_refs {
+            remote_names.entry(*remote).or_default().push(*name);
+        }
+    }
+    for (remote, names) in &remote_names {
+        writeln!(
+            formatter,
+            "Remote bookmarks to forget on {}: {}",
+            remote.as_symbol(),
+            names.iter().map(|name| name.as_symbol()).join(", ")
+        )?;
+    }
+    Ok(())
+}
+
+fn confirm_forget(ui: &Ui, num_forgotten: usize) -> Result<(), CommandError> {
+    if !Ui::can_prompt() {
+        return Err(user_error_with_hint(
+            format!("Refusing to forget {num_forgotten} local and remote bookmarks"),
+            "Use `--yes` to forget them without confirmation.",
+        ));
+    }
+    if !ui.prompt_yes_no("Forget these bookmarks?", Some(false))? {
+        return Err(user_error("Aborted: no bookmarks were forgotten"));
+    }
+    Ok(())
+}
+
 fn find_forgettable_bookmarks<'a>(
     view: &'a View,
     name_patterns: &[StringPattern],
@@ -15,6 +15,8 @@
 use std::cmp;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::fs;
+use std::io::Write as _;
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -24,8 +26,10 @@ use itertools::Itertools as _;
 use jj_lib::backend;
 use jj_lib::backend::CommitId;
 use jj_lib::config::ConfigValue;
+use jj_lib::object_id::ObjectId as _;
 use jj_lib::ref_name::RefName;
-use jj_lib::repo::Repo as _;
+use jj_lib::repo::Repo;
+use jj_lib::revset;
 use jj_lib::revset::RevsetExpression;
 use jj_lib::str_util::StringExpression;
 use jj_lib::str_util::StringPattern;
@@ -33,7 +37,10 @@ use jj_lib::str_util::StringPattern;
 use crate::cli_util::CommandHelper;
 use crate::cli_util::RevisionArg;
 use crate::cli_util::default_ignored_remote_name;
+use crate::cli_util::short_commit_hash;
 use crate::command_error::CommandError;
+use crate::command_error::user_error;
+use crate::command_error::user_error_with_message;
 use crate::commit_templater::CommitRef;
 use crate::complete;
 use crate::templater::TemplateRenderer;
@@ -47,6 +54,10 @@ use crate::ui::Ui;
 /// revisions are preceded by a "-" and new target revisions are preceded by a
 /// "+".
 ///
+/// If the output is colored, or if `--mark-working-copy` is specified, local
+/// bookmarks pointing to the working-copy commit are marked with "@", and the
+/// ones pointing to its parent are marked with "@-".
+///
 /// See [`jj help -k bookmarks`] for more information.
 ///
 /// [`jj help -k bookmarks`]:
@@ -84,9 +95,39 @@ pub struct BookmarkListArgs {
     tracked: bool,
 
     /// Show conflicted bookmarks only
-    #[arg(long, short, conflicts_with_all = ["all_remotes"])]
+    ///
+    /// Combined with `--all-remotes`, bookmarks whose remote bookmarks are
+    /// conflicted are shown as well.
+    #[arg(long, short, visible_alias = "conflicted-only")]
     conflicted: bool,
 
+    /// Exit with an error if any conflicted bookmarks are listed
+    #[arg(long, requires = "conflicted")]
+    exit_code: bool,
+
+    /// Suggest commands to resolve the listed conflicted bookmarks
+    ///
+    /// For each conflicted local bookmark, a `jj bookmark set` command is
+    /// suggested for each of its targets.
+    #[arg(long)]
+    merge_conflicts: bool,
+
+    /// Mark bookmarks pointing to the working-copy commit or its parent even
+    /// if the output isn't colored
+    #[arg(long)]
+    mark_working_copy: bool,
+
+    /// Show only tracked remote bookmarks whose targets differ from the local
+    /// bookmarks, along with the number of commits they are ahead or behind
+    ///
+    /// Bookmarks that are in sync with their remotes are omitted, and the
+    /// target commits aren't printed.
+    #[arg(
+        long,
+        conflicts_with_all = ["all_remotes", "template", "template_file", "format"]
+    )]
+    ahead_behind_only: bool,
+
     /// Show bookmarks whose local name matches
     ///
     /// By default, the specified name matches exactly. Use `glob:` prefix to
@@ -118,15 +159,55 @@ pub struct BookmarkListArgs {
     #[arg(long, short = 'T', add = ArgValueCandidates::new(complete::template_aliases))]
     template: Option<String>,
 
+    /// Render each bookmark using the template read from the given file
+    ///
+    /// The file content is interpreted the same way as the `-T` argument.
+    #[arg(
+        long,
+        value_name = "PATH",
+        value_hint = clap::ValueHint::FilePath,
+        conflicts_with_all = ["template", "format"]
+    )]
+    template_file: Option<String>,
+
+    /// Output format
+    ///
+    /// With `json`, one JSON object is printed per line for each bookmark,
+    /// including its target commit ids and remote bookmarks.
+    #[arg(long, value_enum, default_value_t, conflicts_with = "template")]
+    format: ListFormat,
+
     /// Sort bookmarks based on the given key (or multiple keys)
     ///
     /// Suffix the key with `-` to sort in descending order of the value (e.g.
-    /// `--sort name-`). Note that when using multiple keys, the first key is
-    /// the most significant.
+    /// `--sort name-`). A `-` prefix is also accepted (e.g.
+    /// `--sort=-committer-date`). Note that when using multiple keys, the first
+    /// key is the most significant.
+    ///
+    /// Commit-based keys of a conflicted bookmark are taken from its most
+    /// recently committed target.
     ///
     /// This defaults to the `ui.bookmark-list-sort-keys` setting.
     #[arg(long, value_name = "SORT_KEY", value_enum, value_delimiter = ',')]
     sort: Vec<SortKey>,
+
+    /// Print only the number of listed bookmarks
+    ///
+    /// Local and remote bookmarks are counted separately. Unlike the normal
+    /// listing, an exact name pattern which doesn't match any bookmark is an
+    /// error.
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "template",
+            "template_file",
+            "format",
+            "ahead_behind_only",
+            "exit_code",
+            "merge_conflicts",
+        ],
+    )]
+    count: bool,
 }
 
 pub fn cmd_bookmark_list(
@@ -142,6 +223,16 @@ pub fn cmd_bookmark_list(
     let bookmark_names_to_list = if args.names.is_some() || args.revisions.is_some() {
         let mut bookmark_names: HashSet<&RefName> = HashSet::new();
         if let Some(patterns) = &args.names {
+            if args.count
+                && let Some(pattern) = patterns.iter().find(|pattern| {
+                    pattern.is_exact()
+                        && !view
+                            .bookmarks()
+                            .any(|(name, _)| pattern.is_match(name.as_str()))
+                })
+            {
+                return Err(user_error(format!("No such bookmark: {pattern}")));
+            }
             bookmark_names.extend(
                 view.bookmarks()
                     .filter(|(name, _)| {
@@ -173,26 +264,19 @@ pub fn cmd_bookmark_list(
         None
     };
 
-    let template: TemplateRenderer<Rc<CommitRef>> = {
-        let language = workspace_command.commit_template_language();
-        let text = match &args.template {
-            Some(value) => value.to_owned(),
-            None => workspace_command
-                .settings()
-                .get("templates.bookmark_list")?,
-        };
-        workspace_command
-            .parse_template(ui, &language, &text)?
-            .labeled(["bookmark_list"])
-    };
-
     let ignored_tracked_remote = default_ignored_remote_name(repo.store());
     let mut bookmark_list_items: Vec<RefListItem> = Vec::new();
     let bookmarks_to_list = view.bookmarks().filter(|(name, target)| {
         bookmark_names_to_list
             .as_ref()
             .is_none_or(|bookmark_names| bookmark_names.contains(name))
-            && (!args.conflicted || target.local_target.has_conflict())
+            && (!args.conflicted
+                || target.local_target.has_conflict()
+                || args.all_remotes
+                    && target
+                        .remote_refs
+                        .iter()
+                        .any(|(_, remote_ref)| remote_ref.target.has_conflict()))
     });
     let mut any_conflicts = false;
     for (name, bookmark_target) in bookmarks_to_list {
@@ -218,8 +302,15 @@ pub fn cmd_bookmark_list(
         } else if !args.all_remotes && args.remotes.is_none() {
             tracked_remote_refs.retain(|&(_, remote_ref)| remote_ref.target != *local_target);
         }
+        if args.ahead_behind_only {
+            tracked_remote_refs.retain(|&(_, remote_ref)| {
+                local_target.is_present()
+                    && remote_ref.target.is_present()
+                    && remote_ref.target != *local_target
+            });
+        }
 
-        let include_local_only = !args.tracked && args.remotes.is_none();
+        let include_local_only = !args.tracked && args.remotes.is_none() && !args.ahead_behind_only;
         if include_local_only && local_target.is_present() || !tracked_remote_refs.is_empty() {
             let primary = CommitRef::local(
                 name,
@@ -235,7 +326,8 @@ pub fn cmd_bookmark_list(
             bookmark_list_items.push(RefListItem { primary, tracked });
         }
 
-        if !args.tracked && (args.all_remotes || args.remotes.is_some()) {
+        if !args.tracked && !args.ahead_behind_only && (args.all_remotes || args.remotes.is_some())
+        {
             bookmark_list_items.extend(untracked_remote_refs.iter().map(
                 |&(remote, remote_ref)| RefListItem {
                     primary: CommitRef::remote_only(name, remote, remote_ref.target.clone()),
@@ -245,6 +337,15 @@ pub fn cmd_bookmark_list(
         }
     }
 
+    if args.count {
+        let count: usize = bookmark_list_items
+            .iter()
+            .map(|item| 1 + item.tracked.len())
+            .sum();
+        writeln!(ui.stdout(), "{count}")?;
+        return Ok(());
+    }
+
     let sort_keys = if args.sort.is_empty() {
         workspace_command
             .settings()
@@ -257,7 +358,8 @@ pub fn cmd_bookmark_list(
     if sort_keys.iter().any(|key| key.is_commit_dependant()) {
         commits = bookmark_list_items
             .iter()
-            .filter_map(|item| item.primary.target().added_ids().next())
+            .flat_map(|item| item.primary.target().added_ids())
+            .unique()
             .map(|commit_id| {
                 store
                     .get_commit(commit_id)
@@ -268,14 +370,54 @@ pub fn cmd_bookmark_list(
     sort(&mut bookmark_list_items, &sort_keys, &commits);
 
     ui.request_pager();
-    let mut formatter = ui.stdout_formatter();
-    bookmark_list_items
-        .iter()
-        .flat_map(|item| itertools::chain([&item.primary], &item.tracked))
-        .try_for_each(|commit_ref| template.format(commit_ref, formatter.as_mut()))?;
-    drop(formatter);
+    match args.format {
+        ListFormat::Text => {
+            let template: TemplateRenderer<Rc<CommitRef>> = {
+                let mut language = workspace_command.commit_template_language();
+                language.set_mark_working_copy(args.mark_working_copy || ui.color());
+                let text = match (&args.template, &args.template_file) {
+                    (Some(value), _) => value.to_owned(),
+                    (None, Some(file_path)) => fs::read_to_string(command.cwd().join(file_path))
+                        .map_err(|err| {
+                            user_error_with_message(format!("Failed to read {file_path}"), err)
+                        })?,
+                    (None, None) if args.ahead_behind_only => AHEAD_BEHIND_TEMPLATE.to_owned(),
+                    (None, None) => workspace_command
+                        .settings()
+                        .get("templates.bookmark_list")?,
+                };
+                workspace_command
+                    .parse_template(ui, &language, &text)
+                    .map_err(|err| match &args.template_file {
+                        Some(file_path) => {
+                            err.hinted(format!("The template was read from {file_path}"))
+                        }
+                        None => err,
+                    })?
+                    .labeled(["bookmark_list"])
+            };
+            let mut formatter = ui.stdout_formatter();
+            bookmark_list_items
+                .iter()
+                .flat_map(|item| {
+                    let primary = (!args.ahead_behind_only).then_some(&item.primary);
+                    itertools::chain(primary, &item.tracked)
+                })
+                .try_for_each(|commit_ref| template.format(commit_ref, formatter.as_mut()))?;
+        }
+        ListFormat::Json => {
+            let json_bookmarks = to_json_bookmarks(repo.as_ref(), &bookmark_list_items)?;
+            let mut stdout = ui.stdout();
+            for bookmark in &json_bookmarks {
+                let line = serde_json::to_string(bookmark).expect("serialization shouldn't fail");
+                writeln!(stdout, "{line}")?;
+            }
+        }
+    }
 
-    if any_conflicts {
+    if any_conflicts && args.merge_conflicts {
+        write_conflict_resolutions(ui, &bookmark_list_items)?;
+    } else if any_conflicts {
         writeln!(
             ui.hint_default(),
             "Some bookmarks have conflicts. Use `jj bookmark set <name> -r <rev>` to resolve."
@@ -316,9 +458,159 @@ pub fn cmd_bookmark_list(
         }
     }
 
+    if args.exit_code {
+        let num_conflicted = bookmark_list_items
+            .iter()
+            .map(|item| item.primary.name())
+            .unique()
+            .count();
+        if num_conflicted > 0 {
+            return Err(user_error(format!(
+                "Found {num_conflicted} conflicted bookmarks"
+            )));
+        }
+    }
+
     Ok(())
 }
 
+/// Suggests a `jj bookmark set` command for each target of the listed
+/// conflicted local bookmarks.
+fn write_conflict_resolutions(ui: &Ui, items: &[RefListItem]) -> Result<(), CommandError> {
+    let conflicted_refs = items
+        .iter()
+        .map(|item| &item.primary)
+        .filter(|commit_ref| commit_ref.is_local() && commit_ref.target().has_conflict());
+    for commit_ref in conflicted_refs {
+        let name = RefName::new(commit_ref.name()).as_symbol();
+        writeln!(
+            ui.hint_default(),
+            "Bookmark {name} is conflicted. To resolve it, run one of:"
+        )?;
+        for id in commit_ref.target().added_ids() {
+            writeln!(
+                ui.hint_no_heading(),
+                "  jj bookmark set {name} -r {hash}",
+                hash = short_commit_hash(id)
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Template used by `--ahead-behind-only` to render tracked remote bookmarks.
+const AHEAD_BEHIND_TEMPLATE: &str = r#"
+label("bookmark", name ++ "@" ++ remote)
+  ++ " " ++ format_tracked_remote_ref_distances(self) ++ "\n"
+"#;
+
+/// Output format for the `--format` argument option.
+#[derive(Copy, Clone, Debug, Default, ValueEnum)]
+enum ListFormat {
+    /// Render each bookmark with the template
+    #[default]
+    Text,
+    /// Print each bookmark as a JSON object
+    Json,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct JsonBookmark<'a> {
+    name: &'a str,
+    present: bool,
+    conflicted: bool,
+    /// Full ids of the target commits.
+    targets: Vec<String>,
+    remotes: Vec<JsonRemoteBookmark<'a>>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct JsonRemoteBookmark<'a> {
+    remote: &'a str,
+    /// Full ids of the target commits.
+    target: Vec<String>,
+    tracked: bool,
+    /// Number of commits ahead of the local bookmark if tracked.
+    ahead: Option<usize>,
+    /// Number of commits behind the local bookmark if tracked.
+    behind: Option<usize>,
+}
+
+/// Groups listed refs by bookmark name. Untracked remote bookmarks are merged
+/// into the entry of the local bookmark of the same name if any.
+fn to_json_bookmarks<'a>(
+    repo: &dyn Repo,
+    items: &'a [RefListItem],
+) -> Result<Vec<JsonBookmark<'a>>, CommandError> {
+    let target_ids = |commit_ref: &CommitRef| {
+        commit_ref
+            .target()
+            .added_ids()
+            .map(|id| id.hex())
+            .collect_vec()
+    };
+    let to_json_remote = |commit_ref: &'a CommitRef| -> Result<_, CommandError> {
+        let (ahead, behind) = if commit_ref.is_tracked() {
+            let remote_ids = commit_ref.target().added_ids().cloned().collect_vec();
+            let local_target = repo.view().get_local_bookmark(commit_ref.name().as_ref());
+            let local_ids = local_target.added_ids().cloned().collect_vec();
+            let ahead = count_revs(repo, &remote_ids, &local_ids)?;
+            let behind = count_revs(repo, &local_ids, &remote_ids)?;
+            (Some(ahead), Some(behind))
+        } else {
+            (None, None)
+        };
+        Ok(JsonRemoteBookmark {
+            remote: commit_ref
+                .remote_name()
+                .expect("remote ref should have remote name"),
+            target: target_ids(commit_ref),
+            tracked: commit_ref.is_tracked(),
+            ahead,
+            behind,
+        })
+    };
+
+    let mut bookmarks: Vec<JsonBookmark> = Vec::new();
+    let mut name_to_index: HashMap<&str, usize> = HashMap::new();
+    for item in items {
+        let primary = item.primary.as_ref();
+        let index = *name_to_index.entry(primary.name()).or_insert_with(|| {
+            bookmarks.push(JsonBookmark {
+                name: primary.name(),
+                present: false,
+                conflicted: false,
+                targets: vec![],
+                remotes: vec![],
+            });
+            bookmarks.len() - 1
+        });
+        let bookmark = &mut bookmarks[index];
+        if primary.is_local() {
+            bookmark.present = primary.is_present();
+            bookmark.conflicted = primary.has_conflict();
+            bookmark.targets = target_ids(primary);
+        } else {
+            bookmark.remotes.push(to_json_remote(primary)?);
+        }
+        for commit_ref in &item.tracked {
+            bookmark.remotes.push(to_json_remote(commit_ref.as_ref())?);
+        }
+    }
+    Ok(bookmarks)
+}
+
+/// Counts commits reachable from `wanted` but not from `unwanted`. Unlike the
+/// template keywords, this walks the revset to get the exact number.
+fn count_revs(
+    repo: &dyn Repo,
+    wanted: &[CommitId],
+    unwanted: &[CommitId],
+) -> Result<usize, CommandError> {
+    let revset = revset::walk_revs(repo, wanted, unwanted)?;
+    Ok(revset.iter().process_results(|commits| commits.count())?)
+}
+
 #[derive(Clone, Debug)]
 struct RefListItem {
     /// Local bookmark or untracked remote bookmark.
@@ -331,25 +623,25 @@ struct RefListItem {
 #[derive(Copy, Clone, PartialEq, Debug, ValueEnum)]
 enum SortKey {
     Name,
-    #[value(name = "name-")]
+    #[value(name = "name-", alias = "-name")]
     NameDesc,
     AuthorName,
-    #[value(name = "author-name-")]
+    #[value(name = "author-name-", alias = "-author-name")]
     AuthorNameDesc,
     AuthorEmail,
-    #[value(name = "author-email-")]
+    #[value(name = "author-email-", alias = "-author-email")]
     AuthorEmailDesc,
     AuthorDate,
-    #[value(name = "author-date-")]
+    #[value(name = "author-date-", alias = "-author-date")]
     AuthorDateDesc,
     CommitterName,
-    #[value(name = "committer-name-")]
+    #[value(name = "committer-name-", alias = "-committer-name")]
     CommitterNameDesc,
     CommitterEmail,
-    #[value(name = "committer-email-")]
+    #[value(name = "committer-email-", alias = "-committer-email")]
     CommitterEmailDesc,
     CommitterDate,
-    #[value(name = "committer-date-")]
+    #[value(name = "committer-date-", alias = "-committer-date")]
     CommitterDateDesc,
 }
 
@@ -393,9 +685,13 @@ fn sort(
     sort_keys: &[SortKey],
     commits: &HashMap<CommitId, Arc<backend::Commit>>,
 ) {
+    // A conflicted bookmark is sorted by its most recently committed target.
     let to_commit = |item: &RefListItem| {
-        let id = item.primary.target().added_ids().next()?;
-        commits.get(id)
+        item.primary
+            .target()
+            .added_ids()
+            .filter_map(|id| commits.get(id))
+            .max_by_key(|commit| commit.committer.timestamp)
     };
 
     // Multi-pass sorting, the first key is most significant.
@@ -891,4 +1187,44 @@ mod tests {
         chore               Test User       test.user@g.com  0             eve             test.user@g.com  0
         ");
     }
+
+    #[test]
+    fn test_sort_conflicted_by_most_recent_target() {
+        let mut new_commit_id = commit_id_generator();
+        let mut commits: HashMap<CommitId, Arc<backend::Commit>> = HashMap::new();
+        let mut new_commit = |timestamp: i64| {
+            let mut committer = make_default_signature();
+            committer.timestamp.timestamp = MillisSinceEpoch(timestamp);
+            let commit_id = new_commit_id();
+            commits.insert(
+                commit_id.clone(),
+                make_backend_commit(make_default_signature(), committer),
+            );
+            commit_id
+        };
+        let old_id = new_commit(1);
+        let mid_id = new_commit(2);
+        let new_id = new_commit(3);
+        let mut items = vec![
+            RefListItem {
+                primary: CommitRef::local_only(
+                    "conflicted",
+                    RefTarget::from_merge(Merge::from_vec(vec![Some(old_id), None, Some(new_id)])),
+                ),
+                tracked: vec![],
+            },
+            RefListItem {
+                primary: CommitRef::local_only("normal", RefTarget::normal(mid_id)),
+                tracked: vec![],
+            },
+        ];
+
+        sort(&mut items, &[SortKey::CommitterDate], &commits);
+        let names = items.iter().map(|item| item.primary.name()).collect_vec();
+        assert_eq!(names, ["normal", "conflicted"]);
+
+        sort(&mut items, &[SortKey::CommitterDateDesc], &commits);
+        let names = items.iter().map(|item| item.primary.name()).collect_vec();
+        assert_eq!(names, ["conflicted", "normal"]);
+    }
 }
@@ -19,11 +19,14 @@ mod list;
 mod r#move;
 mod rename;
 mod set;
+mod spread;
 mod track;
 mod untrack;
 
 use itertools::Itertools as _;
 use jj_lib::backend::CommitId;
+use jj_lib::config::ConfigFile;
+use jj_lib::config::ConfigSource;
 use jj_lib::iter_util::fallible_any;
 use jj_lib::op_store::RefTarget;
 use jj_lib::op_store::RemoteRef;
@@ -48,14 +51,20 @@ use self::rename::BookmarkRenameArgs;
 use self::rename::cmd_bookmark_rename;
 use self::set::BookmarkSetArgs;
 use self::set::cmd_bookmark_set;
+use self::spread::BookmarkSpreadArgs;
+use self::spread::cmd_bookmark_spread;
 use self::track::BookmarkTrackArgs;
 use self::track::cmd_bookmark_track;
 use self::untrack::BookmarkUntrackArgs;
 use self::untrack::cmd_bookmark_untrack;
 use crate::cli_util::CommandHelper;
 use crate::cli_util::RemoteBookmarkNamePattern;
+use crate::cli_util::WorkspaceCommandHelper;
+use crate::cli_util::short_commit_hash;
 use crate::command_error::CommandError;
 use crate::command_error::user_error;
+use crate::command_error::user_error_with_hint;
+use crate::command_error::user_error_with_message;
 use crate::ui::Ui;
 
 // Unlike most other aliases, `b` is defined in the config and can be overridden
@@ -83,6 +92,7 @@ pub enum BookmarkCommand {
     Rename(BookmarkRenameArgs),
     #[command(visible_alias("s"))]
     Set(BookmarkSetArgs),
+    Spread(BookmarkSpreadArgs),
     #[command(visible_alias("t"))]
     Track(BookmarkTrackArgs),
     Untrack(BookmarkUntrackArgs),
@@ -101,6 +111,7 @@ pub fn cmd_bookmark(
         BookmarkCommand::Move(args) => cmd_bookmark_move(ui, command, args),
         BookmarkCommand::Rename(args) => cmd_bookmark_rename(ui, command, args),
         BookmarkCommand::Set(args) => cmd_bookmark_set(ui, command, args),
+        BookmarkCommand::Spread(args) => cmd_bookmark_spread(ui, command, args),
         BookmarkCommand::Track(args) => cmd_bookmark_track(ui, command, args),
         BookmarkCommand::Untrack(args) => cmd_bookmark_untrack(ui, command, args),
     }
@@ -182,6 +193,36 @@ fn find_trackable_remote_bookmarks<'a>(
     }
 }
 
+/// Sets or removes (if `description` is `None`) the descriptions of the given
+/// bookmarks in the repo config.
+fn write_bookmark_descriptions(
+    workspace_command: &WorkspaceCommandHelper,
+    names: impl IntoIterator<Item = impl AsRef<RefName>>,
+    description: Option<&str>,
+) -> Result<(), CommandError> {
+    let path = workspace_command.repo_path().join("config.toml");
+    let mut file = ConfigFile::load_or_empty(ConfigSource::Repo, path)?;
+    let mut changed = false;
+    for name in names {
+        let key = ["bookmark-descriptions", name.as_ref().as_str()];
+        let old_value = if let Some(description) = description {
+            changed = true;
+            file.set_value(key, description)
+        } else {
+            file.delete_value(key)
+        };
+        let old_value = old_value.map_err(|err| {
+            user_error_with_message("Failed to update bookmark descriptions", err)
+        })?;
+        changed |= old_value.is_some();
+    }
+    // Don't create an empty repo config file if there was nothing to delete.
+    if changed {
+        file.save()?;
+    }
+    Ok(())
+}
+
 fn is_fast_forward(
     repo: &dyn Repo,
     old_target: &RefTarget,
@@ -199,3 +240,20 @@ fn is_fast_forward(
         Ok(true)
     }
 }
+
+/// Creates an error for a bookmark update that isn't fast-forward.
+fn backwards_move_error(
+    name: &RefName,
+    old_target: &RefTarget,
+    new_target_id: &CommitId,
+) -> CommandError {
+    user_error_with_hint(
+        format!(
+            "Refusing to move bookmark backwards or sideways: {name} (from {old} to {new})",
+            name = name.as_symbol(),
+            old = old_target.added_ids().map(short_commit_hash).join(", "),
+            new = short_commit_hash(new_target_id),
+        ),
+        "Use --allow-backwards to allow it.",
+    )
+}
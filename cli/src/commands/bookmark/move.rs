@@ -20,12 +20,12 @@ use jj_lib::object_id::ObjectId as _;
 use jj_lib::op_store::RefTarget;
 use jj_lib::str_util::StringPattern;
 
+use super::backwards_move_error;
 use super::find_bookmarks_with;
 use super::is_fast_forward;
 use crate::cli_util::CommandHelper;
 use crate::cli_util::RevisionArg;
 use crate::command_error::CommandError;
-use crate::command_error::user_error_with_hint;
 use crate::complete;
 use crate::ui::Ui;
 
@@ -138,7 +138,7 @@ pub fn cmd_bookmark_move(
     }
 
     if !args.allow_backwards
-        && let Some((name, _)) = fallible_find(
+        && let Some((name, old_target)) = fallible_find(
             matched_bookmarks.iter(),
             |(_, old_target)| -> Result<_, CommandError> {
                 let is_ff = is_fast_forward(repo.as_ref(), old_target, target_commit.id())?;
@@ -146,13 +146,7 @@ pub fn cmd_bookmark_move(
             },
         )?
     {
-        return Err(user_error_with_hint(
-            format!(
-                "Refusing to move bookmark backwards or sideways: {name}",
-                name = name.as_symbol()
-            ),
-            "Use --allow-backwards to allow it.",
-        ));
+        return Err(backwards_move_error(name, old_target, target_commit.id()));
     }
     if target_commit.is_discardable(repo.as_ref())? {
         writeln!(ui.warning_default(), "Target revision is empty.")?;
@@ -17,7 +17,9 @@ use std::collections::HashSet;
 use clap_complete::ArgValueCandidates;
 use itertools::Itertools as _;
 use jj_lib::op_store::RefTarget;
+use jj_lib::ref_name::RefName;
 use jj_lib::ref_name::RefNameBuf;
+use jj_lib::ref_name::RemoteNameBuf;
 use jj_lib::repo::Repo as _;
 use jj_lib::str_util::StringExpression;
 use jj_lib::str_util::StringMatcher;
@@ -45,6 +47,26 @@ pub struct BookmarkRenameArgs {
     /// The new name of the bookmark
     #[arg(value_parser = revset_util::parse_bookmark_name)]
     new: RefNameBuf,
+
+    /// Track existing remote bookmarks of the new name
+    ///
+    /// For each remote where the old bookmark was tracked, the remote bookmark
+    /// of the new name will be tracked if it exists. Remotes that don't have
+    /// the new bookmark are skipped with a warning.
+    #[arg(long)]
+    retrack: bool,
+
+    /// Also rename the bookmark on the given remote by the next push
+    ///
+    /// The next `jj git push` to the remote deletes the old bookmark there and
+    /// creates the new one. The old bookmark must be tracked on the remote.
+    #[arg(
+        long,
+        value_name = "REMOTE",
+        conflicts_with = "retrack",
+        add = ArgValueCandidates::new(complete::git_remotes),
+    )]
+    remote_too: Option<RemoteNameBuf>,
 }
 
 pub fn cmd_bookmark_rename(
@@ -71,6 +93,36 @@ pub fn cmd_bookmark_rename(
         )));
     }
 
+    // The name of the bookmark on the remote may differ if it has been
+    // renamed before without pushing.
+    let remote_old_bookmark = if let Some(remote) = &args.remote_too {
+        let remote_old_bookmark: &RefName = view
+            .pending_bookmark_renames(remote)
+            .find_map(|(new_name, old_name)| (*new_name == **old_bookmark).then_some(old_name))
+            .unwrap_or(old_bookmark);
+        let old_remote_ref = view.get_remote_bookmark(remote_old_bookmark.to_remote_symbol(remote));
+        if !old_remote_ref.is_tracked() || old_remote_ref.is_absent() {
+            return Err(user_error(format!(
+                "Bookmark {old_bookmark} is not tracked on remote {remote}",
+                old_bookmark = old_bookmark.as_symbol(),
+                remote = remote.as_symbol()
+            )));
+        }
+        if view
+            .get_remote_bookmark(new_bookmark.to_remote_symbol(remote))
+            .is_present()
+        {
+            return Err(user_error(format!(
+                "Bookmark {new_bookmark} already exists on remote {remote}",
+                new_bookmark = new_bookmark.as_symbol(),
+                remote = remote.as_symbol()
+            )));
+        }
+        Some(remote_old_bookmark.to_owned())
+    } else {
+        None
+    };
+
     let mut tx = workspace_command.start_transaction();
     tx.repo_mut()
         .set_local_bookmark_target(new_bookmark, ref_target);
@@ -86,8 +138,11 @@ pub fn cmd_bookmark_rename(
         .base_repo()
         .view()
         .remote_bookmarks_matching(&StringMatcher::exact(old_bookmark), &remote_matcher)
-        .filter(|(_, remote_ref)| {
-            if remote_ref.is_tracked() && remote_ref.is_present() {
+        .filter(|(symbol, remote_ref)| {
+            if remote_ref.is_tracked()
+                && remote_ref.is_present()
+                && args.remote_too.as_deref() != Some(symbol.remote)
+            {
                 tracked_present_remote_bookmarks_exist_for_old_bookmark = true;
             }
             remote_ref.is_tracked()
@@ -110,6 +165,24 @@ pub fn cmd_bookmark_rename(
     // preserve tracking state of old bookmark
     for old_remote in old_tracked_remotes {
         let new_remote_bookmark = new_bookmark.to_remote_symbol(&old_remote);
+        if args.retrack {
+            if tx
+                .base_repo()
+                .view()
+                .get_remote_bookmark(new_remote_bookmark)
+                .is_absent()
+            {
+                writeln!(
+                    ui.warning_default(),
+                    "The renamed bookmark doesn't exist on the remote '{remote}', tracking state \
+                     was dropped.",
+                    remote = new_remote_bookmark.remote.as_symbol(),
+                )?;
+                continue;
+            }
+            tx.repo_mut().track_remote_bookmark(new_remote_bookmark)?;
+            continue;
+        }
         if existing_untracked_remotes.contains(new_remote_bookmark.remote) {
             writeln!(
                 ui.warning_default(),
@@ -127,6 +200,15 @@ pub fn cmd_bookmark_rename(
         tx.repo_mut().track_remote_bookmark(new_remote_bookmark)?;
     }
 
+    if let Some(remote) = &args.remote_too {
+        tx.repo_mut()
+            .set_pending_bookmark_rename(old_bookmark.to_remote_symbol(remote), None);
+        tx.repo_mut().set_pending_bookmark_rename(
+            new_bookmark.to_remote_symbol(remote),
+            remote_old_bookmark.as_deref(),
+        );
+    }
+
     tx.finish(
         ui,
         format!(
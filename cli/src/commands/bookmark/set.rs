@@ -18,13 +18,15 @@ use itertools::Itertools as _;
 use jj_lib::object_id::ObjectId as _;
 use jj_lib::op_store::RefTarget;
 use jj_lib::ref_name::RefNameBuf;
+use jj_lib::repo::Repo as _;
 
+use super::backwards_move_error;
 use super::is_fast_forward;
+use super::write_bookmark_descriptions;
 use crate::cli_util::CommandHelper;
 use crate::cli_util::RevisionArg;
 use crate::cli_util::has_tracked_remote_bookmarks;
 use crate::command_error::CommandError;
-use crate::command_error::user_error_with_hint;
 use crate::complete;
 use crate::revset_util;
 use crate::ui::Ui;
@@ -46,6 +48,13 @@ pub struct BookmarkSetArgs {
     #[arg(long, short = 'B')]
     allow_backwards: bool,
 
+    /// Annotate the bookmarks with a description
+    ///
+    /// The description is saved in the repo config under the
+    /// `bookmark-descriptions` table, and is shown by `jj bookmark list`.
+    #[arg(long, value_name = "TEXT")]
+    description: Option<String>,
+
     /// The bookmarks to update
     #[arg(
         required = true,
@@ -76,16 +85,11 @@ pub fn cmd_bookmark_set(
             moved_bookmark_count += 1;
         }
         if !args.allow_backwards && !is_fast_forward(repo, old_target, target_commit.id())? {
-            return Err(user_error_with_hint(
-                format!(
-                    "Refusing to move bookmark backwards or sideways: {name}",
-                    name = name.as_symbol()
-                ),
-                "Use --allow-backwards to allow it.",
-            ));
+            return Err(backwards_move_error(name, old_target, target_commit.id()));
         }
     }
-    if target_commit.is_discardable(repo)? {
+    let is_root_target = target_commit.id() == repo.store().root_commit_id();
+    if !is_root_target && target_commit.is_discardable(repo)? {
         writeln!(ui.warning_default(), "Target revision is empty.")?;
     }
 
@@ -111,6 +115,14 @@ pub fn cmd_bookmark_set(
             tx.write_commit_summary(formatter.as_mut(), &target_commit)?;
             writeln!(formatter)?;
         }
+        if is_root_target {
+            let names = bookmark_names.iter().map(|n| n.as_symbol()).join(", ");
+            if bookmark_names.len() == 1 {
+                writeln!(formatter, "Bookmark {names} now points to the root commit")?;
+            } else {
+                writeln!(formatter, "Bookmarks {names} now point to the root commit")?;
+            }
+        }
     }
 
     tx.finish(
@@ -121,5 +133,8 @@ pub fn cmd_bookmark_set(
             id = target_commit.id().hex()
         ),
     )?;
+    if let Some(description) = &args.description {
+        write_bookmark_descriptions(&workspace_command, bookmark_names, Some(description))?;
+    }
     Ok(())
 }
@@ -0,0 +1,145 @@
+// Copyright 2025 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::slice;
+
+use clap_complete::ArgValueCompleter;
+use itertools::Itertools as _;
+use jj_lib::op_store::RefTarget;
+use jj_lib::ref_name::RefNameBuf;
+use jj_lib::revset::RevsetFilterPredicate;
+
+use crate::cli_util::CommandHelper;
+use crate::cli_util::RevisionArg;
+use crate::cli_util::has_tracked_remote_bookmarks;
+use crate::command_error::CommandError;
+use crate::command_error::user_error_with_hint;
+use crate::complete;
+use crate::revset_util;
+use crate::ui::Ui;
+
+/// Create a bookmark for each of the given sets of paths
+///
+/// For each `--by-path` fileset, the newest revision in `--revisions` that
+/// modifies the matching files gets a bookmark named `<BASE_NAME>-<N>`, where
+/// `N` is the position of the fileset on the command line, starting at 1.
+/// Filesets which aren't modified by any revision are skipped.
+///
+/// Example: create a bookmark for each of two subtrees of a monorepo
+///
+/// $ jj bookmark spread topic --by-path frontend --by-path backend
+#[derive(clap::Args, Clone, Debug)]
+pub struct BookmarkSpreadArgs {
+    /// Prefix of the names of the bookmarks to create
+    #[arg(value_parser = revset_util::parse_bookmark_name)]
+    base_name: RefNameBuf,
+
+    /// Paths to look for changes in (can be repeated)
+    #[arg(
+        long,
+        required = true,
+        value_name = "FILESETS",
+        value_hint = clap::ValueHint::AnyPath,
+    )]
+    by_path: Vec<String>,
+
+    /// Revisions to search
+    #[arg(
+        long, short,
+        default_value = "::@",
+        value_name = "REVSETS",
+        add = ArgValueCompleter::new(complete::revset_expression_all),
+    )]
+    revisions: RevisionArg,
+}
+
+pub fn cmd_bookmark_spread(
+    ui: &mut Ui,
+    command: &CommandHelper,
+    args: &BookmarkSpreadArgs,
+) -> Result<(), CommandError> {
+    let mut workspace_command = command.workspace_helper(ui)?;
+    let revisions = workspace_command.parse_revset(ui, &args.revisions)?;
+    let mut new_bookmarks = vec![];
+    for (index, path) in args.by_path.iter().enumerate() {
+        let name = RefNameBuf::from(format!("{}-{}", args.base_name.as_str(), index + 1));
+        let fileset = workspace_command.parse_union_filesets(ui, slice::from_ref(path))?;
+        // The file filter can be evaluated against the changed-path index if
+        // it's enabled.
+        let expression = revisions
+            .expression()
+            .filtered(RevsetFilterPredicate::File(fileset))
+            .latest(1);
+        let commit = workspace_command
+            .attach_revset_evaluator(expression)
+            .evaluate_to_commits()?
+            .next()
+            .transpose()?;
+        let Some(commit) = commit else {
+            writeln!(
+                ui.warning_default(),
+                "No revisions modify {path}, not creating bookmark {name}",
+                name = name.as_symbol()
+            )?;
+            continue;
+        };
+        let repo = workspace_command.repo().as_ref();
+        if repo.view().get_local_bookmark(&name).is_present() {
+            return Err(user_error_with_hint(
+                format!("Bookmark already exists: {name}", name = name.as_symbol()),
+                "Use `jj bookmark set` to update it.",
+            ));
+        }
+        if has_tracked_remote_bookmarks(repo, &name) {
+            return Err(user_error_with_hint(
+                format!(
+                    "Tracked remote bookmarks exist for deleted bookmark: {name}",
+                    name = name.as_symbol()
+                ),
+                format!(
+                    "Use `jj bookmark set` to recreate the local bookmark. Run `jj bookmark \
+                     untrack 'glob:{name}@*'` to disassociate them.",
+                    name = name.as_symbol()
+                ),
+            ));
+        }
+        new_bookmarks.push((name, commit));
+    }
+
+    let mut tx = workspace_command.start_transaction();
+    for (name, commit) in &new_bookmarks {
+        tx.repo_mut()
+            .set_local_bookmark_target(name, RefTarget::normal(commit.id().clone()));
+        if let Some(mut formatter) = ui.status_formatter() {
+            write!(
+                formatter,
+                "Created bookmark {name} pointing to ",
+                name = name.as_symbol()
+            )?;
+            tx.write_commit_summary(formatter.as_mut(), commit)?;
+            writeln!(formatter)?;
+        }
+    }
+    tx.finish(
+        ui,
+        format!(
+            "spread bookmarks {names}",
+            names = new_bookmarks
+                .iter()
+                .map(|(name, _)| name.as_symbol())
+                .join(", ")
+        ),
+    )?;
+    Ok(())
+}
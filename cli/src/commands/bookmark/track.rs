@@ -13,14 +13,20 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::io::Write as _;
 use std::rc::Rc;
 
 use clap_complete::ArgValueCandidates;
 use itertools::Itertools as _;
+use jj_lib::repo::Repo as _;
+use jj_lib::str_util::StringExpression;
+use jj_lib::str_util::StringMatcher;
 
 use super::find_trackable_remote_bookmarks;
 use crate::cli_util::CommandHelper;
 use crate::cli_util::RemoteBookmarkNamePattern;
+use crate::cli_util::default_ignored_remote_name;
 use crate::command_error::CommandError;
 use crate::commit_templater::CommitRef;
 use crate::complete;
@@ -33,6 +39,7 @@ use crate::ui::Ui;
 /// name. Changes to it will propagate to the existing local bookmark on future
 /// pulls.
 #[derive(clap::Args, Clone, Debug)]
+#[command(group(clap::ArgGroup::new("source").required(true)))]
 pub struct BookmarkTrackArgs {
     /// Remote bookmarks to track
     ///
@@ -44,11 +51,21 @@ pub struct BookmarkTrackArgs {
     /// [wildcard pattern]:
     ///     https://jj-vcs.github.io/jj/latest/revsets/#string-patterns
     #[arg(
-        required = true,
+        group = "source",
         value_name = "BOOKMARK@REMOTE",
         add = ArgValueCandidates::new(complete::untracked_bookmarks),
     )]
     names: Vec<RemoteBookmarkNamePattern>,
+
+    /// Track all remote bookmarks which aren't tracked yet
+    #[arg(long, group = "source")]
+    all: bool,
+
+    /// Only print which local bookmarks would track the remote bookmarks
+    ///
+    /// No operation is recorded.
+    #[arg(long)]
+    print_plan: bool,
 }
 
 pub fn cmd_bookmark_track(
@@ -58,17 +75,62 @@ pub fn cmd_bookmark_track(
 ) -> Result<(), CommandError> {
     let mut workspace_command = command.workspace_helper(ui)?;
     let repo = workspace_command.repo().clone();
+    let matched_bookmarks = if args.all {
+        let remote_matcher = match default_ignored_remote_name(repo.store()) {
+            Some(remote) => StringExpression::exact(remote).negated().to_matcher(),
+            None => StringMatcher::all(),
+        };
+        repo.view()
+            .remote_bookmarks_matching(&StringMatcher::all(), &remote_matcher)
+            .collect_vec()
+    } else {
+        find_trackable_remote_bookmarks(repo.view(), &args.names)?
+    };
     let mut symbols = Vec::new();
-    for (symbol, remote_ref) in find_trackable_remote_bookmarks(repo.view(), &args.names)? {
+    for (symbol, remote_ref) in matched_bookmarks {
         if remote_ref.is_tracked() {
-            writeln!(
-                ui.warning_default(),
-                "Remote bookmark already tracked: {symbol}"
-            )?;
+            // Already-tracked bookmarks are expected when tracking everything.
+            if args.all {
+                writeln!(ui.status(), "Remote bookmark already tracked: {symbol}")?;
+            } else {
+                writeln!(
+                    ui.warning_default(),
+                    "Remote bookmark already tracked: {symbol}"
+                )?;
+            }
         } else {
+            if args.all && !args.print_plan {
+                writeln!(ui.status(), "Tracking remote bookmark: {symbol}")?;
+            }
             symbols.push(symbol);
         }
     }
+    if args.print_plan {
+        // A local bookmark created for one remote is merely associated with
+        // the remote bookmarks of the same name on the other remotes.
+        let mut created_names = HashSet::new();
+        for symbol in &symbols {
+            let name = symbol.name.as_symbol();
+            if repo.view().get_local_bookmark(symbol.name).is_present()
+                || !created_names.insert(symbol.name)
+            {
+                writeln!(
+                    ui.stdout(),
+                    "Would associate local bookmark {name} with {symbol}"
+                )?;
+            } else {
+                writeln!(
+                    ui.stdout(),
+                    "Would create local bookmark {name} tracking {symbol}"
+                )?;
+            }
+        }
+        if symbols.is_empty() {
+            writeln!(ui.status(), "Nothing changed.")?;
+        }
+        return Ok(());
+    }
+
     let mut tx = workspace_command.start_transaction();
     for &symbol in &symbols {
         tx.repo_mut().track_remote_bookmark(symbol)?;
@@ -15,6 +15,8 @@
 use clap_complete::ArgValueCandidates;
 use itertools::Itertools as _;
 use jj_lib::repo::Repo as _;
+use jj_lib::str_util::StringExpression;
+use jj_lib::str_util::StringMatcher;
 
 use super::find_trackable_remote_bookmarks;
 use crate::cli_util::CommandHelper;
@@ -32,6 +34,7 @@ use crate::ui::Ui;
 /// If you want to forget a local bookmark while also untracking the
 /// corresponding remote bookmarks, use `jj bookmark forget` instead.
 #[derive(clap::Args, Clone, Debug)]
+#[command(group(clap::ArgGroup::new("source").required(true)))]
 pub struct BookmarkUntrackArgs {
     /// Remote bookmarks to untrack
     ///
@@ -43,11 +46,15 @@ pub struct BookmarkUntrackArgs {
     /// [wildcard pattern]:
     ///     https://jj-vcs.github.io/jj/latest/revsets/#string-patterns
     #[arg(
-        required = true,
+        group = "source",
         value_name = "BOOKMARK@REMOTE",
         add = ArgValueCandidates::new(complete::tracked_bookmarks)
     )]
     names: Vec<RemoteBookmarkNamePattern>,
+
+    /// Untrack all remote bookmarks which are currently tracked
+    #[arg(long, group = "source")]
+    all: bool,
 }
 
 pub fn cmd_bookmark_untrack(
@@ -58,8 +65,20 @@ pub fn cmd_bookmark_untrack(
     let mut workspace_command = command.workspace_helper(ui)?;
     let repo = workspace_command.repo().clone();
     let ignored_remote = default_ignored_remote_name(repo.store());
+    let matched_bookmarks = if args.all {
+        let remote_matcher = match ignored_remote {
+            Some(remote) => StringExpression::exact(remote).negated().to_matcher(),
+            None => StringMatcher::all(),
+        };
+        repo.view()
+            .remote_bookmarks_matching(&StringMatcher::all(), &remote_matcher)
+            .filter(|(_, remote_ref)| remote_ref.is_tracked())
+            .collect_vec()
+    } else {
+        find_trackable_remote_bookmarks(repo.view(), &args.names)?
+    };
     let mut symbols = Vec::new();
-    for (symbol, remote_ref) in find_trackable_remote_bookmarks(repo.view(), &args.names)? {
+    for (symbol, remote_ref) in matched_bookmarks {
         if ignored_remote.is_some_and(|ignored| symbol.remote == ignored) {
             // This restriction can be lifted if we want to support untracked @git
             // bookmarks.
@@ -73,6 +92,9 @@ pub fn cmd_bookmark_untrack(
                 "Remote bookmark not tracked yet: {symbol}"
             )?;
         } else {
+            if args.all {
+                writeln!(ui.status(), "Untracking remote bookmark: {symbol}")?;
+            }
             symbols.push(symbol);
         }
     }
@@ -0,0 +1,77 @@
+// Copyright 2026 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::io::Write as _;
+use std::slice;
+
+use itertools::Itertools as _;
+use jj_lib::default_index::DefaultReadonlyIndex;
+use jj_lib::repo::Repo as _;
+
+use crate::cli_util::CommandHelper;
+use crate::cli_util::RevisionArg;
+use crate::cli_util::short_commit_hash;
+use crate::command_error::CommandError;
+use crate::ui::Ui;
+
+/// Show ancestry relationship between two revisions
+#[derive(clap::Args, Clone, Debug)]
+pub struct DebugAncestryArgs {
+    /// The first revision
+    #[arg(value_name = "REVSET")]
+    revision_a: RevisionArg,
+    /// The second revision
+    #[arg(value_name = "REVSET")]
+    revision_b: RevisionArg,
+}
+
+pub fn cmd_debug_ancestry(
+    ui: &mut Ui,
+    command: &CommandHelper,
+    args: &DebugAncestryArgs,
+) -> Result<(), CommandError> {
+    let workspace_command = command.workspace_helper(ui)?;
+    let commit_a = workspace_command.resolve_single_rev(ui, &args.revision_a)?;
+    let commit_b = workspace_command.resolve_single_rev(ui, &args.revision_b)?;
+    let repo = workspace_command.repo();
+    let index = repo.index();
+    let a_is_ancestor = index.is_ancestor(commit_a.id(), commit_b.id())?;
+    let b_is_ancestor = index.is_ancestor(commit_b.id(), commit_a.id())?;
+    let merge_bases = index.common_ancestors(
+        slice::from_ref(commit_a.id()),
+        slice::from_ref(commit_b.id()),
+    )?;
+    // Generation numbers are specific to the default index implementation.
+    let default_index = repo.readonly_index().downcast_ref::<DefaultReadonlyIndex>();
+
+    let mut stdout = ui.stdout();
+    for (label, commit) in [("A", &commit_a), ("B", &commit_b)] {
+        write!(stdout, "{label}: {}", short_commit_hash(commit.id()))?;
+        if let Some(generation) =
+            default_index.and_then(|index| index.generation_number(commit.id()))
+        {
+            write!(stdout, " (generation {generation})")?;
+        }
+        writeln!(stdout)?;
+    }
+    writeln!(stdout, "A is an ancestor of B: {a_is_ancestor}")?;
+    writeln!(stdout, "B is an ancestor of A: {b_is_ancestor}")?;
+    writeln!(
+        stdout,
+        "Merge bases: {}",
+        merge_bases.iter().map(short_commit_hash).join(", ")
+    )?;
+    Ok(())
+}
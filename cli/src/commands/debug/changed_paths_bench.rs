@@ -0,0 +1,152 @@
+// Copyright 2025 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write as _;
+use std::time::Instant;
+
+use futures::StreamExt as _;
+use itertools::Itertools as _;
+use jj_lib::backend::CommitId;
+use jj_lib::default_index::DefaultReadonlyIndex;
+use jj_lib::matchers::Matcher;
+use jj_lib::repo::Repo;
+use pollster::FutureExt as _;
+
+use crate::cli_util::CommandHelper;
+use crate::cli_util::RevisionArg;
+use crate::cli_util::short_commit_hash;
+use crate::command_error::CommandError;
+use crate::command_error::user_error;
+use crate::ui::Ui;
+
+/// Compare changed-path index lookups with full diff scanning
+///
+/// Finds the revisions that modify the given files twice, once by looking up
+/// the changed-path index and once by diffing each revision against its
+/// parents, and reports the number of matches and the time taken by each.
+/// Fails if the two methods disagree.
+#[derive(clap::Args, Clone, Debug)]
+pub struct DebugChangedPathsBenchArgs {
+    /// Revisions to search
+    revisions: RevisionArg,
+    /// Files to look for
+    #[arg(required = true, value_hint = clap::ValueHint::AnyPath)]
+    paths: Vec<String>,
+}
+
+pub fn cmd_debug_changed_paths_bench(
+    ui: &mut Ui,
+    command: &CommandHelper,
+    args: &DebugChangedPathsBenchArgs,
+) -> Result<(), CommandError> {
+    let workspace_command = command.workspace_helper(ui)?;
+    let repo = workspace_command.repo().as_ref();
+    let matcher = workspace_command
+        .parse_file_patterns(ui, &args.paths)?
+        .to_matcher();
+    let commit_ids: Vec<CommitId> = workspace_command
+        .parse_revset(ui, &args.revisions)?
+        .evaluate_to_commit_ids()?
+        .try_collect()?;
+    let changed_path_index_enabled = repo
+        .readonly_index()
+        .downcast_ref::<DefaultReadonlyIndex>()
+        .is_some_and(|index| index.changed_path_commits_range().is_some());
+
+    let scan_start = Instant::now();
+    let mut scan_matches = vec![];
+    for id in &commit_ids {
+        if has_diff_from_parents(repo, id, matcher.as_ref())? {
+            scan_matches.push(id.clone());
+        }
+    }
+    let scan_elapsed = scan_start.elapsed();
+
+    if !changed_path_index_enabled {
+        writeln!(
+            ui.stdout(),
+            "Changed-path index is disabled, only running full scan."
+        )?;
+        writeln!(
+            ui.stdout(),
+            "Full scan: {} of {} commits matched in {scan_elapsed:?}",
+            scan_matches.len(),
+            commit_ids.len(),
+        )?;
+        return Ok(());
+    }
+
+    // Commits which aren't indexed yet are scanned, just like the revset
+    // engine does.
+    let index_start = Instant::now();
+    let mut index_matches = vec![];
+    let mut not_indexed_count = 0;
+    for id in &commit_ids {
+        let matched = if let Some(mut paths) = repo.index().changed_paths_in_commit(id)? {
+            paths.any(|path| matcher.matches(&path))
+        } else {
+            not_indexed_count += 1;
+            has_diff_from_parents(repo, id, matcher.as_ref())?
+        };
+        if matched {
+            index_matches.push(id.clone());
+        }
+    }
+    let index_elapsed = index_start.elapsed();
+
+    writeln!(
+        ui.stdout(),
+        "Changed-path index: {} of {} commits matched ({not_indexed_count} not indexed) in \
+         {index_elapsed:?}",
+        index_matches.len(),
+        commit_ids.len(),
+    )?;
+    writeln!(
+        ui.stdout(),
+        "Full scan: {} of {} commits matched in {scan_elapsed:?}",
+        scan_matches.len(),
+        commit_ids.len(),
+    )?;
+    if index_matches != scan_matches {
+        let mismatched_ids = commit_ids
+            .iter()
+            .filter(|id| index_matches.contains(id) != scan_matches.contains(id))
+            .map(short_commit_hash)
+            .join(", ");
+        return Err(user_error(format!(
+            "Changed-path index and full scan disagree on commits: {mismatched_ids}"
+        )));
+    }
+    writeln!(ui.stdout(), "Both methods matched the same commits.")?;
+    Ok(())
+}
+
+/// Returns true if the commit modifies any of the matching paths compared to
+/// its parents.
+fn has_diff_from_parents(
+    repo: &dyn Repo,
+    commit_id: &CommitId,
+    matcher: &dyn Matcher,
+) -> Result<bool, CommandError> {
+    let commit = repo.store().get_commit(commit_id)?;
+    let from_tree = commit.parent_tree(repo)?;
+    let to_tree = commit.tree();
+    let mut tree_diff = from_tree.diff_stream(&to_tree, matcher);
+    while let Some(entry) = tree_diff.next().block_on() {
+        if entry.values?.is_changed() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
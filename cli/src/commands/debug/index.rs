@@ -12,26 +12,51 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::error::Error as _;
 use std::fmt::Debug;
 use std::io::Write as _;
 
+use jj_lib::default_index::DefaultIndexStore;
+use jj_lib::default_index::DefaultIndexStoreError;
 use jj_lib::default_index::DefaultReadonlyIndex;
+use jj_lib::default_index::IndexStats;
+use pollster::FutureExt as _;
 
 use crate::cli_util::CommandHelper;
+use crate::cli_util::short_operation_hash;
 use crate::command_error::CommandError;
 use crate::command_error::internal_error;
+use crate::command_error::print_error_sources;
 use crate::command_error::user_error;
+use crate::command_error::user_error_with_message;
 use crate::ui::Ui;
 
 /// Show commit index stats
 #[derive(clap::Args, Clone, Debug)]
-pub struct DebugIndexArgs {}
+pub struct DebugIndexArgs {
+    #[command(subcommand)]
+    command: Option<DebugIndexCommand>,
+}
+
+#[derive(clap::Subcommand, Clone, Debug)]
+enum DebugIndexCommand {
+    Rebuild(DebugIndexRebuildArgs),
+}
+
+/// Rebuild commit index and show its stats before and after
+///
+/// The changed-path index is also rebuilt if it was enabled.
+#[derive(clap::Args, Clone, Debug)]
+struct DebugIndexRebuildArgs {}
 
 pub fn cmd_debug_index(
     ui: &mut Ui,
     command: &CommandHelper,
-    _args: &DebugIndexArgs,
+    args: &DebugIndexArgs,
 ) -> Result<(), CommandError> {
+    if let Some(DebugIndexCommand::Rebuild(args)) = &args.command {
+        return cmd_debug_index_rebuild(ui, command, args);
+    }
     // Resolve the operation without loading the repo, so this command won't
     // update the index.
     let workspace = command.load_workspace()?;
@@ -53,6 +78,13 @@ pub fn cmd_debug_index(
         )?;
         writeln!(ui.stdout(), "Number of heads: {}", stats.num_heads)?;
         writeln!(ui.stdout(), "Number of changes: {}", stats.num_changes)?;
+        if stats.num_reindexed_segments > 0 {
+            writeln!(
+                ui.stdout(),
+                "Reindexed due to {} missing segments",
+                stats.num_reindexed_segments
+            )?;
+        }
         writeln!(ui.stdout(), "Stats per level:")?;
         for (i, level) in stats.commit_levels.iter().enumerate() {
             writeln!(ui.stdout(), "  Level {i}:")?;
@@ -61,6 +93,12 @@ pub fn cmd_debug_index(
         }
 
         writeln!(ui.stdout(), "=== Changed paths ===")?;
+        let enabled = stats.changed_path_commits_range.is_some();
+        writeln!(
+            ui.stdout(),
+            "Enabled: {}",
+            if enabled { "yes" } else { "no" }
+        )?;
         if let Some(range) = &stats.changed_path_commits_range {
             writeln!(ui.stdout(), "Indexed commits: {range:?}")?;
         } else {
@@ -86,3 +124,94 @@ pub fn cmd_debug_index(
     }
     Ok(())
 }
+
+fn cmd_debug_index_rebuild(
+    ui: &mut Ui,
+    command: &CommandHelper,
+    _args: &DebugIndexRebuildArgs,
+) -> Result<(), CommandError> {
+    // Resolve the operation without loading the repo. The index might have to
+    // be rebuilt while loading the repo.
+    let workspace = command.load_workspace()?;
+    let repo_loader = workspace.repo_loader();
+    let op = command.resolve_operation(ui, repo_loader)?;
+    let index_store = repo_loader.index_store();
+    let Some(default_index_store) = index_store.downcast_ref::<DefaultIndexStore>() else {
+        return Err(user_error(format!(
+            "Cannot rebuild indexes of type '{}'",
+            index_store.name()
+        )));
+    };
+    // The old index may be unloadable, in which case there's nothing to
+    // compare with.
+    let old_stats = match index_store.get_index_at_op(&op, repo_loader.store()) {
+        Ok(index) => index
+            .downcast_ref::<DefaultReadonlyIndex>()
+            .map(|index| index.stats()),
+        Err(err) => {
+            writeln!(
+                ui.warning_default(),
+                "Failed to load the index before rebuilding it: {err}"
+            )?;
+            print_error_sources(ui, err.source())?;
+            writeln!(
+                ui.hint_default(),
+                "If the changed-path index was enabled, run `jj debug index-changed-paths` to \
+                 rebuild it."
+            )?;
+            None
+        }
+    };
+    default_index_store.reinit().map_err(internal_error)?;
+    let mut new_index = default_index_store
+        .build_index_at_operation(&op, repo_loader.store())
+        .block_on()
+        .map_err(|err| match err {
+            DefaultIndexStoreError::IndexCommits { op_id, source } => user_error_with_message(
+                format!(
+                    "Failed to index commits at operation {}",
+                    short_operation_hash(&op_id)
+                ),
+                source,
+            ),
+            err => internal_error(err),
+        })?;
+    // reinit() removes the changed-path index, so build it again over as many
+    // commits as before.
+    if let Some(range) = old_stats
+        .as_ref()
+        .and_then(|stats| stats.changed_path_commits_range.clone())
+    {
+        let num_commits = range.end - range.start;
+        new_index = default_index_store
+            .build_changed_path_index_at_operation(op.id(), repo_loader.store(), num_commits)
+            .block_on()
+            .map_err(internal_error)?;
+        writeln!(
+            ui.status(),
+            "Rebuilt changed-path index of {num_commits} commits."
+        )?;
+    }
+    if let Some(stats) = &old_stats {
+        writeln!(ui.stdout(), "=== Before ===")?;
+        write_commit_stats(ui, stats)?;
+    }
+    writeln!(ui.stdout(), "=== After ===")?;
+    write_commit_stats(ui, &new_index.stats())?;
+    Ok(())
+}
+
+fn write_commit_stats(ui: &Ui, stats: &IndexStats) -> Result<(), CommandError> {
+    writeln!(ui.stdout(), "Number of commits: {}", stats.num_commits)?;
+    writeln!(ui.stdout(), "Number of merges: {}", stats.num_merges)?;
+    writeln!(
+        ui.stdout(),
+        "Max generation number: {}",
+        stats.max_generation_number
+    )?;
+    writeln!(ui.stdout(), "Commits per level:")?;
+    for (i, level) in stats.commit_levels.iter().enumerate() {
+        writeln!(ui.stdout(), "  Level {i}: {}", level.num_commits)?;
+    }
+    Ok(())
+}
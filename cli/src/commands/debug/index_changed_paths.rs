@@ -23,11 +23,17 @@ use crate::command_error::user_error;
 use crate::ui::Ui;
 
 /// Build changed-path index
+///
+/// Once enabled, the changed-path index is updated as new commits are added.
+/// It is used by `files()` and `diff_contains()` revsets.
 #[derive(clap::Args, Clone, Debug)]
 pub struct DebugIndexChangedPathsArgs {
     /// Limit number of revisions to index
     #[arg(long, short = 'n', default_value_t = u32::MAX)]
     limit: u32,
+    /// Drop changed-path index instead of building it
+    #[arg(long, conflicts_with = "limit")]
+    disable: bool,
 }
 
 pub fn cmd_debug_index_changed_paths(
@@ -45,6 +51,13 @@ pub fn cmd_debug_index_changed_paths(
             index_store.name()
         )));
     };
+    if args.disable {
+        default_index_store
+            .drop_changed_path_index_at_operation(repo.op_id(), repo.store())
+            .map_err(internal_error)?;
+        writeln!(ui.status(), "Disabled changed-path index.")?;
+        return Ok(());
+    }
     let index = default_index_store
         .build_changed_path_index_at_operation(repo.op_id(), repo.store(), args.limit)
         .block_on()
@@ -55,5 +68,14 @@ pub fn cmd_debug_index_changed_paths(
         "Finished indexing {:?} commits.",
         stats.changed_path_commits_range.unwrap()
     )?;
+    for (i, level) in stats.changed_path_levels.iter().enumerate() {
+        writeln!(
+            ui.status(),
+            "  Level {i}: {} commits, {} changed paths, {} paths",
+            level.num_commits,
+            level.num_changed_paths,
+            level.num_paths
+        )?;
+    }
     Ok(())
 }
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod ancestry;
+mod changed_paths_bench;
 mod copy_detection;
 mod fileset;
 mod index;
@@ -22,6 +24,7 @@ mod object;
 mod reindex;
 mod revset;
 mod snapshot;
+mod snapshot_bench;
 mod template;
 mod tree;
 mod watchman;
@@ -31,6 +34,10 @@ use clap::Subcommand;
 use jj_lib::local_working_copy::LocalWorkingCopy;
 use jj_lib::working_copy::WorkingCopy;
 
+use self::ancestry::DebugAncestryArgs;
+use self::ancestry::cmd_debug_ancestry;
+use self::changed_paths_bench::DebugChangedPathsBenchArgs;
+use self::changed_paths_bench::cmd_debug_changed_paths_bench;
 use self::copy_detection::CopyDetectionArgs;
 use self::copy_detection::cmd_debug_copy_detection;
 use self::fileset::DebugFilesetArgs;
@@ -51,6 +58,8 @@ use self::revset::DebugRevsetArgs;
 use self::revset::cmd_debug_revset;
 use self::snapshot::DebugSnapshotArgs;
 use self::snapshot::cmd_debug_snapshot;
+use self::snapshot_bench::DebugSnapshotBenchArgs;
+use self::snapshot_bench::cmd_debug_snapshot_bench;
 use self::template::DebugTemplateArgs;
 use self::template::cmd_debug_template;
 use self::tree::DebugTreeArgs;
@@ -68,6 +77,8 @@ use crate::ui::Ui;
 #[derive(Subcommand, Clone, Debug)]
 #[command(hide = true)]
 pub enum DebugCommand {
+    Ancestry(DebugAncestryArgs),
+    ChangedPathsBench(DebugChangedPathsBenchArgs),
     CopyDetection(CopyDetectionArgs),
     Fileset(DebugFilesetArgs),
     Index(DebugIndexArgs),
@@ -79,6 +90,7 @@ pub enum DebugCommand {
     Reindex(DebugReindexArgs),
     Revset(DebugRevsetArgs),
     Snapshot(DebugSnapshotArgs),
+    SnapshotBench(DebugSnapshotBenchArgs),
     Template(DebugTemplateArgs),
     Tree(DebugTreeArgs),
     #[command(subcommand)]
@@ -92,6 +104,8 @@ pub fn cmd_debug(
     subcommand: &DebugCommand,
 ) -> Result<(), CommandError> {
     match subcommand {
+        DebugCommand::Ancestry(args) => cmd_debug_ancestry(ui, command, args),
+        DebugCommand::ChangedPathsBench(args) => cmd_debug_changed_paths_bench(ui, command, args),
         DebugCommand::CopyDetection(args) => cmd_debug_copy_detection(ui, command, args),
         DebugCommand::Fileset(args) => cmd_debug_fileset(ui, command, args),
         DebugCommand::Index(args) => cmd_debug_index(ui, command, args),
@@ -102,6 +116,7 @@ pub fn cmd_debug(
         DebugCommand::Reindex(args) => cmd_debug_reindex(ui, command, args),
         DebugCommand::Revset(args) => cmd_debug_revset(ui, command, args),
         DebugCommand::Snapshot(args) => cmd_debug_snapshot(ui, command, args),
+        DebugCommand::SnapshotBench(args) => cmd_debug_snapshot_bench(ui, command, args),
         DebugCommand::Template(args) => cmd_debug_template(ui, command, args),
         DebugCommand::Tree(args) => cmd_debug_tree(ui, command, args),
         DebugCommand::Watchman(args) => cmd_debug_watchman(ui, command, args),
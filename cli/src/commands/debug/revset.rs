@@ -13,11 +13,20 @@
 // limitations under the License.
 
 use std::fmt::Debug;
-use std::io::Write as _;
+use std::io;
+use std::io::Write;
 
+use itertools::Itertools as _;
+use jj_lib::default_index::DefaultReadonlyIndex;
 use jj_lib::object_id::ObjectId as _;
+use jj_lib::repo::Repo;
 use jj_lib::revset;
+use jj_lib::revset::GENERATION_RANGE_FULL;
+use jj_lib::revset::PARENTS_RANGE_FULL;
+use jj_lib::revset::ResolvedExpression;
+use jj_lib::revset::ResolvedPredicateExpression;
 use jj_lib::revset::RevsetDiagnostics;
+use jj_lib::revset::RevsetFilterPredicate;
 
 use crate::cli_util::CommandHelper;
 use crate::command_error::CommandError;
@@ -35,6 +44,16 @@ pub struct DebugRevsetArgs {
     /// Do not rewrite expression to optimized form
     #[arg(long)]
     no_optimize: bool,
+    /// Explain how the expression is evaluated against the index
+    ///
+    /// Prints the backend expression tree with the number of commits each
+    /// node yields, and whether file predicates can use the changed-path
+    /// index. The numbers may be estimated unless `--count` is specified.
+    #[arg(long, conflicts_with = "no_resolve")]
+    explain: bool,
+    /// Count the commits of each node exactly in the `--explain` output
+    #[arg(long, requires = "explain")]
+    count: bool,
 }
 
 pub fn cmd_debug_revset(
@@ -85,6 +104,21 @@ pub fn cmd_debug_revset(
     writeln!(ui.stdout(), "{backend_expression:#?}")?;
     writeln!(ui.stdout())?;
 
+    if args.explain {
+        let changed_path_index_enabled = repo
+            .readonly_index()
+            .downcast_ref::<DefaultReadonlyIndex>()
+            .is_some_and(|index| index.changed_path_commits_range().is_some());
+        let explainer = RevsetExplainer {
+            repo,
+            count_exactly: args.count,
+            changed_path_index_enabled,
+        };
+        writeln!(ui.stdout(), "-- Explain:")?;
+        explainer.write_expression(&mut ui.stdout(), 0, None, &backend_expression)?;
+        return Ok(());
+    }
+
     let revset = expression.evaluate_unoptimized(repo)?;
     writeln!(ui.stdout(), "-- Evaluated:")?;
     writeln!(ui.stdout(), "{revset:#?}")?;
@@ -96,3 +130,225 @@ pub fn cmd_debug_revset(
     }
     Ok(())
 }
+
+struct RevsetExplainer<'a> {
+    repo: &'a dyn Repo,
+    count_exactly: bool,
+    changed_path_index_enabled: bool,
+}
+
+impl RevsetExplainer<'_> {
+    fn write_expression(
+        &self,
+        out: &mut dyn Write,
+        depth: usize,
+        role: Option<&str>,
+        expression: &ResolvedExpression,
+    ) -> Result<(), CommandError> {
+        let revset = self
+            .repo
+            .index()
+            .evaluate_revset(expression, self.repo.store())?;
+        let count = if self.count_exactly {
+            revset
+                .iter()
+                .process_results(|iter| iter.count())?
+                .to_string()
+        } else {
+            match revset.count_estimate()? {
+                (lower, Some(upper)) if lower == upper => lower.to_string(),
+                (lower, Some(upper)) => format!("{lower}..={upper}"),
+                (lower, None) => format!("{lower}.."),
+            }
+        };
+        write_node_prefix(out, depth, role)?;
+        writeln!(
+            out,
+            "{label} (commits: {count})",
+            label = expression_label(expression)
+        )?;
+
+        type E = ResolvedExpression;
+        match expression {
+            E::Commits(_) => {}
+            E::Ancestors { heads, .. } => {
+                self.write_expression(out, depth + 1, Some("heads"), heads)?;
+            }
+            E::Range { roots, heads, .. }
+            | E::DagRange { roots, heads, .. }
+            | E::HeadsRange { roots, heads, .. } => {
+                self.write_expression(out, depth + 1, Some("roots"), roots)?;
+                self.write_expression(out, depth + 1, Some("heads"), heads)?;
+                if let E::HeadsRange {
+                    filter: Some(filter),
+                    ..
+                } = expression
+                {
+                    self.write_predicate(out, depth + 1, Some("filter"), filter)?;
+                }
+            }
+            E::Reachable { sources, domain } => {
+                self.write_expression(out, depth + 1, Some("sources"), sources)?;
+                self.write_expression(out, depth + 1, Some("domain"), domain)?;
+            }
+            E::Heads(candidates)
+            | E::Roots(candidates)
+            | E::ForkPoint(candidates)
+            | E::Bisect(candidates)
+            | E::HasSize { candidates, .. }
+            | E::Latest { candidates, .. } => {
+                self.write_expression(out, depth + 1, None, candidates)?;
+            }
+            E::Coalesce(expression1, expression2)
+            | E::Union(expression1, expression2)
+            | E::Intersection(expression1, expression2)
+            | E::Difference(expression1, expression2) => {
+                self.write_expression(out, depth + 1, None, expression1)?;
+                self.write_expression(out, depth + 1, None, expression2)?;
+            }
+            E::FilterWithin {
+                candidates,
+                predicate,
+            } => {
+                self.write_expression(out, depth + 1, Some("candidates"), candidates)?;
+                self.write_predicate(out, depth + 1, Some("predicate"), predicate)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_predicate(
+        &self,
+        out: &mut dyn Write,
+        depth: usize,
+        role: Option<&str>,
+        predicate: &ResolvedPredicateExpression,
+    ) -> Result<(), CommandError> {
+        type P = ResolvedPredicateExpression;
+        match predicate {
+            P::Filter(filter) => {
+                write_node_prefix(out, depth, role)?;
+                write!(out, "Filter {}", filter_label(filter))?;
+                if matches!(
+                    filter,
+                    RevsetFilterPredicate::File(_) | RevsetFilterPredicate::DiffContains { .. }
+                ) {
+                    let used = if self.changed_path_index_enabled {
+                        "yes"
+                    } else {
+                        "no"
+                    };
+                    write!(out, " (changed-path index: {used})")?;
+                }
+                writeln!(out)?;
+            }
+            P::Set(expression) => self.write_expression(out, depth, role, expression)?,
+            P::NotIn(predicate) => {
+                write_node_prefix(out, depth, role)?;
+                writeln!(out, "NotIn")?;
+                self.write_predicate(out, depth + 1, None, predicate)?;
+            }
+            P::Union(predicate1, predicate2) | P::Intersection(predicate1, predicate2) => {
+                write_node_prefix(out, depth, role)?;
+                let label = if matches!(predicate, P::Union(..)) {
+                    "Union"
+                } else {
+                    "Intersection"
+                };
+                writeln!(out, "{label}")?;
+                self.write_predicate(out, depth + 1, None, predicate1)?;
+                self.write_predicate(out, depth + 1, None, predicate2)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn write_node_prefix(out: &mut dyn Write, depth: usize, role: Option<&str>) -> io::Result<()> {
+    write!(out, "{:indent$}", "", indent = depth * 2)?;
+    if let Some(role) = role {
+        write!(out, "{role}: ")?;
+    }
+    Ok(())
+}
+
+fn expression_label(expression: &ResolvedExpression) -> String {
+    type E = ResolvedExpression;
+    let mut label = match expression {
+        E::Commits(_) => "Commits",
+        E::Ancestors { .. } => "Ancestors",
+        E::Range { .. } => "Range",
+        E::DagRange { .. } => "DagRange",
+        E::Reachable { .. } => "Reachable",
+        E::Heads(_) => "Heads",
+        E::HeadsRange { .. } => "HeadsRange",
+        E::Roots(_) => "Roots",
+        E::ForkPoint(_) => "ForkPoint",
+        E::Bisect(_) => "Bisect",
+        E::HasSize { .. } => "HasSize",
+        E::Latest { .. } => "Latest",
+        E::Coalesce(..) => "Coalesce",
+        E::Union(..) => "Union",
+        E::FilterWithin { .. } => "FilterWithin",
+        E::Intersection(..) => "Intersection",
+        E::Difference(..) => "Difference",
+    }
+    .to_owned();
+    match expression {
+        E::Ancestors {
+            generation,
+            parents_range,
+            ..
+        }
+        | E::Range {
+            generation,
+            parents_range,
+            ..
+        } => {
+            if *generation != GENERATION_RANGE_FULL {
+                label += &format!(" generation={generation:?}");
+            }
+            if *parents_range != PARENTS_RANGE_FULL {
+                label += &format!(" parents={parents_range:?}");
+            }
+        }
+        E::DagRange {
+            generation_from_roots,
+            ..
+        } => {
+            if *generation_from_roots != GENERATION_RANGE_FULL {
+                label += &format!(" generation={generation_from_roots:?}");
+            }
+        }
+        E::HeadsRange { parents_range, .. } => {
+            if *parents_range != PARENTS_RANGE_FULL {
+                label += &format!(" parents={parents_range:?}");
+            }
+        }
+        E::HasSize { count, .. } | E::Latest { count, .. } => {
+            label += &format!(" count={count}");
+        }
+        _ => {}
+    }
+    label
+}
+
+fn filter_label(filter: &RevsetFilterPredicate) -> &'static str {
+    type F = RevsetFilterPredicate;
+    match filter {
+        F::ParentCount(_) => "ParentCount",
+        F::Description(_) => "Description",
+        F::Subject(_) => "Subject",
+        F::AuthorName(_) => "AuthorName",
+        F::AuthorEmail(_) => "AuthorEmail",
+        F::AuthorDate(_) => "AuthorDate",
+        F::CommitterName(_) => "CommitterName",
+        F::CommitterEmail(_) => "CommitterEmail",
+        F::CommitterDate(_) => "CommitterDate",
+        F::File(_) => "File",
+        F::DiffContains { .. } => "DiffContains",
+        F::HasConflict => "HasConflict",
+        F::Signed => "Signed",
+        F::Extension(_) => "Extension",
+    }
+}
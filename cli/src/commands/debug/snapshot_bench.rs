@@ -0,0 +1,61 @@
+// Copyright 2025 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write as _;
+use std::time::Instant;
+
+use pollster::FutureExt as _;
+
+use crate::cli_util::CommandHelper;
+use crate::command_error::CommandError;
+use crate::ui::Ui;
+
+/// Time a snapshot of the working copy without recording it
+///
+/// Scans the working copy like a regular snapshot does, and reports the number
+/// of files scanned, the number and total size of the files whose contents
+/// were hashed, and the time taken. The snapshot is discarded, so neither the
+/// working-copy commit nor the operation log is changed. File contents hashed
+/// by the snapshot may still be written to the backend.
+#[derive(clap::Args, Clone, Debug)]
+pub struct DebugSnapshotBenchArgs {}
+
+pub fn cmd_debug_snapshot_bench(
+    ui: &mut Ui,
+    command: &CommandHelper,
+    _args: &DebugSnapshotBenchArgs,
+) -> Result<(), CommandError> {
+    let mut workspace_command = command.workspace_helper_no_snapshot(ui)?;
+    let auto_tracking_matcher = workspace_command.auto_tracking_matcher(ui)?;
+    let options =
+        workspace_command.snapshot_options_with_start_tracking_matcher(&auto_tracking_matcher)?;
+    let (mut locked_ws, _wc_commit) = workspace_command.start_working_copy_mutation()?;
+
+    let start = Instant::now();
+    let (_new_tree, stats) = locked_ws.locked_wc().snapshot(&options).block_on()?;
+    let elapsed = start.elapsed();
+    // The working copy is unlocked without finishing the mutation, so the
+    // updated file states aren't saved.
+    drop(locked_ws);
+
+    writeln!(ui.stdout(), "Files scanned: {}", stats.scanned_files)?;
+    writeln!(
+        ui.stdout(),
+        "Files hashed: {} ({} bytes)",
+        stats.hashed_files,
+        stats.hashed_bytes
+    )?;
+    writeln!(ui.stdout(), "Snapshot took {elapsed:?}")?;
+    Ok(())
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+use std::slice;
+
 use clap_complete::ArgValueCandidates;
 use clap_complete::ArgValueCompleter;
 use itertools::Itertools as _;
@@ -22,6 +25,9 @@ use jj_lib::graph::GraphEdge;
 use jj_lib::graph::TopoGroupedGraphIterator;
 use jj_lib::graph::reverse_graph;
 use jj_lib::matchers::EverythingMatcher;
+use jj_lib::op_store::OperationId;
+use jj_lib::op_walk;
+use jj_lib::operation::Operation;
 use pollster::FutureExt as _;
 use tracing::instrument;
 
@@ -64,6 +70,19 @@ pub(crate) struct EvologArgs {
     /// Don't show the graph, show a flat list of revisions
     #[arg(long, short = 'G')]
     no_graph: bool,
+    /// Show only the entries created by this operation
+    ///
+    /// Predecessors created by other operations are elided from the graph.
+    #[arg(
+        long,
+        value_name = "OPERATION",
+        add = ArgValueCandidates::new(complete::operations),
+    )]
+    op: Option<String>,
+    /// With `--op`, also show the entries created by descendants of the
+    /// operation
+    #[arg(long, requires = "op")]
+    op_range: bool,
     /// Render each revision using the given template
     ///
     /// All 0-argument methods of the [`CommitEvolutionEntry` type] are
@@ -86,6 +105,21 @@ pub(crate) struct EvologArgs {
     /// contaminated by unrelated changes.
     #[arg(long, short = 'p')]
     patch: bool,
+    /// Detect renamed and copied files in the patch
+    ///
+    /// Files are considered renamed if their contents are at least the given
+    /// percentage similar (50% by default). Renames are only detected between
+    /// versions of the change which have the same parents, and the backend
+    /// doesn't report renames less than 50% similar.
+    #[arg(
+        long,
+        value_name = "THRESHOLD",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "50",
+        value_parser = clap::value_parser!(u8).range(50..=100),
+    )]
+    find_renames: Option<u8>,
     #[command(flatten)]
     diff_format: DiffFormatArgs,
 }
@@ -98,6 +132,18 @@ pub(crate) fn cmd_evolog(
 ) -> Result<(), CommandError> {
     let workspace_command = command.workspace_helper(ui)?;
 
+    let op_ids = if let Some(op_str) = &args.op {
+        let op = workspace_command.resolve_single_op(op_str)?;
+        let op_ids = if args.op_range {
+            descendant_op_ids(workspace_command.repo().operation(), &op)?
+        } else {
+            HashSet::from([op.id().clone()])
+        };
+        Some(op_ids)
+    } else {
+        None
+    };
+
     let start_commit_ids: Vec<_> = workspace_command
         .parse_union_revsets(ui, &args.revisions)?
         .evaluate_to_commit_ids()?
@@ -136,13 +182,48 @@ pub(crate) fn cmd_evolog(
 
     let repo = workspace_command.repo();
     let evolution_entries = walk_predecessors(repo, &start_commit_ids);
+    // If entries are filtered by operation, predecessors that aren't shown
+    // are rendered as missing edges.
+    let (evolution_entries, shown_commit_ids): (Box<dyn Iterator<Item = _>>, _) =
+        if let Some(op_ids) = &op_ids {
+            let entries: Vec<_> = evolution_entries
+                .filter_ok(|entry| {
+                    entry
+                        .operation
+                        .as_ref()
+                        .is_some_and(|op| op_ids.contains(op.id()))
+                })
+                .try_collect()?;
+            let commit_ids: HashSet<_> = entries
+                .iter()
+                .map(|entry| entry.commit.id().clone())
+                .collect();
+            (Box::new(entries.into_iter().map(Ok)), Some(commit_ids))
+        } else {
+            (Box::new(evolution_entries), None)
+        };
     if !args.no_graph {
         let mut raw_output = formatter.raw()?;
         let mut graph = get_graphlog(graph_style, raw_output.as_mut());
 
         let evolution_nodes = evolution_entries.map_ok(|entry| {
             let ids = entry.predecessor_ids();
-            let edges = ids.iter().cloned().map(GraphEdge::direct).collect_vec();
+            let edges = match &shown_commit_ids {
+                Some(shown_ids) => {
+                    let (shown, hidden): (Vec<_>, Vec<_>) =
+                        ids.iter().partition(|id| shown_ids.contains(id));
+                    let mut edges = shown
+                        .into_iter()
+                        .cloned()
+                        .map(GraphEdge::direct)
+                        .collect_vec();
+                    if let Some(id) = hidden.first() {
+                        edges.push(GraphEdge::missing((*id).clone()));
+                    }
+                    edges
+                }
+                None => ids.iter().cloned().map(GraphEdge::direct).collect_vec(),
+            };
             (entry, edges)
         });
         // TopoGroupedGraphIterator also helps emit squashed commits in reverse
@@ -187,6 +268,7 @@ pub(crate) fn cmd_evolog(
                         &predecessors,
                         &entry.commit,
                         &EverythingMatcher,
+                        args.find_renames,
                         within_graph.width(),
                     )
                     .block_on()?;
@@ -221,6 +303,7 @@ pub(crate) fn cmd_evolog(
                         &predecessors,
                         &entry.commit,
                         &EverythingMatcher,
+                        args.find_renames,
                         width,
                     )
                     .block_on()?;
@@ -230,3 +313,22 @@ pub(crate) fn cmd_evolog(
 
     Ok(())
 }
+
+/// Returns the ids of `root_op` and its descendants which are ancestors of
+/// `head_op`.
+fn descendant_op_ids(
+    head_op: &Operation,
+    root_op: &Operation,
+) -> Result<HashSet<OperationId>, CommandError> {
+    let root_parents: Vec<_> = root_op.parents().try_collect()?;
+    let ops: Vec<_> =
+        op_walk::walk_ancestors_range(slice::from_ref(head_op), &root_parents).try_collect()?;
+    let mut op_ids = HashSet::from([root_op.id().clone()]);
+    // Visit operations in topological order so parents are checked first.
+    for op in ops.iter().rev() {
+        if op.parent_ids().iter().any(|id| op_ids.contains(id)) {
+            op_ids.insert(op.id().clone());
+        }
+    }
+    Ok(op_ids)
+}
@@ -18,6 +18,7 @@ use std::io::Write as _;
 use std::num::NonZeroU32;
 use std::path::Path;
 
+use itertools::Itertools as _;
 use jj_lib::git;
 use jj_lib::git::FetchTagsOverride;
 use jj_lib::git::GitFetch;
@@ -58,7 +59,11 @@ pub struct GitCloneArgs {
     #[arg(value_hint = clap::ValueHint::DirPath)]
     destination: Option<String>,
     /// Name of the newly created remote
-    #[arg(long = "remote", default_value = "origin")]
+    #[arg(
+        long = "remote",
+        visible_alias = "remote-name",
+        default_value = "origin"
+    )]
     remote_name: RemoteNameBuf,
     /// Colocate the Jujutsu repo with the git repo
     ///
@@ -101,7 +106,9 @@ pub struct GitCloneArgs {
     /// change
     ///
     /// If not present, all branches are fetched and the repository's default
-    /// branch is used as parent of the working-copy change.
+    /// branch is used as parent of the working-copy change. Otherwise, only
+    /// the matching branches are fetched, and local bookmarks tracking them
+    /// are created.
     ///
     /// By default, the specified name matches exactly. Use `glob:` prefix to
     /// expand `*` as a glob, e.g. `--branch 'glob:push-*'`. Other wildcard
@@ -328,6 +335,7 @@ fn fetch_new_remote(
                 fetch_refspecs,
                 cb,
                 depth,
+                None,
                 match fetch_tags {
                     // If not explicitly specified on the CLI, override the remote
                     // configuration and fetch all tags by default since this is
@@ -374,6 +382,26 @@ fn fetch_new_remote(
         None => default_branch.clone(),
     };
 
+    if let Some(target_branches) = target_branches {
+        // Track all the selected branches since they were explicitly requested.
+        let names_to_track = tx
+            .repo()
+            .view()
+            .remote_bookmarks(remote_name)
+            .filter(|(name, remote_ref)| {
+                !remote_ref.is_tracked()
+                    && target_branches
+                        .iter()
+                        .any(|pattern| pattern.is_match(name.as_str()))
+            })
+            .map(|(name, _)| name.to_owned())
+            .collect_vec();
+        for name in &names_to_track {
+            tx.repo_mut()
+                .track_remote_bookmark(name.to_remote_symbol(remote_name))?;
+        }
+    }
+
     let working_is_default = working_branch == default_branch;
     if let Some(name) = &working_branch
         && working_is_default
@@ -386,7 +414,7 @@ fn fetch_new_remote(
             tx.repo_mut().track_remote_bookmark(remote_symbol)?;
         }
     }
-    print_git_import_stats(ui, tx.repo(), &import_stats, true)?;
+    print_git_import_stats(ui, tx.repo(), &import_stats, true, false)?;
     if git_settings.auto_local_bookmark && !should_track_default {
         writeln!(
             ui.hint_default(),
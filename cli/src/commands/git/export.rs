@@ -12,10 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use clap_complete::ArgValueCandidates;
+use itertools::Itertools as _;
 use jj_lib::git;
+use jj_lib::git::GitRefKind;
+use jj_lib::ref_name::RefNameBuf;
+use jj_lib::repo::Repo as _;
+use jj_lib::str_util::StringPattern;
 
 use crate::cli_util::CommandHelper;
+use crate::cli_util::WorkspaceCommandTransaction;
 use crate::command_error::CommandError;
+use crate::complete;
 use crate::git_util::print_git_export_stats;
 use crate::ui::Ui;
 
@@ -24,17 +32,109 @@ use crate::ui::Ui;
 /// There is no need to run this command if you're in colocated workspace
 /// because the export happens automatically there.
 #[derive(clap::Args, Clone, Debug)]
-pub struct GitExportArgs {}
+pub struct GitExportArgs {
+    /// Export only some of the branches
+    ///
+    /// By default, the specified name matches exactly. Use `glob:` prefix to
+    /// expand `*` as a glob, e.g. `--branch 'glob:release/*'`. Can be repeated
+    /// to specify multiple branches. Only local bookmarks of the matching
+    /// names are exported. Tags aren't exported if this option is specified.
+    ///
+    /// In colocated workspaces, all bookmarks are exported automatically when
+    /// a command finishes, so this option has no effect there.
+    #[arg(
+        long, short,
+        alias = "bookmark",
+        value_parser = StringPattern::parse,
+        add = ArgValueCandidates::new(complete::local_bookmarks),
+    )]
+    branch: Vec<StringPattern>,
+}
 
 pub fn cmd_git_export(
     ui: &mut Ui,
     command: &CommandHelper,
-    _args: &GitExportArgs,
+    args: &GitExportArgs,
 ) -> Result<(), CommandError> {
     let mut workspace_command = command.workspace_helper(ui)?;
     let mut tx = workspace_command.start_transaction();
-    let stats = git::export_refs(tx.repo_mut())?;
+    if args.branch.is_empty() {
+        let stats = git::export_refs(tx.repo_mut())?;
+        tx.finish(ui, "export git refs")?;
+        print_git_export_stats(ui, &stats)?;
+        return Ok(());
+    }
+
+    let is_match = |name: &str| args.branch.iter().any(|pattern| pattern.is_match(name));
+    let (matched_names, skipped_names): (Vec<RefNameBuf>, Vec<RefNameBuf>) = tx
+        .repo()
+        .view()
+        .local_remote_bookmarks(git::REMOTE_NAME_FOR_LOCAL_GIT_REPO)
+        .filter(|(_, targets)| targets.local_target != &targets.remote_ref.target)
+        .map(|(name, _)| name.to_owned())
+        .partition(|name| is_match(name.as_str()));
+    warn_if_branches_not_found(ui, &tx, &args.branch)?;
+    let stats = git::export_some_refs(tx.repo_mut(), |kind, symbol| match kind {
+        GitRefKind::Bookmark => {
+            symbol.remote == git::REMOTE_NAME_FOR_LOCAL_GIT_REPO && is_match(symbol.name.as_str())
+        }
+        GitRefKind::Tag => false,
+    })?;
+    let exported_names = matched_names
+        .iter()
+        .filter(|name| {
+            !stats
+                .failed_bookmarks
+                .iter()
+                .any(|(symbol, _)| symbol.name.as_str() == name.as_str())
+        })
+        .collect_vec();
+    if let Some(mut formatter) = ui.status_formatter() {
+        if !exported_names.is_empty() {
+            writeln!(
+                formatter,
+                "Exported bookmarks: {}",
+                exported_names
+                    .iter()
+                    .map(|name| name.as_symbol())
+                    .join(", ")
+            )?;
+        }
+        if !skipped_names.is_empty() {
+            writeln!(
+                formatter,
+                "Skipped bookmarks: {}",
+                skipped_names.iter().map(|name| name.as_symbol()).join(", ")
+            )?;
+        }
+    }
     tx.finish(ui, "export git refs")?;
     print_git_export_stats(ui, &stats)?;
     Ok(())
 }
+
+fn warn_if_branches_not_found(
+    ui: &Ui,
+    tx: &WorkspaceCommandTransaction,
+    branches: &[StringPattern],
+) -> Result<(), CommandError> {
+    let missing_branches = branches
+        .iter()
+        .filter(|branch| {
+            let branch_matcher = branch.to_matcher();
+            tx.repo()
+                .view()
+                .local_bookmarks_matching(&branch_matcher)
+                .next()
+                .is_none()
+        })
+        .collect_vec();
+    if !missing_branches.is_empty() {
+        writeln!(
+            ui.warning_default(),
+            "No branch matching {} found",
+            missing_branches.iter().map(|b| format!("`{b}`")).join(", ")
+        )?;
+    }
+    Ok(())
+}
@@ -12,31 +12,56 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::collections::HashSet;
+use std::fmt;
+use std::io;
+use std::num::NonZeroU32;
+use std::num::NonZeroUsize;
+use std::thread;
+use std::time::Duration;
 
 use clap_complete::ArgValueCandidates;
 use itertools::Itertools as _;
+use jj_lib::backend::MillisSinceEpoch;
 use jj_lib::config::ConfigGetResultExt as _;
+use jj_lib::default_index::DefaultIndexStore;
 use jj_lib::git;
+use jj_lib::git::FetchTagsOverride;
 use jj_lib::git::GitFetch;
+use jj_lib::git::GitFetchError;
+use jj_lib::git::GitImportStats;
 use jj_lib::git::IgnoredRefspec;
 use jj_lib::git::IgnoredRefspecs;
 use jj_lib::git::expand_default_fetch_refspecs;
 use jj_lib::git::expand_fetch_refspecs;
 use jj_lib::git::get_git_backend;
+use jj_lib::object_id::ObjectId as _;
+use jj_lib::op_store::RefTarget;
 use jj_lib::ref_name::RemoteName;
+use jj_lib::ref_name::RemoteNameBuf;
 use jj_lib::repo::Repo as _;
+use jj_lib::store::Store;
 use jj_lib::str_util::StringMatcher;
 use jj_lib::str_util::StringPattern;
+use jj_lib::str_util::StringPatternParseError;
+use jj_lib::time_util::DatePattern;
+use pollster::FutureExt as _;
+use unicode_width::UnicodeWidthStr as _;
 
 use crate::cli_util::CommandHelper;
 use crate::cli_util::WorkspaceCommandHelper;
 use crate::cli_util::WorkspaceCommandTransaction;
+use crate::cli_util::default_ignored_remote_name;
 use crate::command_error::CommandError;
 use crate::command_error::config_error;
+use crate::command_error::internal_error;
 use crate::command_error::user_error;
+use crate::command_error::user_error_with_hint;
+use crate::command_error::user_error_with_message;
 use crate::commands::git::get_single_remote;
 use crate::complete;
+use crate::formatter::FormatterExt as _;
 use crate::git_util::print_git_import_stats;
 use crate::git_util::with_remote_git_callbacks;
 use crate::ui::Ui;
@@ -53,13 +78,20 @@ pub struct GitFetchArgs {
     /// expand `*` as a glob, e.g. `--branch 'glob:push-*'`. Other wildcard
     /// characters such as `?` are *not* supported. Can be repeated to specify
     /// multiple branches.
+    ///
+    /// A branch can be qualified by a remote name in `branch@remote` form,
+    /// e.g. `--branch 'glob:feature-*@upstream'`, to fetch it only from that
+    /// remote. The remote name matches exactly. If `--remote` or
+    /// `--all-remotes` is specified, the remote must be one of the selected
+    /// remotes. Otherwise, the remote is fetched even if it isn't one of the
+    /// default remotes.
     #[arg(
         long, short,
         alias = "bookmark",
-        value_parser = StringPattern::parse,
+        value_parser = parse_branch_pattern,
         add = ArgValueCandidates::new(complete::bookmarks),
     )]
-    branch: Vec<StringPattern>,
+    branch: Vec<BranchPattern>,
     /// Fetch only tracked bookmarks
     ///
     /// This fetches only bookmarks that are already tracked from the specified
@@ -71,23 +103,138 @@ pub struct GitFetchArgs {
     ///
     /// This defaults to the `git.fetch` setting. If that is not configured, and
     /// if there are multiple remotes, the remote named "origin" will be used.
+    /// If `git.fetch-mode` is set to `"fallback"`, the remotes in `git.fetch`
+    /// are tried in order, and only the first one that can be fetched from is
+    /// fetched.
     ///
     /// By default, the specified remote names matches exactly. Use a [string
     /// pattern], e.g. `--remote 'glob:*'`, to select remotes using
-    /// patterns.
+    /// patterns. A pattern prefixed with `~` excludes the matching remotes,
+    /// e.g. `--remote 'glob:*' --remote '~origin'`.
     ///
     /// [string pattern]:
     ///     https://jj-vcs.github.io/jj/latest/revsets#string-patterns
     #[arg(
         long = "remote",
         value_name = "REMOTE",
-        value_parser = StringPattern::parse,
+        value_parser = RemotePattern::parse,
         add = ArgValueCandidates::new(complete::git_remotes),
     )]
-    remotes: Vec<StringPattern>,
+    remotes: Vec<RemotePattern>,
     /// Fetch from all remotes
     #[arg(long, conflicts_with = "remotes")]
     all_remotes: bool,
+    /// Abort fetching from a remote after this many seconds
+    ///
+    /// This defaults to the `git.fetch-timeout` setting. When fetching from
+    /// multiple remotes, a remote that times out is skipped and the other
+    /// remotes are still fetched.
+    #[arg(long, value_name = "SECONDS", value_parser = clap::value_parser!(u64).range(1..))]
+    timeout: Option<u64>,
+    /// Retry fetching from a remote up to this many times
+    ///
+    /// Only connection failures, e.g. because the remote host couldn't be
+    /// reached or the connection was lost, are retried. Errors such as a
+    /// missing repository are not. The delay before each retry starts at
+    /// `--retry-delay` and doubles after every attempt.
+    #[arg(long, value_name = "N")]
+    retry: Option<u32>,
+    /// Number of seconds to wait before the first retry (default: 1)
+    #[arg(long, value_name = "SECONDS", requires = "retry")]
+    retry_delay: Option<u64>,
+    /// Fetch from up to this many remotes concurrently
+    ///
+    /// By default, remotes are fetched one after another. With a larger
+    /// number, the fetches run in parallel without showing progress, and the
+    /// results are reported in the order of the remote names. The remotes of
+    /// `git.fetch-mode = "fallback"` are still tried one after another.
+    ///
+    /// A remote that can't be reached is skipped with a warning, and the other
+    /// remotes are still fetched, unless `--atomic` is specified.
+    #[arg(long, value_name = "N")]
+    max_concurrent: Option<NonZeroUsize>,
+    /// Don't import anything if fetching from any of the remotes fails
+    ///
+    /// By default, remotes that time out, or that can't be reached with
+    /// `--max-concurrent`, are skipped with a warning, and the changes fetched
+    /// from the other remotes are imported.
+    #[arg(long)]
+    atomic: bool,
+    /// Delete local bookmarks whose remote bookmarks were deleted
+    ///
+    /// A local bookmark is deleted only if it still points to the same target
+    /// as the deleted remote bookmark, and if no other remote bookmark of the
+    /// same name points to it. Local bookmarks that have been moved are kept.
+    #[arg(long)]
+    prune: bool,
+    /// Limit fetching to the given number of commits from the tip of each
+    /// remote branch
+    ///
+    /// Commits beyond the depth are not fetched, and their children appear as
+    /// if they had no parents. The history can be deepened later by fetching
+    /// with a larger depth or with `--unshallow`.
+    #[arg(long, conflicts_with = "unshallow")]
+    depth: Option<NonZeroU32>,
+    /// Limit fetching to the commits committed at or after the given date
+    ///
+    /// The date is parsed the same way as in the `after:` revset pattern, e.g.
+    /// `2024-01-01` or `"2 weeks ago"`. Older commits are not fetched, and the
+    /// oldest fetched commits appear as if they had no parents, like with
+    /// `--depth`.
+    #[arg(long, value_name = "DATE", conflicts_with_all = ["depth", "unshallow"])]
+    since: Option<String>,
+    /// Fetch the full history of a shallow repository
+    #[arg(long)]
+    unshallow: bool,
+    /// Record the changed remote bookmarks in the operation metadata
+    ///
+    /// The changes are recorded in the operation tag named
+    /// `fetched_bookmarks`. Each change has the `remote` and `name` of the
+    /// remote bookmark, its `kind` (`new`, `updated`, or `deleted`), and its
+    /// `old_target` and `new_target` commit ids. The changes can be read back
+    /// as a JSON array by `jj op show --format=json`.
+    #[arg(long)]
+    record_changes: bool,
+    /// Track the remote bookmarks newly created by this fetch
+    ///
+    /// Each new remote bookmark is tracked, and a local bookmark is created
+    /// for it, regardless of the `git.auto-local-bookmark` setting. Remote
+    /// bookmarks for which a local bookmark of the same name already exists are
+    /// left untracked.
+    #[arg(long)]
+    set_upstream: bool,
+    /// Fetch all tags from the remote(s)
+    ///
+    /// By default, tags are fetched as configured by the remote's `tagOpt`,
+    /// which usually means only tags pointing to fetched commits are fetched.
+    #[arg(long, conflicts_with = "no_tags")]
+    tags: bool,
+    /// Don't fetch any tags from the remote(s)
+    #[arg(long)]
+    no_tags: bool,
+    /// Number of threads to use for processing the objects fetched from each
+    /// remote
+    ///
+    /// Remotes are still fetched one after another; this only parallelizes the
+    /// indexing of the objects received from a single remote. By default,
+    /// Git's `pack.threads` setting is used, which uses one thread per CPU
+    /// unless configured otherwise.
+    #[arg(long, value_name = "N")]
+    jobs: Option<NonZeroU32>,
+    /// Import changes to the local branches of the colocated Git repo
+    ///
+    /// In colocated repos, changes to the local Git branches are imported
+    /// along with the fetched remote refs. With `false`, only the remote
+    /// bookmarks and tags are imported, and changes to the local Git branches
+    /// are left for the next command to import. This has no effect in
+    /// non-colocated repos.
+    #[arg(
+        long,
+        value_name = "BOOL",
+        default_value_t = true,
+        action = clap::ArgAction::Set,
+    )]
+    import_after: bool,
 }
 
 #[tracing::instrument(skip_all)]
@@ -96,39 +243,97 @@ pub fn cmd_git_fetch(
     command: &CommandHelper,
     args: &GitFetchArgs,
 ) -> Result<(), CommandError> {
-    let mut workspace_command = command.workspace_helper(ui)?;
+    let mut workspace_command = if args.import_after {
+        command.workspace_helper(ui)?
+    } else {
+        command.workspace_helper_no_git_refs_import(ui)?
+    };
+    let branches = &args.branch;
+    // If all branches are qualified by remote names, the default remotes
+    // aren't needed.
+    let has_unqualified_branches =
+        branches.is_empty() || branches.iter().any(|branch| branch.remote.is_none());
     let remote_patterns = if args.all_remotes {
-        vec![StringPattern::all()]
-    } else if args.remotes.is_empty() {
+        vec![RemotePattern::new(StringPattern::all())]
+    } else if !args.remotes.is_empty() {
+        args.remotes.clone()
+    } else if has_unqualified_branches {
         get_default_fetch_remotes(ui, &workspace_command)?
     } else {
-        args.remotes.clone()
+        vec![]
     };
+    // The fallback mode only applies to the remotes selected by the config.
+    let fallback = args.remotes.is_empty()
+        && !args.all_remotes
+        && branches.iter().all(|branch| branch.remote.is_none())
+        && workspace_command
+            .settings()
+            .get::<FetchMode>("git.fetch-mode")?
+            == FetchMode::Fallback;
 
     let all_remotes = git::get_all_remote_names(workspace_command.repo().store())?;
 
     let mut matching_remotes = HashSet::new();
-    for pattern in remote_patterns {
+    let mut excluded_remotes = HashSet::new();
+    for pattern in &remote_patterns {
         let remotes = all_remotes
             .iter()
-            .filter(|r| pattern.is_match(r.as_str()))
+            .filter(|r| pattern.pattern.is_match(r.as_str()))
             .collect_vec();
         if remotes.is_empty() {
             writeln!(ui.warning_default(), "No git remotes matching '{pattern}'")?;
+        } else if pattern.negated {
+            excluded_remotes.extend(remotes);
         } else {
             matching_remotes.extend(remotes);
         }
     }
+    // Exclusions apply after all the positive patterns have been unioned
+    matching_remotes.retain(|remote| !excluded_remotes.contains(remote));
+    let has_selected_remotes = args.all_remotes || !args.remotes.is_empty();
+    for name in args
+        .branch
+        .iter()
+        .filter_map(|branch| branch.remote.as_ref())
+    {
+        let remote = all_remotes
+            .iter()
+            .find(|remote| *remote == name)
+            .ok_or_else(|| user_error(format!("No git remote named '{}'", name.as_symbol())))?;
+        if has_selected_remotes && !matching_remotes.contains(remote) {
+            return Err(user_error_with_hint(
+                format!("Remote {} is not selected by --remote", remote.as_symbol()),
+                "Specify the remote with `--remote` too, or omit `--remote`.",
+            ));
+        }
+        matching_remotes.insert(remote);
+    }
 
     if matching_remotes.is_empty() {
         return Err(user_error("No git remotes to fetch from"));
     }
 
-    let remotes = matching_remotes
-        .iter()
-        .map(|r| r.as_ref())
-        .sorted()
-        .collect_vec();
+    let remotes = if fallback {
+        // Try the remotes in the order of the patterns they first matched
+        remote_patterns
+            .iter()
+            .filter(|pattern| !pattern.negated)
+            .flat_map(|pattern| {
+                all_remotes
+                    .iter()
+                    .filter(|r| pattern.pattern.is_match(r.as_str()))
+            })
+            .filter(|r| matching_remotes.contains(r))
+            .map(|r| r.as_ref())
+            .unique()
+            .collect_vec()
+    } else {
+        matching_remotes
+            .iter()
+            .map(|r| r.as_ref())
+            .sorted()
+            .collect_vec()
+    };
 
     let mut tx = workspace_command.start_transaction();
 
@@ -144,7 +349,7 @@ pub fn cmd_git_fetch(
                 .collect_vec();
             expansions.push((remote, expand_fetch_refspecs(remote, tracked_branches)?));
         }
-    } else if args.branch.is_empty() {
+    } else if branches.is_empty() {
         let git_repo = get_git_backend(tx.repo_mut().store())?.git_repo();
         for remote in &remotes {
             let (ignored, expanded) = expand_default_fetch_refspecs(remote, &git_repo)?;
@@ -153,23 +358,154 @@ pub fn cmd_git_fetch(
         }
     } else {
         for remote in &remotes {
-            let expanded = expand_fetch_refspecs(remote, args.branch.clone())?;
+            let remote_branches = branches
+                .iter()
+                .filter(|branch| branch.matches_remote(remote))
+                .map(|branch| branch.pattern.clone())
+                .collect_vec();
+            if remote_branches.is_empty() {
+                continue;
+            }
+            let expanded = expand_fetch_refspecs(remote, remote_branches)?;
             expansions.push((remote, expanded));
         }
     };
 
-    let git_settings = tx.settings().git_settings()?;
+    let mut git_settings = tx.settings().git_settings()?;
+    if let Some(timeout) = args.timeout {
+        git_settings.fetch_timeout = Some(Duration::from_secs(timeout));
+    }
+    if let Some(jobs) = args.jobs {
+        git_settings.fetch_jobs = Some(jobs);
+    }
+    // Git treats this depth as infinite.
+    let depth = if args.unshallow {
+        NonZeroU32::new(i32::MAX as u32)
+    } else {
+        args.depth
+    };
+    let shallow_since = args.since.as_deref().map(parse_since_date).transpose()?;
+    let fetch_tags_override = if args.tags {
+        Some(FetchTagsOverride::AllTags)
+    } else if args.no_tags {
+        Some(FetchTagsOverride::NoTags)
+    } else {
+        None
+    };
+    let old_shallow_commits = shallow_commit_ids(tx.repo().store())?;
     let mut git_fetch = GitFetch::new(tx.repo_mut(), &git_settings)?;
 
-    for (remote, expanded) in expansions {
-        with_remote_git_callbacks(ui, |callbacks| {
-            git_fetch.fetch(remote, expanded, callbacks, None, None)
-        })?;
+    let max_retries = args.retry.unwrap_or(0);
+    let retry_delay = Duration::from_secs(args.retry_delay.unwrap_or(1));
+    let num_remotes_to_fetch = expansions.len();
+    let mut fetched_remotes = vec![];
+    if let Some(max_concurrent) = args.max_concurrent.filter(|_| !fallback) {
+        let mut pending = expansions
+            .into_iter()
+            .map(|(remote, expanded)| (*remote, expanded))
+            .collect_vec();
+        let mut attempt = 0;
+        let mut results = vec![];
+        while !pending.is_empty() {
+            let round_results = git_fetch.fetch_concurrently(
+                &pending,
+                max_concurrent,
+                depth,
+                shallow_since,
+                fetch_tags_override,
+            );
+            let delay = retry_delay.saturating_mul(2u32.saturating_pow(attempt));
+            let mut failed = vec![];
+            for ((remote, expanded), result) in pending.into_iter().zip(round_results) {
+                match result {
+                    Err(err) if err.is_transport_error() && attempt < max_retries => {
+                        write_retry_warning(ui, remote, attempt + 1, max_retries, delay, &err)?;
+                        failed.push((remote, expanded));
+                    }
+                    result => results.push((remote, result)),
+                }
+            }
+            if !failed.is_empty() {
+                attempt += 1;
+                thread::sleep(delay);
+            }
+            pending = failed;
+        }
+        results.sort_by_key(|(remote, _)| *remote);
+        for (remote, result) in results {
+            if check_fetch_result(
+                ui,
+                remote,
+                result,
+                fallback,
+                args.atomic,
+                true,
+                num_remotes_to_fetch,
+            )? {
+                fetched_remotes.push(remote);
+            }
+        }
+    } else {
+        for (remote, expanded) in expansions {
+            let mut attempt = 0;
+            let result = loop {
+                let result = with_remote_git_callbacks(ui, |callbacks| {
+                    git_fetch.fetch(
+                        remote,
+                        expanded.clone(),
+                        callbacks,
+                        depth,
+                        shallow_since,
+                        fetch_tags_override,
+                    )
+                });
+                match result {
+                    Err(err) if err.is_transport_error() && attempt < max_retries => {
+                        // Back off exponentially
+                        let delay = retry_delay.saturating_mul(2u32.saturating_pow(attempt));
+                        attempt += 1;
+                        write_retry_warning(ui, remote, attempt, max_retries, delay, &err)?;
+                        thread::sleep(delay);
+                    }
+                    result => break result,
+                }
+            };
+            if check_fetch_result(
+                ui,
+                remote,
+                result,
+                fallback,
+                args.atomic,
+                false,
+                num_remotes_to_fetch,
+            )? {
+                fetched_remotes.push(*remote);
+                if fallback {
+                    break;
+                }
+            }
+        }
+    }
+    if num_remotes_to_fetch > 0 && fetched_remotes.is_empty() {
+        return Err(user_error(format!(
+            "Failed to fetch from any of the remotes: {}",
+            remotes.iter().map(|n| n.as_symbol()).join(", ")
+        )));
     }
+    let remotes = if fallback { fetched_remotes } else { remotes };
 
     let import_stats = git_fetch.import_refs()?;
-    print_git_import_stats(ui, tx.repo(), &import_stats, true)?;
-    warn_if_branches_not_found(ui, &tx, &args.branch, &remotes)?;
+    if args.set_upstream {
+        track_new_bookmarks(&mut tx, &import_stats)?;
+    }
+    print_git_import_stats(ui, tx.repo(), &import_stats, true, false)?;
+    if args.record_changes {
+        record_bookmark_changes(&mut tx, &import_stats);
+    }
+    if args.prune {
+        prune_local_bookmarks(ui, &mut tx, &remotes, &import_stats)?;
+    }
+    warn_if_branches_not_found(ui, &tx, branches, &remotes)?;
     tx.finish(
         ui,
         format!(
@@ -177,15 +513,350 @@ pub fn cmd_git_fetch(
             remotes.iter().map(|n| n.as_symbol()).join(",")
         ),
     )?;
+    let new_shallow_commits = shallow_commit_ids(workspace_command.repo().store())?;
+    if old_shallow_commits
+        .iter()
+        .any(|id| !new_shallow_commits.contains(id))
+    {
+        reindex_deepened_history(ui, &workspace_command)?;
+    }
     Ok(())
 }
 
+/// Reports that fetching from `remote` failed and will be retried after
+/// `delay`. `attempt` is the 1-based number of the failed attempt.
+fn write_retry_warning(
+    ui: &Ui,
+    remote: &RemoteName,
+    attempt: u32,
+    max_retries: u32,
+    delay: Duration,
+    err: &GitFetchError,
+) -> io::Result<()> {
+    writeln!(
+        ui.warning_default(),
+        "Failed to fetch from remote {} (attempt {attempt} of {}), retrying in {delay:?}: {err}",
+        remote.as_symbol(),
+        max_retries + 1
+    )
+}
+
+/// Reports the failure to fetch from `remote`. Returns whether the fetch
+/// succeeded, or an error if the command should be aborted.
+fn check_fetch_result(
+    ui: &Ui,
+    remote: &RemoteName,
+    result: Result<(), GitFetchError>,
+    fallback: bool,
+    atomic: bool,
+    skip_failed_remotes: bool,
+    num_remotes_to_fetch: usize,
+) -> Result<bool, CommandError> {
+    match result {
+        Ok(()) => Ok(true),
+        Err(err @ GitFetchError::Subprocess(_)) if fallback && !atomic => {
+            writeln!(
+                ui.warning_default(),
+                "Failed to fetch from remote {}: {err}",
+                remote.as_symbol()
+            )?;
+            Ok(false)
+        }
+        Err(err)
+            if err.is_retryable()
+                || (skip_failed_remotes && matches!(err, GitFetchError::Subprocess(_))) =>
+        {
+            let message = format!("Failed to fetch from remote {}", remote.as_symbol());
+            if atomic || num_remotes_to_fetch == 1 {
+                return Err(user_error_with_message(message, err));
+            }
+            writeln!(ui.warning_default(), "{message}: {err}")?;
+            Ok(false)
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Returns the ids of the commits at the shallow boundary.
+fn shallow_commit_ids(store: &Store) -> Result<HashSet<gix::ObjectId>, CommandError> {
+    let git_repo = git::get_git_repo(store)?;
+    let shallow_commits = git_repo.shallow_commits().map_err(internal_error)?;
+    Ok(shallow_commits.map_or_else(HashSet::new, |ids| ids.iter().copied().collect()))
+}
+
+/// Rebuilds the index after the shallow boundary moved. The commits which
+/// used to be at the boundary are indexed as children of the root commit, so
+/// their actual parents wouldn't be reachable otherwise.
+fn reindex_deepened_history(
+    ui: &Ui,
+    workspace_command: &WorkspaceCommandHelper,
+) -> Result<(), CommandError> {
+    let repo = workspace_command.repo();
+    let index_store = repo.loader().index_store();
+    let Some(default_index_store) = index_store.downcast_ref::<DefaultIndexStore>() else {
+        return Ok(());
+    };
+    // Cached commits may still have the root commit as their parent.
+    repo.store().clear_caches();
+    default_index_store.reinit().map_err(internal_error)?;
+    let default_index = default_index_store
+        .build_index_at_operation(repo.operation(), repo.store())
+        .block_on()
+        .map_err(internal_error)?;
+    writeln!(
+        ui.status(),
+        "Reindexed {} commits after fetching deeper history.",
+        default_index.num_commits()
+    )?;
+    Ok(())
+}
+
+fn parse_since_date(date: &str) -> Result<MillisSinceEpoch, CommandError> {
+    let pattern = DatePattern::from_str_kind(date, "after", chrono::Local::now())
+        .map_err(|err| user_error_with_message(format!("Invalid date: {date}"), err))?;
+    match pattern {
+        DatePattern::AtOrAfter(time) => Ok(time),
+        DatePattern::Before(_) => unreachable!(),
+    }
+}
+
+/// Tracks the remote bookmarks created by the fetch unless a local bookmark of
+/// the same name exists.
+fn track_new_bookmarks(
+    tx: &mut WorkspaceCommandTransaction,
+    stats: &GitImportStats,
+) -> Result<(), CommandError> {
+    for (symbol, (old_remote_ref, new_target)) in &stats.changed_remote_bookmarks {
+        if old_remote_ref.target.is_present() || new_target.is_absent() {
+            continue;
+        }
+        let view = tx.repo().view();
+        if view.get_remote_bookmark(symbol.as_ref()).is_tracked()
+            || view.get_local_bookmark(&symbol.name).is_present()
+        {
+            continue;
+        }
+        tx.repo_mut().track_remote_bookmark(symbol.as_ref())?;
+    }
+    Ok(())
+}
+
+/// Remote bookmark change recorded in the operation metadata.
+#[derive(Debug, serde::Serialize)]
+struct FetchedBookmarkChange<'a> {
+    remote: &'a str,
+    name: &'a str,
+    kind: FetchedBookmarkChangeKind,
+    /// Full ids of the target commits before the fetch.
+    old_target: Vec<String>,
+    /// Full ids of the target commits after the fetch.
+    new_target: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+enum FetchedBookmarkChangeKind {
+    New,
+    Updated,
+    Deleted,
+}
+
+/// Attaches the remote bookmark changes to the operation metadata.
+fn record_bookmark_changes(tx: &mut WorkspaceCommandTransaction, stats: &GitImportStats) {
+    let target_ids = |target: &RefTarget| target.added_ids().map(|id| id.hex()).collect_vec();
+    let changes = stats
+        .changed_remote_bookmarks
+        .iter()
+        .map(
+            |(symbol, (old_remote_ref, new_target))| FetchedBookmarkChange {
+                remote: symbol.remote.as_str(),
+                name: symbol.name.as_str(),
+                kind: if old_remote_ref.target.is_absent() {
+                    FetchedBookmarkChangeKind::New
+                } else if new_target.is_absent() {
+                    FetchedBookmarkChangeKind::Deleted
+                } else {
+                    FetchedBookmarkChangeKind::Updated
+                },
+                old_target: target_ids(&old_remote_ref.target),
+                new_target: target_ids(new_target),
+            },
+        )
+        .collect_vec();
+    let value = serde_json::to_string(&changes).expect("serialization shouldn't fail");
+    tx.set_tag("fetched_bookmarks".to_owned(), value);
+}
+
+/// Deletes local bookmarks that were only kept alive by the remote bookmarks
+/// deleted by the fetch.
+fn prune_local_bookmarks(
+    ui: &Ui,
+    tx: &mut WorkspaceCommandTransaction,
+    remotes: &[&RemoteName],
+    import_stats: &GitImportStats,
+) -> Result<(), CommandError> {
+    let base_view = tx.base_repo().view();
+    let view = tx.repo().view();
+    let ignored_remote = default_ignored_remote_name(tx.repo().store());
+    let mut names_to_prune = BTreeSet::new();
+    for &remote in remotes {
+        for (name, old_remote_ref) in base_view.remote_bookmarks(remote) {
+            if !old_remote_ref.is_present()
+                || view
+                    .get_remote_bookmark(name.to_remote_symbol(remote))
+                    .is_present()
+            {
+                continue;
+            }
+            // Bookmarks moved locally are kept as is.
+            let old_local_target = base_view.get_local_bookmark(name);
+            if old_local_target.is_absent() || *old_local_target != old_remote_ref.target {
+                continue;
+            }
+            // The local bookmark of a tracked remote bookmark may have been
+            // deleted by the import already.
+            let local_target = view.get_local_bookmark(name);
+            if local_target.is_absent() {
+                names_to_prune.insert(name.to_owned());
+                continue;
+            }
+            if local_target != old_local_target {
+                continue;
+            }
+            let kept_by_other_remote = view
+                .remote_bookmarks_matching(&StringMatcher::exact(name), &StringMatcher::all())
+                .any(|(symbol, remote_ref)| {
+                    Some(symbol.remote) != ignored_remote && remote_ref.target == *local_target
+                });
+            if !kept_by_other_remote {
+                names_to_prune.insert(name.to_owned());
+            }
+        }
+    }
+    for name in &names_to_prune {
+        tx.repo_mut()
+            .set_local_bookmark_target(name, RefTarget::absent());
+    }
+    if let Some(mut formatter) = ui.status_formatter() {
+        // Align with the remote bookmark lines printed by the import
+        let max_width = import_stats
+            .changed_remote_bookmarks
+            .iter()
+            .map(|(symbol, _)| symbol.to_string().width())
+            .chain(
+                names_to_prune
+                    .iter()
+                    .map(|name| name.as_symbol().to_string().width()),
+            )
+            .max()
+            .unwrap_or(0);
+        for name in &names_to_prune {
+            write!(formatter, "bookmark: ")?;
+            write!(
+                formatter.labeled("bookmark"),
+                "{:<max_width$}",
+                name.as_symbol().to_string()
+            )?;
+            writeln!(formatter, " [pruned]")?;
+        }
+    }
+    Ok(())
+}
+
+/// How the remotes selected by `git.fetch` are fetched.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum FetchMode {
+    /// Fetch from all remotes.
+    #[default]
+    All,
+    /// Fetch from the first remote that succeeds.
+    Fallback,
+}
+
+/// Remote name pattern, negated by a leading `~`.
+#[derive(Clone, Debug)]
+struct RemotePattern {
+    pattern: StringPattern,
+    negated: bool,
+}
+
+impl RemotePattern {
+    fn new(pattern: StringPattern) -> Self {
+        Self {
+            pattern,
+            negated: false,
+        }
+    }
+
+    fn parse(src: &str) -> Result<Self, StringPatternParseError> {
+        let (negated, src) = match src.strip_prefix('~') {
+            Some(rest) => (true, rest),
+            None => (false, src),
+        };
+        let pattern = StringPattern::parse(src)?;
+        Ok(Self { pattern, negated })
+    }
+}
+
+impl fmt::Display for RemotePattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Self { pattern, negated } = self;
+        if *negated {
+            write!(f, "~")?;
+        }
+        write!(f, "{pattern}")
+    }
+}
+
+/// Branch pattern optionally qualified by a remote name.
+#[derive(Clone, Debug)]
+struct BranchPattern {
+    pattern: StringPattern,
+    remote: Option<RemoteNameBuf>,
+}
+
+impl BranchPattern {
+    fn matches_remote(&self, remote: &RemoteName) -> bool {
+        self.remote.as_deref().is_none_or(|name| name == remote)
+    }
+}
+
+impl fmt::Display for BranchPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Self { pattern, remote } = self;
+        write!(f, "{pattern}")?;
+        if let Some(remote) = remote {
+            write!(f, "@{}", remote.as_symbol())?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_branch_pattern(src: &str) -> Result<BranchPattern, StringPatternParseError> {
+    // Like RemoteBookmarkNamePattern, the kind prefix applies to the branch
+    // fragment, but the remote name always matches exactly.
+    let (maybe_kind, pat) = src
+        .split_once(':')
+        .map_or((None, src), |(kind, pat)| (Some(kind), pat));
+    let (name, remote) = match pat.rsplit_once('@') {
+        Some((name, remote)) => (name, Some(RemoteName::new(remote).to_owned())),
+        None => (pat, None),
+    };
+    let pattern = if let Some(kind) = maybe_kind {
+        StringPattern::from_str_kind(name, kind)?
+    } else {
+        StringPattern::exact(name)
+    };
+    Ok(BranchPattern { pattern, remote })
+}
+
 const DEFAULT_REMOTE: &RemoteName = RemoteName::new("origin");
 
 fn get_default_fetch_remotes(
     ui: &Ui,
     workspace_command: &WorkspaceCommandHelper,
-) -> Result<Vec<StringPattern>, CommandError> {
+) -> Result<Vec<RemotePattern>, CommandError> {
     const KEY: &str = "git.fetch";
     let settings = workspace_command.settings();
     if let Ok(remotes) = settings.get::<Vec<String>>(KEY) {
@@ -204,39 +875,44 @@ fn get_default_fetch_remotes(
                 remote = remote.as_symbol()
             )?;
         }
-        Ok(vec![StringPattern::exact(remote)])
+        Ok(vec![RemotePattern::new(StringPattern::exact(remote))])
     } else {
-        Ok(vec![StringPattern::exact(DEFAULT_REMOTE)])
+        Ok(vec![RemotePattern::new(StringPattern::exact(
+            DEFAULT_REMOTE,
+        ))])
     }
 }
 
-fn parse_remote_pattern(remote: &str) -> Result<StringPattern, CommandError> {
-    StringPattern::parse(remote).map_err(config_error)
+fn parse_remote_pattern(remote: &str) -> Result<RemotePattern, CommandError> {
+    RemotePattern::parse(remote).map_err(config_error)
 }
 
 fn warn_if_branches_not_found(
     ui: &mut Ui,
     tx: &WorkspaceCommandTransaction,
-    branches: &[StringPattern],
+    branches: &[BranchPattern],
     remotes: &[&RemoteName],
 ) -> Result<(), CommandError> {
     let mut missing_branches = vec![];
     for branch in branches {
-        let branch_matcher = branch.to_matcher();
-        let matches = remotes.iter().any(|&remote| {
-            let remote_matcher = StringMatcher::exact(remote);
-            tx.repo()
-                .view()
-                .remote_bookmarks_matching(&branch_matcher, &remote_matcher)
-                .next()
-                .is_some()
-                || tx
-                    .base_repo()
+        let branch_matcher = branch.pattern.to_matcher();
+        let matches = remotes
+            .iter()
+            .filter(|remote| branch.matches_remote(remote))
+            .any(|&remote| {
+                let remote_matcher = StringMatcher::exact(remote);
+                tx.repo()
                     .view()
                     .remote_bookmarks_matching(&branch_matcher, &remote_matcher)
                     .next()
                     .is_some()
-        });
+                    || tx
+                        .base_repo()
+                        .view()
+                        .remote_bookmarks_matching(&branch_matcher, &remote_matcher)
+                        .next()
+                        .is_some()
+            });
         if !matches {
             missing_branches.push(branch);
         }
@@ -12,10 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use clap_complete::ArgValueCandidates;
+use itertools::Itertools as _;
 use jj_lib::git;
+use jj_lib::git::GitRefKind;
+use jj_lib::repo::Repo as _;
+use jj_lib::str_util::StringMatcher;
+use jj_lib::str_util::StringPattern;
 
 use crate::cli_util::CommandHelper;
+use crate::cli_util::WorkspaceCommandTransaction;
 use crate::command_error::CommandError;
+use crate::complete;
 use crate::git_util::print_git_import_stats;
 use crate::ui::Ui;
 
@@ -27,12 +35,36 @@ use crate::ui::Ui;
 /// There is no need to run this command if you're in colocated workspace
 /// because the import happens automatically there.
 #[derive(clap::Args, Clone, Debug)]
-pub struct GitImportArgs {}
+pub struct GitImportArgs {
+    /// Import only some of the branches
+    ///
+    /// By default, the specified name matches exactly. Use `glob:` prefix to
+    /// expand `*` as a glob, e.g. `--branch 'glob:release/*'`. Can be repeated
+    /// to specify multiple branches. Local and remote-tracking branches of the
+    /// matching names are imported. Tags aren't imported if this option is
+    /// specified.
+    #[arg(
+        long, short,
+        alias = "bookmark",
+        value_parser = StringPattern::parse,
+        add = ArgValueCandidates::new(complete::bookmarks),
+    )]
+    branch: Vec<StringPattern>,
+
+    /// Only display what would change in the repo
+    ///
+    /// The refs which would be imported, and the ones which couldn't be, are
+    /// listed, but no operation is created. In a colocated workspace, use
+    /// `--ignore-working-copy` so the refs aren't imported automatically
+    /// beforehand.
+    #[arg(long)]
+    dry_run: bool,
+}
 
 pub fn cmd_git_import(
     ui: &mut Ui,
     command: &CommandHelper,
-    _args: &GitImportArgs,
+    args: &GitImportArgs,
 ) -> Result<(), CommandError> {
     let mut workspace_command = command.workspace_helper(ui)?;
     let git_settings = workspace_command.settings().git_settings()?;
@@ -40,8 +72,49 @@ pub fn cmd_git_import(
     // In non-colocated workspace, Git HEAD will never be moved internally by jj.
     // That's why cmd_git_export() doesn't export the HEAD ref.
     git::import_head(tx.repo_mut())?;
-    let stats = git::import_refs(tx.repo_mut(), &git_settings)?;
-    print_git_import_stats(ui, tx.repo(), &stats, true)?;
+    let stats = if args.branch.is_empty() {
+        git::import_refs(tx.repo_mut(), &git_settings)?
+    } else {
+        git::import_some_refs(tx.repo_mut(), &git_settings, |kind, symbol| match kind {
+            GitRefKind::Bookmark => args
+                .branch
+                .iter()
+                .any(|pattern| pattern.is_match(symbol.name.as_str())),
+            GitRefKind::Tag => false,
+        })?
+    };
+    print_git_import_stats(ui, tx.repo(), &stats, true, args.dry_run)?;
+    warn_if_branches_not_found(ui, &tx, &args.branch)?;
+    if args.dry_run {
+        writeln!(ui.status(), "Dry-run requested, not importing.")?;
+        return Ok(());
+    }
     tx.finish(ui, "import git refs")?;
     Ok(())
 }
+
+fn warn_if_branches_not_found(
+    ui: &Ui,
+    tx: &WorkspaceCommandTransaction,
+    branches: &[StringPattern],
+) -> Result<(), CommandError> {
+    let missing_branches = branches
+        .iter()
+        .filter(|branch| {
+            let branch_matcher = branch.to_matcher();
+            tx.repo()
+                .view()
+                .remote_bookmarks_matching(&branch_matcher, &StringMatcher::all())
+                .next()
+                .is_none()
+        })
+        .collect_vec();
+    if !missing_branches.is_empty() {
+        writeln!(
+            ui.warning_default(),
+            "No branch matching {} found in the Git repo",
+            missing_branches.iter().map(|b| format!("`{b}`")).join(", ")
+        )?;
+    }
+    Ok(())
+}
@@ -238,7 +238,7 @@ fn init_git_refs(
     // There should be no old refs to abandon, but enforce it.
     git_settings.abandon_unreachable_commits = false;
     let stats = git::import_refs(tx.repo_mut(), &git_settings)?;
-    print_git_import_stats(ui, tx.repo(), &stats, false)?;
+    print_git_import_stats(ui, tx.repo(), &stats, false, false)?;
     if !tx.repo().has_changes() {
         return Ok(repo);
     }
@@ -47,8 +47,8 @@ use self::import::GitImportArgs;
 use self::import::cmd_git_import;
 use self::init::GitInitArgs;
 use self::init::cmd_git_init;
-use self::push::GitPushArgs;
-use self::push::cmd_git_push;
+pub(crate) use self::push::GitPushArgs;
+pub(crate) use self::push::cmd_git_push;
 pub use self::push::is_push_operation;
 use self::remote::RemoteCommand;
 use self::remote::cmd_git_remote;
@@ -117,7 +117,9 @@ pub fn maybe_add_gitignore(workspace_command: &WorkspaceCommandHelper) -> Result
     }
 }
 
-fn get_single_remote(store: &Store) -> Result<Option<RemoteNameBuf>, UnexpectedGitBackendError> {
+pub(crate) fn get_single_remote(
+    store: &Store,
+) -> Result<Option<RemoteNameBuf>, UnexpectedGitBackendError> {
     let mut names = git::get_all_remote_names(store)?;
     Ok(match names.len() {
         1 => names.pop(),
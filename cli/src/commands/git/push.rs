@@ -33,6 +33,8 @@ use jj_lib::git::GitBranchPushTargets;
 use jj_lib::git::GitPushStats;
 use jj_lib::index::IndexResult;
 use jj_lib::op_store::RefTarget;
+use jj_lib::op_store::RemoteRef;
+use jj_lib::op_store::RemoteRefState;
 use jj_lib::operation::Operation;
 use jj_lib::ref_name::RefName;
 use jj_lib::ref_name::RefNameBuf;
@@ -93,7 +95,7 @@ use crate::ui::Ui;
 /// [bookmark conflicts]:
 ///     https://jj-vcs.github.io/jj/latest/bookmarks/#conflicts
 
-#[derive(clap::Args, Clone, Debug)]
+#[derive(clap::Args, Clone, Debug, Default)]
 #[command(group(ArgGroup::new("specific").args(&["bookmark", "change", "revisions", "named"]).multiple(true)))]
 #[command(group(ArgGroup::new("what").args(&["all", "tracked"]).conflicts_with("specific")))]
 pub struct GitPushArgs {
@@ -148,6 +150,12 @@ pub struct GitPushArgs {
     /// Allow pushing commits with empty descriptions
     #[arg(long)]
     allow_empty_description: bool,
+    /// Refuse to push commits with conflicts or empty descriptions
+    ///
+    /// All offending commits to be pushed are listed before failing, instead
+    /// of stopping at the first one.
+    #[arg(long, conflicts_with = "allow_empty_description")]
+    require_clean: bool,
     /// Allow pushing commits that are private
     ///
     /// The set of private commits can be configured by the
@@ -155,6 +163,31 @@ pub struct GitPushArgs {
     /// commits are eligible to be pushed.
     #[arg(long)]
     allow_private: bool,
+    /// Warn about commits to be pushed whose author isn't the configured user
+    ///
+    /// The author email of each commit is compared with the `user.email`
+    /// setting.
+    #[arg(long)]
+    warn_foreign_authors: bool,
+    /// Refuse to push commits whose author isn't the configured user
+    ///
+    /// This is like `--warn-foreign-authors`, but makes the push fail instead
+    /// of printing a warning.
+    #[arg(long)]
+    reject_foreign_authors: bool,
+    /// Only push a bookmark if the remote bookmark is at the expected commit
+    ///
+    /// Without a value, the remote bookmarks are expected to be where they
+    /// were last fetched, which is also what happens when this flag isn't
+    /// given. With a value of the form BOOKMARK:REVISION, the push is refused
+    /// unless the remote bookmark was last fetched at the given revision.
+    #[arg(
+        long,
+        value_name = "BOOKMARK:REVISION",
+        num_args = 0..=1,
+        require_equals = true
+    )]
+    force_with_lease: Option<Vec<String>>,
     /// Push bookmarks pointing to these commits (can be repeated)
     #[arg(
         long,
@@ -170,7 +203,9 @@ pub struct GitPushArgs {
     ///
     /// The created bookmark will be tracked automatically. Use the
     /// `templates.git_push_bookmark` setting to customize the generated
-    /// bookmark name. The default is `"push-" ++ change_id.short()`.
+    /// bookmark name. The default is `"push-" ++ change_id.short()`. If the
+    /// template generates the same name for several revisions, a numeric
+    /// suffix is appended to the later ones (e.g. `push-foo-2`).
     #[arg(
         long,
         short,
@@ -186,6 +221,11 @@ pub struct GitPushArgs {
     /// '--named myfeature=@'
     ///
     /// Does not require --allow-new.
+    ///
+    /// An existing local bookmark can instead be pushed under a different
+    /// name on the remote by using the LOCAL:REMOTE form, e.g. '--named
+    /// feature:team/feature'. The local bookmark isn't renamed, and the
+    /// remote bookmark isn't tracked unless it was already.
     #[arg(
         long,
         value_name = "NAME=REVISION",
@@ -193,6 +233,9 @@ pub struct GitPushArgs {
     )]
     named: Vec<String>,
     /// Only display what will change on the remote
+    ///
+    /// The remote is still contacted to check that the bookmarks haven't
+    /// unexpectedly moved, but nothing is pushed and no operation is created.
     #[arg(long)]
     dry_run: bool,
 }
@@ -204,6 +247,21 @@ fn make_bookmark_term(bookmark_names: &[impl fmt::Display]) -> String {
     }
 }
 
+impl GitPushArgs {
+    /// Arguments to push the given newly created bookmarks to the remote.
+    pub(crate) fn for_new_bookmarks(remote: RemoteNameBuf, names: &[RefNameBuf]) -> Self {
+        Self {
+            remote: Some(remote),
+            bookmark: names
+                .iter()
+                .map(|name| StringPattern::exact(name.as_str()))
+                .collect(),
+            allow_new: true,
+            ..Default::default()
+        }
+    }
+}
+
 const DEFAULT_REMOTE: &RemoteName = RemoteName::new("origin");
 
 const TX_DESC_PUSH: &str = "push ";
@@ -234,6 +292,8 @@ pub fn cmd_git_push(
     let view = tx.repo().view();
     let tx_description;
     let mut bookmark_updates = vec![];
+    let mut untracked_renamed_bookmarks: Vec<RefNameBuf> = vec![];
+    let mut applied_pending_renames: Vec<RefNameBuf> = vec![];
     if args.all {
         for (name, targets) in view.local_remote_bookmarks(remote) {
             let allow_new = true; // implied by --all
@@ -300,9 +360,16 @@ pub fn cmd_git_push(
 
         // --change and --named don't move existing bookmarks. If they did, be
         // careful to not select old state by -r/--revisions and bookmark names.
-        let change_bookmark_names = create_change_bookmarks(ui, &mut tx, &args.change)?;
-        let created_bookmark_names: Vec<RefNameBuf> = args
+        let (renamed_args, named_args): (Vec<_>, Vec<_>) = args
             .named
+            .iter()
+            .partition(|arg| is_renamed_bookmark_arg(arg));
+        let renamed_bookmarks: Vec<(RefNameBuf, RefNameBuf)> = renamed_args
+            .iter()
+            .map(|arg| parse_renamed_bookmark(arg))
+            .try_collect()?;
+        let change_bookmark_names = create_change_bookmarks(ui, &mut tx, &args.change)?;
+        let created_bookmark_names: Vec<RefNameBuf> = named_args
             .iter()
             .map(|name_revision| create_explicitly_named_bookmarks(ui, &mut tx, name_revision))
             .try_collect()?;
@@ -335,7 +402,77 @@ pub fn cmd_git_push(
             }
         }
 
+        for (local_name, remote_name) in &renamed_bookmarks {
+            let view = tx.repo().view();
+            let local_target = view.get_local_bookmark(local_name);
+            if local_target.is_absent() {
+                return Err(user_error(format!(
+                    "No such bookmark: {name}",
+                    name = local_name.as_symbol()
+                )));
+            }
+            let remote_symbol = remote_name.to_remote_symbol(remote);
+            if !seen_bookmarks.insert(remote_name) {
+                continue;
+            }
+            let remote_ref = view.get_remote_bookmark(remote_symbol);
+            if !remote_ref.is_tracked() {
+                untracked_renamed_bookmarks.push(remote_name.clone());
+            }
+            // Check the remote bookmark as if the local bookmark were tracking it.
+            let remote_ref = RemoteRef {
+                target: remote_ref.target.clone(),
+                state: RemoteRefState::Tracked,
+            };
+            let targets = LocalAndRemoteRef {
+                local_target,
+                remote_ref: &remote_ref,
+            };
+            let allow_new = true; // implied by the explicit remote name
+            let allow_delete = false; // doesn't matter
+            match classify_bookmark_update(remote_symbol, targets, allow_new, allow_delete) {
+                Ok(Some(update)) => bookmark_updates.push((remote_name.clone(), update)),
+                Ok(None) => writeln!(
+                    ui.status(),
+                    "Bookmark {remote_symbol} already matches {name}",
+                    name = local_name.as_symbol()
+                )?,
+                Err(reason) => return Err(reason.into()),
+            }
+        }
+
+        let use_default_revset = args.bookmark.is_empty()
+            && args.change.is_empty()
+            && args.revisions.is_empty()
+            && args.named.is_empty();
+
         let view = tx.repo().view();
+        // Bookmarks renamed by `jj bookmark rename --remote-too` are renamed on
+        // the remote by deleting the old name and creating the new one.
+        if use_default_revset {
+            for (new_name, old_name) in view.pending_bookmark_renames(remote) {
+                applied_pending_renames.push(new_name.to_owned());
+                for (name, allow_new, allow_delete) in
+                    [(old_name, false, true), (new_name, true, false)]
+                {
+                    if !seen_bookmarks.insert(name) {
+                        continue;
+                    }
+                    let remote_symbol = name.to_remote_symbol(remote);
+                    let targets = LocalAndRemoteRef {
+                        local_target: view.get_local_bookmark(name),
+                        remote_ref: view.get_remote_bookmark(remote_symbol),
+                    };
+                    match classify_bookmark_update(remote_symbol, targets, allow_new, allow_delete)
+                    {
+                        Ok(Some(update)) => bookmark_updates.push((name.to_owned(), update)),
+                        Ok(None) => {}
+                        Err(reason) => return Err(reason.into()),
+                    }
+                }
+            }
+        }
+
         let allow_new = args.allow_new || tx.settings().get("git.push-new-bookmarks")?;
         let bookmarks_by_name = find_bookmarks_to_push(view, &args.bookmark, remote)?;
         for &(name, targets) in &bookmarks_by_name {
@@ -355,10 +492,6 @@ pub fn cmd_git_push(
             }
         }
 
-        let use_default_revset = args.bookmark.is_empty()
-            && args.change.is_empty()
-            && args.revisions.is_empty()
-            && args.named.is_empty();
         let bookmarks_targeted = find_bookmarks_targeted_by_revisions(
             ui,
             tx.base_workspace_helper(),
@@ -394,10 +527,45 @@ pub fn cmd_git_push(
             remote = remote.as_symbol()
         );
     }
+    // However the renamed bookmark was selected, its old name is deleted from
+    // the remote along with it.
+    let view = tx.repo().view();
+    for (new_name, old_name) in view.pending_bookmark_renames(remote) {
+        if applied_pending_renames.iter().any(|name| name == new_name)
+            || !bookmark_updates.iter().any(|(name, _)| name == new_name)
+        {
+            continue;
+        }
+        applied_pending_renames.push(new_name.to_owned());
+        if bookmark_updates.iter().any(|(name, _)| name == old_name) {
+            continue;
+        }
+        let remote_symbol = old_name.to_remote_symbol(remote);
+        let targets = LocalAndRemoteRef {
+            local_target: view.get_local_bookmark(old_name),
+            remote_ref: view.get_remote_bookmark(remote_symbol),
+        };
+        let allow_new = false; // doesn't matter
+        let allow_delete = true; // implied by the pending rename
+        match classify_bookmark_update(remote_symbol, targets, allow_new, allow_delete) {
+            Ok(Some(update)) => bookmark_updates.push((old_name.to_owned(), update)),
+            Ok(None) => {}
+            Err(reason) => return Err(reason.into()),
+        }
+    }
     if bookmark_updates.is_empty() {
         writeln!(ui.status(), "Nothing changed.")?;
         return Ok(());
     }
+    for lease in args.force_with_lease.iter().flatten() {
+        check_bookmark_lease(
+            ui,
+            tx.base_workspace_helper(),
+            remote,
+            &bookmark_updates,
+            lease,
+        )?;
+    }
 
     let sign_behavior = if tx.settings().get_bool("git.sign-on-push")? {
         Some(SignBehavior::Own)
@@ -437,19 +605,34 @@ pub fn cmd_git_push(
         print_commits_ready_to_push(formatter.as_mut(), tx.repo(), &bookmark_updates)?;
     }
 
+    let targets = GitBranchPushTargets {
+        branch_updates: bookmark_updates,
+    };
+    let git_settings = tx.settings().git_settings()?;
     if args.dry_run {
+        // Ask the remote to check the leases so that a dry run fails the same
+        // way the real push would. No operation is recorded.
+        let push_stats = with_remote_git_callbacks(ui, |cb| {
+            git::dry_run_push_branches(tx.repo(), &git_settings, remote, &targets, cb)
+        })?;
+        process_push_stats(&push_stats)?;
         writeln!(ui.status(), "Dry-run requested, not pushing.")?;
         return Ok(());
     }
 
-    let targets = GitBranchPushTargets {
-        branch_updates: bookmark_updates,
-    };
-    let git_settings = tx.settings().git_settings()?;
     let push_stats = with_remote_git_callbacks(ui, |cb| {
         git::push_branches(tx.repo_mut(), &git_settings, remote, &targets, cb)
     })?;
     process_push_stats(&push_stats)?;
+    // Bookmarks pushed under a different name have no local counterpart.
+    for name in &untracked_renamed_bookmarks {
+        tx.repo_mut()
+            .untrack_remote_bookmark(name.to_remote_symbol(remote));
+    }
+    for name in &applied_pending_renames {
+        tx.repo_mut()
+            .set_pending_bookmark_rename(name.to_remote_symbol(remote), None);
+    }
     tx.finish(ui, tx_description)?;
     Ok(())
 }
@@ -540,13 +723,20 @@ fn validate_commits_ready_to_push(
         sign_settings
     });
 
+    let check_foreign_authors = args.warn_foreign_authors || args.reject_foreign_authors;
     let mut commits_to_sign = vec![];
+    let mut foreign_commits = vec![];
+    let mut unclean_commits = vec![];
 
     for commit in workspace_helper
         .attach_revset_evaluator(commits_to_push)
         .evaluate_to_commits()?
     {
         let commit = commit?;
+        if args.require_clean && (commit.has_conflict() || commit.description().is_empty()) {
+            unclean_commits.push(commit);
+            continue;
+        }
         let mut reasons = vec![];
         if commit.description().is_empty() && !args.allow_empty_description {
             reasons.push("it has no description");
@@ -587,6 +777,9 @@ fn validate_commits_ready_to_push(
             }
             return Err(error);
         }
+        if check_foreign_authors && commit.author().email != settings.user_email() {
+            foreign_commits.push(commit.clone());
+        }
         if let Some(sign_settings) = &sign_settings
             && !commit.is_signed()
             && sign_settings.should_sign(commit.store_commit())
@@ -594,6 +787,53 @@ fn validate_commits_ready_to_push(
             commits_to_sign.push(commit);
         }
     }
+    if !unclean_commits.is_empty() {
+        let mut error = user_error(format!(
+            "Won't push {} commit{} with conflicts or empty descriptions",
+            unclean_commits.len(),
+            if unclean_commits.len() == 1 { "" } else { "s" },
+        ));
+        error.add_formatted_hint_with(|formatter| {
+            writeln!(formatter, "Rejected commits:")?;
+            for commit in &unclean_commits {
+                write!(formatter, "  ")?;
+                workspace_helper.write_commit_summary(formatter, commit)?;
+                writeln!(formatter)?;
+            }
+            Ok(())
+        });
+        return Err(error);
+    }
+    if !foreign_commits.is_empty() {
+        if args.reject_foreign_authors {
+            let mut error = user_error(format!(
+                "Won't push {} commit{} authored by others",
+                foreign_commits.len(),
+                if foreign_commits.len() == 1 { "" } else { "s" },
+            ));
+            error.add_formatted_hint_with(|formatter| {
+                writeln!(formatter, "Rejected commits:")?;
+                for commit in &foreign_commits {
+                    write!(formatter, "  ")?;
+                    workspace_helper.write_commit_summary(formatter, commit)?;
+                    writeln!(formatter)?;
+                }
+                Ok(())
+            });
+            return Err(error);
+        }
+        writeln!(
+            ui.warning_default(),
+            "The following commits to push are authored by others:"
+        )?;
+        if let Some(mut formatter) = ui.status_formatter() {
+            for commit in &foreign_commits {
+                write!(formatter, "  ")?;
+                workspace_helper.write_commit_summary(formatter.as_mut(), commit)?;
+                writeln!(formatter)?;
+            }
+        }
+    }
     Ok(commits_to_sign)
 }
 
@@ -841,6 +1081,89 @@ fn ensure_new_bookmark_name(repo: &dyn Repo, name: &RefName) -> Result<(), Comma
     Ok(())
 }
 
+/// Returns true if the `--named` argument has the LOCAL:REMOTE form.
+fn is_renamed_bookmark_arg(arg: &str) -> bool {
+    !arg.contains('=') && arg.contains(':')
+}
+
+/// Parses a `--named LOCAL:REMOTE` argument into the local and remote bookmark
+/// names.
+fn parse_renamed_bookmark(arg: &str) -> Result<(RefNameBuf, RefNameBuf), CommandError> {
+    let hint = "For example, `--named feature:team/feature` is valid syntax";
+    let (local_str, remote_str) = arg.split_once(':').unwrap();
+    if local_str.is_empty() || remote_str.is_empty() {
+        return Err(cli_error(format!(
+            "Argument '{arg}' must have the form LOCAL:REMOTE, with both LOCAL and REMOTE \
+             non-empty"
+        ))
+        .hinted(hint));
+    }
+    let parse = |name_str: &str| {
+        parse_bookmark_name(name_str).map_err(|err| {
+            cli_error_with_message(
+                format!("Could not parse '{name_str}' as a bookmark name"),
+                err,
+            )
+            .hinted(hint)
+        })
+    };
+    Ok((parse(local_str)?, parse(remote_str)?))
+}
+
+/// Checks that the remote bookmark named by a `--force-with-lease` argument
+/// was last fetched at the expected revision.
+fn check_bookmark_lease(
+    ui: &Ui,
+    workspace_command: &WorkspaceCommandHelper,
+    remote: &RemoteName,
+    bookmark_updates: &[(RefNameBuf, BookmarkPushUpdate)],
+    lease: &str,
+) -> Result<(), CommandError> {
+    let hint = "For example, `--force-with-lease=main:abc123` is valid syntax";
+    let Some((name_str, revision_str)) = lease
+        .split_once(':')
+        .filter(|(name, revision)| !name.is_empty() && !revision.is_empty())
+    else {
+        return Err(cli_error(format!(
+            "Argument '{lease}' must have the form BOOKMARK:REVISION"
+        ))
+        .hinted(hint));
+    };
+    let name = parse_bookmark_name(name_str).map_err(|err| {
+        cli_error_with_message(
+            format!("Could not parse '{name_str}' as a bookmark name"),
+            err,
+        )
+        .hinted(hint)
+    })?;
+    let expected =
+        workspace_command.resolve_single_rev(ui, &RevisionArg::from(revision_str.to_owned()))?;
+    let remote_symbol = name.to_remote_symbol(remote);
+    let Some((_, update)) = bookmark_updates.iter().find(|(n, _)| *n == name) else {
+        return Err(user_error(format!(
+            "Bookmark {name} is not being pushed to {remote}",
+            name = name.as_symbol(),
+            remote = remote.as_symbol(),
+        )));
+    };
+    if update.old_target.as_ref() == Some(expected.id()) {
+        return Ok(());
+    }
+    let actual = match &update.old_target {
+        Some(id) => format!("was last fetched at {}", short_commit_hash(id)),
+        None => "doesn't exist".to_owned(),
+    };
+    Err(user_error_with_hint(
+        format!(
+            "Refusing to push bookmark {name}: {remote_symbol} {actual}, not at the expected \
+             {expected}",
+            name = name.as_symbol(),
+            expected = short_commit_hash(expected.id()),
+        ),
+        "Run `jj git fetch` to see where the remote bookmark is now.",
+    ))
+}
+
 /// Creates a bookmark for a single `--named` argument and returns its name
 ///
 /// The logic is not identical to that of `jj bookmark create` since we need to
@@ -898,7 +1221,7 @@ fn create_change_bookmarks(
         .iter()
         .map(|id| tx.repo().store().get_commit(id))
         .try_collect()?;
-    let bookmark_names: Vec<_> = {
+    let generated_names: Vec<_> = {
         let template_text = tx.settings().get_string("templates.git_push_bookmark")?;
         let template = tx.parse_commit_template(ui, &template_text)?;
         all_commits
@@ -915,6 +1238,7 @@ fn create_change_bookmarks(
             })
             .try_collect()?
     };
+    let bookmark_names = disambiguate_bookmark_names(generated_names);
 
     for (commit, name) in iter::zip(&all_commits, &bookmark_names) {
         let target = RefTarget::normal(commit.id().clone());
@@ -934,6 +1258,27 @@ fn create_change_bookmarks(
     Ok(bookmark_names)
 }
 
+/// Appends a numeric suffix to names that were already generated for earlier
+/// revisions, so that each revision gets its own bookmark.
+fn disambiguate_bookmark_names(names: Vec<RefNameBuf>) -> Vec<RefNameBuf> {
+    let mut used_names = HashSet::new();
+    names
+        .into_iter()
+        .map(|name| {
+            let name = if used_names.contains(&name) {
+                (2..)
+                    .map(|n| RefNameBuf::from(format!("{}-{n}", name.as_str())))
+                    .find(|candidate| !used_names.contains(candidate))
+                    .unwrap()
+            } else {
+                name
+            };
+            used_names.insert(name.clone());
+            name
+        })
+        .collect()
+}
+
 fn find_bookmarks_to_push<'a>(
     view: &'a View,
     bookmark_patterns: &[StringPattern],
@@ -14,6 +14,7 @@
 
 use std::io::Write as _;
 
+use clap::ValueEnum;
 use jj_lib::git;
 use jj_lib::repo::Repo as _;
 
@@ -24,32 +25,86 @@ use crate::ui::Ui;
 
 /// List Git remotes
 #[derive(clap::Args, Clone, Debug)]
-pub struct GitRemoteListArgs {}
+pub struct GitRemoteListArgs {
+    /// Output format
+    ///
+    /// With `json`, one JSON object is printed per line for each remote,
+    /// including its fetch and push URLs.
+    #[arg(long, value_enum, default_value_t)]
+    format: ListFormat,
+    /// Include the remote named `git` in the JSON output
+    ///
+    /// The name `git` is reserved for the underlying Git repository, so such
+    /// a remote is omitted from the JSON output by default. This option
+    /// requires `--format=json`.
+    #[arg(long, requires = "format")]
+    include_git: bool,
+}
+
+/// Output format for the `--format` argument option.
+#[derive(Copy, Clone, Debug, Default, ValueEnum)]
+enum ListFormat {
+    /// Print the name and fetch URL of each remote
+    #[default]
+    Text,
+    /// Print each remote as a JSON object
+    Json,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct JsonRemote<'a> {
+    name: &'a str,
+    fetch_url: Option<String>,
+    push_url: Option<String>,
+}
 
 pub fn cmd_git_remote_list(
     ui: &mut Ui,
     command: &CommandHelper,
-    _args: &GitRemoteListArgs,
+    args: &GitRemoteListArgs,
 ) -> Result<(), CommandError> {
     let workspace_command = command.workspace_helper(ui)?;
-    let git_repo = git::get_git_repo(workspace_command.repo().store())?;
-    for remote_name in git_repo.remote_names() {
-        let remote = match git_repo.try_find_remote(&*remote_name) {
+    let store = workspace_command.repo().store();
+    let git_repo = git::get_git_repo(store)?;
+    let mut remote_names = git::get_all_remote_names(store)?;
+    if matches!(args.format, ListFormat::Json) && !args.include_git {
+        remote_names.retain(|name| **name != *git::REMOTE_NAME_FOR_LOCAL_GIT_REPO);
+    }
+    remote_names.sort_unstable();
+    for remote_name in &remote_names {
+        let remote = match git_repo.try_find_remote(remote_name.as_str()) {
             Some(Ok(remote)) => remote,
             Some(Err(err)) => {
                 return Err(user_error_with_message(
-                    format!("Failed to load configured remote {remote_name}"),
+                    format!("Failed to load configured remote {}", remote_name.as_str()),
                     err,
                 ));
             }
             None => continue, // ignore empty [remote "<name>"] section
         };
-        // TODO: print push url (by default or by some flag)?
         let fetch_url = remote
             .url(gix::remote::Direction::Fetch)
-            .map(|url| url.to_bstring())
-            .unwrap_or_else(|| "<no URL>".into());
-        writeln!(ui.stdout(), "{remote_name} {fetch_url}")?;
+            .map(|url| url.to_bstring());
+        match args.format {
+            ListFormat::Text => {
+                // TODO: print push url (by default or by some flag)?
+                let fetch_url = fetch_url.unwrap_or_else(|| "<no URL>".into());
+                writeln!(ui.stdout(), "{} {fetch_url}", remote_name.as_str())?;
+            }
+            ListFormat::Json => {
+                let push_url = remote
+                    .url(gix::remote::Direction::Push)
+                    .map(|url| url.to_bstring());
+                let json_remote = JsonRemote {
+                    name: remote_name.as_str(),
+                    fetch_url: fetch_url.map(|url| url.to_string()),
+                    push_url: push_url.map(|url| url.to_string()),
+                };
+                let line =
+                    serde_json::to_string(&json_remote).expect("serialization shouldn't fail");
+                writeln!(ui.stdout(), "{line}")?;
+            }
+        }
     }
     Ok(())
 }
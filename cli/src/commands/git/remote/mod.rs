@@ -17,6 +17,7 @@ mod list;
 mod remove;
 mod rename;
 mod set_url;
+mod show;
 
 use clap::Subcommand;
 
@@ -30,6 +31,8 @@ use self::rename::GitRemoteRenameArgs;
 use self::rename::cmd_git_remote_rename;
 use self::set_url::GitRemoteSetUrlArgs;
 use self::set_url::cmd_git_remote_set_url;
+use self::show::GitRemoteShowArgs;
+use self::show::cmd_git_remote_show;
 use crate::cli_util::CommandHelper;
 use crate::command_error::CommandError;
 use crate::ui::Ui;
@@ -44,6 +47,7 @@ pub enum RemoteCommand {
     Remove(GitRemoteRemoveArgs),
     Rename(GitRemoteRenameArgs),
     SetUrl(GitRemoteSetUrlArgs),
+    Show(GitRemoteShowArgs),
 }
 
 pub fn cmd_git_remote(
@@ -57,5 +61,6 @@ pub fn cmd_git_remote(
         RemoteCommand::Remove(args) => cmd_git_remote_remove(ui, command, args),
         RemoteCommand::Rename(args) => cmd_git_remote_rename(ui, command, args),
         RemoteCommand::SetUrl(args) => cmd_git_remote_set_url(ui, command, args),
+        RemoteCommand::Show(args) => cmd_git_remote_show(ui, command, args),
     }
 }
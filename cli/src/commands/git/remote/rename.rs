@@ -29,6 +29,18 @@ pub struct GitRemoteRenameArgs {
     old: RemoteNameBuf,
     /// The desired name for `old`
     new: RemoteNameBuf,
+    /// Move the remote bookmarks and their tracking state to the new name
+    ///
+    /// With `false`, the remote bookmarks are left under the old remote name,
+    /// e.g. to keep them associated with a remote of that name which will be
+    /// recreated later.
+    #[arg(
+        long,
+        value_name = "BOOL",
+        default_value_t = true,
+        action = clap::ArgAction::Set,
+    )]
+    update_tracking: bool,
 }
 
 pub fn cmd_git_remote_rename(
@@ -38,7 +50,7 @@ pub fn cmd_git_remote_rename(
 ) -> Result<(), CommandError> {
     let mut workspace_command = command.workspace_helper(ui)?;
     let mut tx = workspace_command.start_transaction();
-    git::rename_remote(tx.repo_mut(), &args.old, &args.new)?;
+    git::rename_remote(tx.repo_mut(), &args.old, &args.new, args.update_tracking)?;
     if tx.repo().has_changes() {
         tx.finish(
             ui,
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::io::Write as _;
+
 use clap_complete::ArgValueCandidates;
 use jj_lib::git;
 use jj_lib::ref_name::RemoteNameBuf;
@@ -34,6 +36,11 @@ pub struct GitRemoteSetUrlArgs {
     /// Local path will be resolved to absolute form.
     #[arg(value_hint = clap::ValueHint::Url)]
     url: String,
+    /// Set the URL used for pushing instead of the one used for fetching
+    ///
+    /// Once set, the push URL is kept when the fetch URL is changed.
+    #[arg(long)]
+    push: bool,
 }
 
 pub fn cmd_git_remote_set_url(
@@ -43,6 +50,20 @@ pub fn cmd_git_remote_set_url(
 ) -> Result<(), CommandError> {
     let workspace_command = command.workspace_helper(ui)?;
     let url = absolute_git_url(command.cwd(), &args.url)?;
-    git::set_remote_url(workspace_command.repo().store(), &args.remote, &url)?;
+    let store = workspace_command.repo().store();
+    let (kind, old_url) = if args.push {
+        (
+            "push URL",
+            git::set_remote_push_url(store, &args.remote, &url)?,
+        )
+    } else {
+        ("URL", git::set_remote_url(store, &args.remote, &url)?)
+    };
+    writeln!(
+        ui.status(),
+        "Changed {kind} of remote {remote} from {old_url} to {url}",
+        remote = args.remote.as_symbol(),
+        old_url = old_url.unwrap_or_else(|| "<no URL>".into()),
+    )?;
     Ok(())
 }
@@ -0,0 +1,128 @@
+// Copyright 2026 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write as _;
+
+use clap_complete::ArgValueCandidates;
+use itertools::Itertools as _;
+use jj_lib::git;
+use jj_lib::git::GitRemoteManagementError;
+use jj_lib::git::GitRemoteNameError;
+use jj_lib::ref_name::RemoteNameBuf;
+use jj_lib::repo::Repo as _;
+use jj_lib::revset;
+
+use crate::cli_util::CommandHelper;
+use crate::command_error::CommandError;
+use crate::command_error::user_error_with_hint;
+use crate::command_error::user_error_with_message;
+use crate::complete;
+use crate::formatter::FormatterExt as _;
+use crate::templater::SizeHint;
+use crate::ui::Ui;
+
+/// Show a Git remote and its bookmarks
+///
+/// Prints the fetch and push URLs of the remote, followed by the remote
+/// bookmarks and their tracking state. For tracked bookmarks, the number of
+/// commits the remote bookmark is ahead of or behind the local bookmark is
+/// also shown.
+#[derive(clap::Args, Clone, Debug)]
+pub struct GitRemoteShowArgs {
+    /// The remote's name
+    #[arg(add = ArgValueCandidates::new(complete::git_remotes))]
+    remote: RemoteNameBuf,
+}
+
+pub fn cmd_git_remote_show(
+    ui: &mut Ui,
+    command: &CommandHelper,
+    args: &GitRemoteShowArgs,
+) -> Result<(), CommandError> {
+    if *args.remote == *git::REMOTE_NAME_FOR_LOCAL_GIT_REPO {
+        return Err(user_error_with_hint(
+            GitRemoteNameError::ReservedForLocalGitRepo,
+            "Run `jj git remote rename` to give a different name.",
+        ));
+    }
+    let workspace_command = command.workspace_helper(ui)?;
+    let repo = workspace_command.repo().as_ref();
+    let git_repo = git::get_git_repo(repo.store())?;
+    let remote = match git_repo.try_find_remote(args.remote.as_str()) {
+        Some(Ok(remote)) => remote,
+        Some(Err(err)) => {
+            return Err(user_error_with_message(
+                format!("Failed to load configured remote {}", args.remote.as_str()),
+                err,
+            ));
+        }
+        None => return Err(GitRemoteManagementError::NoSuchRemote(args.remote.clone()).into()),
+    };
+    let format_url = |direction| {
+        remote
+            .url(direction)
+            .map_or_else(|| "<no URL>".into(), |url| url.to_bstring())
+    };
+
+    ui.request_pager();
+    let mut formatter = ui.stdout_formatter();
+    writeln!(formatter, "Name: {}", args.remote.as_symbol())?;
+    writeln!(
+        formatter,
+        "Fetch URL: {}",
+        format_url(gix::remote::Direction::Fetch)
+    )?;
+    writeln!(
+        formatter,
+        "Push URL: {}",
+        format_url(gix::remote::Direction::Push)
+    )?;
+    writeln!(formatter, "Bookmarks:")?;
+    for (name, targets) in repo.view().local_remote_bookmarks(&args.remote) {
+        if targets.remote_ref.target.is_absent() {
+            continue;
+        }
+        write!(formatter, "  ")?;
+        write!(formatter.labeled("bookmark"), "{}", name.as_symbol())?;
+        if !targets.remote_ref.is_tracked() {
+            writeln!(formatter, ": untracked")?;
+            continue;
+        }
+        write!(formatter, ": tracked")?;
+        if targets.local_target.is_present() {
+            let remote_ids = targets.remote_ref.target.added_ids().cloned().collect_vec();
+            let local_ids = targets.local_target.added_ids().cloned().collect_vec();
+            let ahead = revset::walk_revs(repo, &remote_ids, &local_ids)?.count_estimate()?;
+            let behind = revset::walk_revs(repo, &local_ids, &remote_ids)?.count_estimate()?;
+            let distances = [("ahead", ahead), ("behind", behind)]
+                .into_iter()
+                .filter(|&(_, count)| count != (0, Some(0)))
+                .map(|(label, count)| format_distance(label, count))
+                .collect::<Vec<_>>();
+            if !distances.is_empty() {
+                write!(formatter, " ({})", distances.join(", "))?;
+            }
+        }
+        writeln!(formatter)?;
+    }
+    Ok(())
+}
+
+fn format_distance(label: &str, (lower, upper): SizeHint) -> String {
+    if upper == Some(lower) {
+        format!("{label} by {lower} commits")
+    } else {
+        format!("{label} by at least {lower} commits")
+    }
+}
@@ -101,6 +101,7 @@ pub(crate) fn cmd_interdiff(
             slice::from_ref(&from),
             &to,
             matcher.as_ref(),
+            None,
             ui.term_width(),
         )
         .block_on()?;
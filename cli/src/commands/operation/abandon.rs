@@ -18,6 +18,9 @@ use std::slice;
 
 use clap_complete::ArgValueCandidates;
 use itertools::Itertools as _;
+use jj_lib::backend::MillisSinceEpoch;
+use jj_lib::backend::Timestamp;
+use jj_lib::default_index::DefaultIndexStore;
 use jj_lib::op_walk;
 use pollster::FutureExt as _;
 
@@ -26,6 +29,7 @@ use crate::cli_util::short_operation_hash;
 use crate::command_error::CommandError;
 use crate::command_error::cli_error;
 use crate::command_error::user_error;
+use crate::command_error::user_error_with_hint;
 use crate::complete;
 use crate::ui::Ui;
 
@@ -38,15 +42,27 @@ use crate::ui::Ui;
 /// To discard recent operations, use `jj op restore <operation ID>` followed
 /// by `jj op abandon <operation ID>..@-`.
 ///
+/// To discard operations older than a certain age, use `jj op abandon
+/// --older-than 30d`. The current operation is always kept.
+///
 /// Previous versions of a change (or predecessors) are also discarded if they
 /// become unreachable from the operation history. The abandoned operations,
 /// commits, and other unreachable objects can later be garbage collected by
 /// using `jj util gc` command.
 #[derive(clap::Args, Clone, Debug)]
+#[command(group(clap::ArgGroup::new("target").required(true)))]
 pub struct OperationAbandonArgs {
     /// The operation or operation range to abandon
-    #[arg(add = ArgValueCandidates::new(complete::operations))]
-    operation: String,
+    #[arg(group = "target", add = ArgValueCandidates::new(complete::operations))]
+    operation: Option<String>,
+
+    /// Abandon operations that finished before the given age
+    ///
+    /// The age is a number followed by one of the units `s`, `m`, `h`, `d`,
+    /// or `w`, e.g. `30d`. The newest such operation and all its ancestors
+    /// are abandoned.
+    #[arg(long, value_name = "AGE", group = "target")]
+    older_than: Option<String>,
 }
 
 pub fn cmd_op_abandon(
@@ -67,8 +83,22 @@ pub fn cmd_op_abandon(
     }
     let current_head_ops = op_walk::get_current_head_ops(op_store, op_heads_store.as_ref())?;
     let resolve_op = |op_str| op_walk::resolve_op_at(op_store, &current_head_ops, op_str);
-    let (abandon_root_op, abandon_head_ops) =
-        if let Some((root_op_str, head_op_str)) = args.operation.split_once("..") {
+    let (abandon_root_op, abandon_head_ops) = if let Some(age) = &args.older_than {
+        let cutoff = MillisSinceEpoch(Timestamp::now().timestamp.0 - parse_age(age)?);
+        let newest_old_op = op_walk::walk_ancestors(&current_head_ops)
+            .filter_ok(|op| {
+                !current_head_ops.contains(op) && op.metadata().time.end.timestamp < cutoff
+            })
+            .next()
+            .transpose()?;
+        let Some(op) = newest_old_op else {
+            writeln!(ui.status(), "Nothing changed.")?;
+            return Ok(());
+        };
+        (repo_loader.root_operation(), vec![op])
+    } else {
+        let operation = args.operation.as_deref().expect("required by clap group");
+        if let Some((root_op_str, head_op_str)) = operation.split_once("..") {
             let root_op = if root_op_str.is_empty() {
                 repo_loader.root_operation()
             } else {
@@ -81,7 +111,7 @@ pub fn cmd_op_abandon(
             };
             (root_op, head_ops)
         } else {
-            let op = resolve_op(&args.operation)?;
+            let op = resolve_op(operation)?;
             let parent_ops: Vec<_> = op.parents().try_collect()?;
             let parent_op = match parent_ops.len() {
                 0 => return Err(user_error("Cannot abandon the root operation")),
@@ -89,7 +119,8 @@ pub fn cmd_op_abandon(
                 _ => return Err(user_error("Cannot abandon a merge operation")),
             };
             (parent_op, vec![op])
-        };
+        }
+    };
 
     if let Some(op) = abandon_head_ops
         .iter()
@@ -133,6 +164,22 @@ pub fn cmd_op_abandon(
             .update_op_heads(slice::from_ref(old.id()), new_id)
             .block_on()?;
     }
+    // Index the reparented operations now, so the next command won't report
+    // their index files as missing. This is best effort as the index would be
+    // rebuilt anyway.
+    if let Some(default_index_store) = repo_loader
+        .index_store()
+        .downcast_ref::<DefaultIndexStore>()
+    {
+        for (_, new_id) in reparented_head_ops().filter(|&(old, new_id)| old.id() != new_id) {
+            if let Ok(op) = repo_loader.load_operation(new_id) {
+                default_index_store
+                    .build_index_at_operation(&op, repo_loader.store())
+                    .block_on()
+                    .ok();
+            }
+        }
+    }
     // Remap the operation id of the current workspace. If there were any
     // divergent operations, user will need to re-abandon their ancestors.
     if !command.global_args().ignore_working_copy {
@@ -154,3 +201,27 @@ pub fn cmd_op_abandon(
     }
     Ok(())
 }
+
+/// Parses an age such as `30d` into milliseconds.
+fn parse_age(age: &str) -> Result<i64, CommandError> {
+    let invalid_age = || {
+        user_error_with_hint(
+            format!("Invalid age: {age}"),
+            "Use a number followed by one of the units s, m, h, d, or w, e.g. `30d`",
+        )
+    };
+    let unit_pos = age
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(invalid_age)?;
+    let (count, unit) = age.split_at(unit_pos);
+    let count: i64 = count.parse().map_err(|_| invalid_age())?;
+    let unit_millis = match unit {
+        "s" => 1000,
+        "m" => 60 * 1000,
+        "h" => 60 * 60 * 1000,
+        "d" => 24 * 60 * 60 * 1000,
+        "w" => 7 * 24 * 60 * 60 * 1000,
+        _ => return Err(invalid_age()),
+    };
+    count.checked_mul(unit_millis).ok_or_else(invalid_age)
+}
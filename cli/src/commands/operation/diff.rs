@@ -594,6 +594,7 @@ async fn show_change_diff(
                     predecessors.get(..1).unwrap_or(&[]),
                     commit,
                     &EverythingMatcher,
+                    None,
                     width,
                 )
                 .await?;
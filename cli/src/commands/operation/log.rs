@@ -12,13 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::io::Write as _;
 use std::slice;
 
+use clap::ValueEnum;
 use clap_complete::ArgValueCandidates;
 use itertools::Itertools as _;
+use jj_lib::default_index::DefaultIndexStore;
 use jj_lib::graph::GraphEdge;
 use jj_lib::graph::reverse_graph;
+use jj_lib::object_id::ObjectId as _;
 use jj_lib::op_store::OpStoreError;
+use jj_lib::op_store::TimestampRange;
 use jj_lib::op_walk;
 use jj_lib::operation::Operation;
 use jj_lib::repo::RepoLoader;
@@ -28,12 +34,14 @@ use crate::cli_util::CommandHelper;
 use crate::cli_util::LogContentFormat;
 use crate::cli_util::WorkspaceCommandEnvironment;
 use crate::cli_util::format_template;
+use crate::cli_util::parse_op_tag;
 use crate::command_error::CommandError;
 use crate::complete;
 use crate::diff_util::DiffFormatArgs;
 use crate::diff_util::DiffRenderer;
 use crate::diff_util::diff_formats_for_log;
 use crate::formatter::Formatter;
+use crate::formatter::FormatterExt as _;
 use crate::graphlog::GraphStyle;
 use crate::graphlog::get_graphlog;
 use crate::operation_templater::OperationTemplateLanguage;
@@ -59,6 +67,12 @@ pub struct OperationLogArgs {
     /// Don't show the graph, show a flat list of operations
     #[arg(long, short = 'G')]
     no_graph: bool,
+    /// Only show operations with the given tag (can be repeated)
+    ///
+    /// Tags can be attached to operations with the global `--op-tag` option.
+    /// If repeated, only operations with all the given tags are shown.
+    #[arg(long, value_name = "KEY=VALUE", value_parser = parse_op_tag)]
+    tag: Vec<(String, String)>,
     /// Render each operation using the given template
     ///
     /// You can specify arbitrary template expressions using the
@@ -72,6 +86,19 @@ pub struct OperationLogArgs {
     ///     https://jj-vcs.github.io/jj/latest/templates/
     #[arg(long, short = 'T', add = ArgValueCandidates::new(complete::template_aliases))]
     template: Option<String>,
+    /// Output format
+    ///
+    /// With `json`, a JSON array of operations is printed, newest first. Each
+    /// operation includes its full id, parent ids, time, description, user,
+    /// and tags. Bookmark changes recorded by `jj git fetch --record-changes`
+    /// are included as `fetched_bookmarks`.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        conflicts_with_all = ["template", "op_diff", "patch", "show_index_events"]
+    )]
+    format: LogFormat,
     /// Show changes to the repository at each operation
     #[arg(long, short = 'd')]
     op_diff: bool,
@@ -82,10 +109,72 @@ pub struct OperationLogArgs {
     /// contaminated by unrelated changes.
     #[arg(long, short = 'p')]
     patch: bool,
+    /// Annotate operations whose index had to be rebuilt when loaded
+    ///
+    /// The index at an operation is rebuilt if its files are missing or
+    /// corrupt, e.g. because the operation was rewritten by `jj op abandon`.
+    #[arg(long)]
+    show_index_events: bool,
     #[command(flatten)]
     diff_format: DiffFormatArgs,
 }
 
+/// Output format for the `--format` argument option.
+#[derive(Copy, Clone, Debug, Default, ValueEnum)]
+pub(super) enum LogFormat {
+    /// Render each operation with the template
+    #[default]
+    Text,
+    /// Print all operations as a JSON array
+    Json,
+}
+
+/// Operation tag in which `jj git fetch --record-changes` stores the changed
+/// remote bookmarks as JSON.
+const FETCHED_BOOKMARKS_TAG: &str = "fetched_bookmarks";
+
+#[derive(Debug, serde::Serialize)]
+pub(super) struct JsonOperation<'a> {
+    /// Full id of the operation.
+    id: String,
+    /// Full ids of the parent operations.
+    parents: Vec<String>,
+    time: &'a TimestampRange,
+    description: &'a str,
+    user: String,
+    tags: BTreeMap<&'a str, &'a str>,
+    /// Remote bookmark changes recorded by `jj git fetch --record-changes`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fetched_bookmarks: Option<serde_json::Value>,
+}
+
+impl<'a> JsonOperation<'a> {
+    pub(super) fn new(op: &'a Operation) -> Self {
+        let metadata = op.metadata();
+        let mut tags: BTreeMap<_, _> = metadata
+            .tags
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+        // Embed the recorded changes as JSON rather than as an encoded string.
+        let fetched_bookmarks = tags
+            .get(FETCHED_BOOKMARKS_TAG)
+            .and_then(|value| serde_json::from_str(value).ok());
+        if fetched_bookmarks.is_some() {
+            tags.remove(FETCHED_BOOKMARKS_TAG);
+        }
+        Self {
+            id: op.id().hex(),
+            parents: op.parent_ids().iter().map(|id| id.hex()).collect(),
+            time: &metadata.time,
+            description: &metadata.description,
+            user: format!("{}@{}", metadata.username, metadata.hostname),
+            tags,
+            fetched_bookmarks,
+        }
+    }
+}
+
 pub fn cmd_op_log(
     ui: &mut Ui,
     command: &CommandHelper,
@@ -115,6 +204,10 @@ fn do_op_log(
     current_op: &Operation,
     args: &OperationLogArgs,
 ) -> Result<(), CommandError> {
+    if let LogFormat::Json = args.format {
+        return write_json_op_log(ui, current_op, args);
+    }
+
     let settings = repo_loader.settings();
     let graph_style = GraphStyle::from_settings(settings)?;
     let with_content_format = LogContentFormat::new(ui, settings)?;
@@ -196,11 +289,27 @@ fn do_op_log(
         None
     };
 
+    let default_index_store = repo_loader
+        .index_store()
+        .downcast_ref::<DefaultIndexStore>();
+    let show_index_event = |formatter: &mut dyn Formatter, op: &Operation| {
+        if args.show_index_events
+            && default_index_store.is_some_and(|store| store.is_reindexed_at_operation(op.id()))
+        {
+            writeln!(
+                formatter.labeled("index_event"),
+                "(index was rebuilt when loaded)"
+            )?;
+        }
+        Ok::<_, CommandError>(())
+    };
+
     ui.request_pager();
     let mut formatter = ui.stdout_formatter();
     let formatter = formatter.as_mut();
-    let iter =
-        op_walk::walk_ancestors(slice::from_ref(current_op)).take(args.limit.unwrap_or(usize::MAX));
+    let iter = op_walk::walk_ancestors(slice::from_ref(current_op))
+        .filter_ok(|op| has_tags(op, &args.tag))
+        .take(args.limit.unwrap_or(usize::MAX));
 
     if !args.no_graph {
         let mut raw_output = formatter.raw()?;
@@ -208,7 +317,12 @@ fn do_op_log(
         let iter = iter.map(|op| -> Result<_, OpStoreError> {
             let op = op?;
             let ids = op.parent_ids();
-            let edges = ids.iter().cloned().map(GraphEdge::direct).collect();
+            // The parents may have been filtered out by --tag
+            let edges = if args.tag.is_empty() {
+                ids.iter().cloned().map(GraphEdge::direct).collect()
+            } else {
+                ids.iter().cloned().map(GraphEdge::missing).collect()
+            };
             Ok((op, edges))
         });
         let iter_nodes: Box<dyn Iterator<Item = _>> = if args.reversed {
@@ -226,6 +340,7 @@ fn do_op_log(
             if !buffer.ends_with(b"\n") {
                 buffer.push(b'\n');
             }
+            show_index_event(ui.new_formatter(&mut buffer).as_mut(), &op)?;
             if let Some(show) = &maybe_show_op_diff {
                 let mut formatter = ui.new_formatter(&mut buffer);
                 show(ui, formatter.as_mut(), &op, &within_graph)?;
@@ -247,6 +362,7 @@ fn do_op_log(
         for op in iter {
             let op = op?;
             with_content_format.write(formatter, |formatter| template.format(&op, formatter))?;
+            show_index_event(formatter, &op)?;
             if let Some(show) = &maybe_show_op_diff {
                 show(ui, formatter, &op, &with_content_format)?;
             }
@@ -255,3 +371,28 @@ fn do_op_log(
 
     Ok(())
 }
+
+fn write_json_op_log(
+    ui: &mut Ui,
+    current_op: &Operation,
+    args: &OperationLogArgs,
+) -> Result<(), CommandError> {
+    let mut ops: Vec<_> = op_walk::walk_ancestors(slice::from_ref(current_op))
+        .filter_ok(|op| has_tags(op, &args.tag))
+        .take(args.limit.unwrap_or(usize::MAX))
+        .try_collect()?;
+    if args.reversed {
+        ops.reverse();
+    }
+    let json_ops = ops.iter().map(JsonOperation::new).collect_vec();
+    ui.request_pager();
+    let text = serde_json::to_string(&json_ops).expect("serialization shouldn't fail");
+    writeln!(ui.stdout(), "{text}")?;
+    Ok(())
+}
+
+fn has_tags(op: &Operation, tags: &[(String, String)]) -> bool {
+    let op_tags = &op.metadata().tags;
+    tags.iter()
+        .all(|(key, value)| op_tags.get(key) == Some(value))
+}
@@ -84,6 +84,9 @@ pub(crate) enum RevertWhatToRestore {
     /// The remote-tracking bookmarks. Do not restore these if you'd like to
     /// push after the undo
     RemoteTracking,
+    /// The local bookmarks only. Commits and remote-tracking bookmarks are left
+    /// as they are
+    Bookmarks,
 }
 
 // pub for `jj undo`
@@ -103,6 +106,13 @@ pub(crate) fn view_with_desired_portions_restored(
     } else {
         current_view
     };
+    let bookmark_source = if what.contains(&RevertWhatToRestore::Repo)
+        || what.contains(&RevertWhatToRestore::Bookmarks)
+    {
+        view_being_restored
+    } else {
+        current_view
+    };
     let remote_source = if what.contains(&RevertWhatToRestore::RemoteTracking) {
         view_being_restored
     } else {
@@ -110,7 +120,7 @@ pub(crate) fn view_with_desired_portions_restored(
     };
     jj_lib::op_store::View {
         head_ids: repo_source.head_ids.clone(),
-        local_bookmarks: repo_source.local_bookmarks.clone(),
+        local_bookmarks: bookmark_source.local_bookmarks.clone(),
         local_tags: repo_source.local_tags.clone(),
         remote_views: remote_source.remote_views.clone(),
         git_refs: current_view.git_refs.clone(),
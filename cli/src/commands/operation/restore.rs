@@ -17,10 +17,13 @@ use jj_lib::object_id::ObjectId as _;
 
 use super::DEFAULT_REVERT_WHAT;
 use super::RevertWhatToRestore;
+use super::diff::show_op_diff;
 use super::view_with_desired_portions_restored;
 use crate::cli_util::CommandHelper;
+use crate::cli_util::LogContentFormat;
 use crate::command_error::CommandError;
 use crate::complete;
+use crate::graphlog::GraphStyle;
 use crate::ui::Ui;
 
 /// Create a new operation that restores the repo to an earlier state
@@ -42,6 +45,13 @@ pub struct OperationRestoreArgs {
     /// This option is EXPERIMENTAL.
     #[arg(long, value_enum, default_values_t = DEFAULT_REVERT_WHAT)]
     what: Vec<RevertWhatToRestore>,
+
+    /// Only display what would change in the repo
+    ///
+    /// The changes are shown the same way as by `jj op diff`, but no operation
+    /// is created.
+    #[arg(long)]
+    dry_run: bool,
 }
 
 pub fn cmd_op_restore(
@@ -58,6 +68,51 @@ pub fn cmd_op_restore(
         &args.what,
     );
     tx.repo_mut().set_view(new_view);
+    if args.dry_run {
+        let base_repo = tx.base_repo().clone();
+        // The operation is written so the restored state can be loaded, but
+        // it's never published as an operation head.
+        let new_repo = tx
+            .into_inner()
+            .write(format!("restore to operation {}", target_op.id().hex()))?
+            .leave_unpublished();
+
+        let workspace_env = workspace_command.env();
+        let settings = workspace_command.settings();
+        let id_prefix_context = workspace_env.new_id_prefix_context();
+        let commit_summary_template = {
+            let language =
+                workspace_env.commit_template_language(new_repo.as_ref(), &id_prefix_context);
+            let text = settings.get_string("templates.commit_summary")?;
+            workspace_env
+                .parse_template(ui, &language, &text)?
+                .labeled(["op_diff", "commit"])
+        };
+        let graph_style = GraphStyle::from_settings(settings)?;
+        let with_content_format = LogContentFormat::new(ui, settings)?;
+
+        if let Some(mut formatter) = ui.status_formatter() {
+            write!(formatter, "Would restore to operation: ")?;
+            let template = workspace_command.operation_summary_template();
+            template.format(&target_op, formatter.as_mut())?;
+            writeln!(formatter)?;
+        }
+        // The base repo's index is an ancestor of the new repo's, so the new
+        // repo contains all commits of both.
+        show_op_diff(
+            ui,
+            ui.stdout_formatter().as_mut(),
+            new_repo.as_ref(),
+            &base_repo,
+            &new_repo,
+            &commit_summary_template,
+            Some(graph_style),
+            &with_content_format,
+            None,
+        )?;
+        writeln!(ui.status(), "Dry-run requested, not restoring.")?;
+        return Ok(());
+    }
     if let Some(mut formatter) = ui.status_formatter() {
         write!(formatter, "Restored to operation: ")?;
         let template = tx.base_workspace_helper().operation_summary_template();
@@ -12,11 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::io::Write as _;
+
 use clap_complete::ArgValueCandidates;
 use itertools::Itertools as _;
 use jj_lib::operation::Operation;
 
 use super::diff::show_op_diff;
+use super::log::JsonOperation;
+use super::log::LogFormat;
 use crate::cli_util::CommandHelper;
 use crate::cli_util::LogContentFormat;
 use crate::command_error::CommandError;
@@ -60,6 +64,19 @@ pub struct OperationShowArgs {
     /// Do not show operation diff
     #[arg(long, conflicts_with_all = ["patch", "DiffFormatArgs"])]
     no_op_diff: bool,
+    /// Output format
+    ///
+    /// With `json`, the operation is printed as a JSON object including its
+    /// full id, parent ids, time, description, user, and tags. Bookmark changes
+    /// recorded by `jj git fetch --record-changes` are included as
+    /// `fetched_bookmarks`. The operation diff isn't shown.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        conflicts_with_all = ["template", "patch", "DiffFormatArgs"]
+    )]
+    format: LogFormat,
     #[command(flatten)]
     diff_format: DiffFormatArgs,
 }
@@ -74,6 +91,13 @@ pub fn cmd_op_show(
     let repo_loader = workspace_command.workspace().repo_loader();
     let settings = workspace_command.settings();
     let op = workspace_command.resolve_single_op(&args.operation)?;
+    if let LogFormat::Json = args.format {
+        ui.request_pager();
+        let text =
+            serde_json::to_string(&JsonOperation::new(&op)).expect("serialization shouldn't fail");
+        writeln!(ui.stdout(), "{text}")?;
+        return Ok(());
+    }
     let parent_ops: Vec<_> = op.parents().try_collect()?;
     let merged_parent_op = repo_loader.merge_operations(parent_ops.clone(), None)?;
     let parent_repo = repo_loader.load_at(&merged_parent_op)?;
@@ -13,33 +13,64 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fs;
 use std::iter::once;
+use std::slice;
 
 use clap_complete::ArgValueCandidates;
 use clap_complete::ArgValueCompleter;
+use futures::StreamExt as _;
 use indoc::formatdoc;
 use itertools::Itertools as _;
+use jj_lib::backend::CommitId;
 use jj_lib::commit::Commit;
 use jj_lib::commit::CommitIteratorExt as _;
+use jj_lib::commit_builder::DetachedCommitBuilder;
+use jj_lib::conflicts::MaterializedTreeValue;
+use jj_lib::conflicts::materialize_tree_value;
+use jj_lib::diff_presentation::file_content_for_diff;
+use jj_lib::matchers::DifferenceMatcher;
+use jj_lib::matchers::EverythingMatcher;
+use jj_lib::matchers::FilesMatcher;
 use jj_lib::matchers::Matcher;
 use jj_lib::merge::Diff;
+use jj_lib::merged_tree::MergedTree;
+use jj_lib::merged_tree::TreeDiffEntry;
 use jj_lib::object_id::ObjectId as _;
+use jj_lib::op_store::RefTarget;
+use jj_lib::ref_name::RefNameBuf;
+use jj_lib::repo::MutableRepo;
 use jj_lib::repo::Repo as _;
+use jj_lib::repo_path::RepoPathBuf;
+use jj_lib::revset::RevsetExpression;
+use jj_lib::revset::RevsetIteratorExt as _;
 use jj_lib::rewrite;
 use jj_lib::rewrite::CommitWithSelection;
+use jj_lib::rewrite::RebaseOptions;
+use jj_lib::rewrite::RebasedCommit;
+use jj_lib::rewrite::SquashedCommit;
 use jj_lib::rewrite::merge_commit_trees;
+use jj_lib::rewrite::restore_tree;
+use jj_lib::store::Store;
+use jj_lib::view::View;
 use pollster::FutureExt as _;
 use tracing::instrument;
 
 use crate::cli_util::CommandHelper;
 use crate::cli_util::DiffSelector;
 use crate::cli_util::RevisionArg;
+use crate::cli_util::WorkspaceCommandHelper;
 use crate::cli_util::WorkspaceCommandTransaction;
 use crate::cli_util::compute_commit_location;
 use crate::cli_util::print_unmatched_explicit_paths;
+use crate::cli_util::short_commit_hash;
+use crate::cli_util::short_operation_hash;
 use crate::command_error::CommandError;
+use crate::command_error::internal_error;
 use crate::command_error::user_error;
 use crate::command_error::user_error_with_hint;
+use crate::command_error::user_error_with_message;
 use crate::complete;
 use crate::description_util::add_trailers;
 use crate::description_util::combine_messages_for_editing;
@@ -47,6 +78,7 @@ use crate::description_util::description_template;
 use crate::description_util::edit_description;
 use crate::description_util::join_message_paragraphs;
 use crate::description_util::try_combine_messages;
+use crate::text_util::complete_newline;
 use crate::ui::Ui;
 
 /// Move changes from a revision into another revision
@@ -66,7 +98,9 @@ use crate::ui::Ui;
 ///
 /// If the source was abandoned and both the source and destination had a
 /// non-empty description, you will be asked for the combined description. If
-/// either was empty, then the other one will be used.
+/// either was empty, then the other one will be used. Leaving the combined
+/// description empty aborts the squash unless `--allow-empty-description` is
+/// set.
 ///
 /// If a working-copy commit gets abandoned, it will be given a new, empty
 /// commit. This is true in general; it is not specific to this command.
@@ -97,7 +131,43 @@ pub(crate) struct SquashArgs {
     )]
     from: Vec<RevisionArg>,
 
+    /// Exclude the working-copy commit from the `--from` revisions
+    ///
+    /// By default, the working-copy commit is squashed along with the other
+    /// source revisions if `--from` includes it.
+    #[arg(long, requires = "from")]
+    no_working_copy: bool,
+
+    /// Squash the working-copy changes snapshotted by the given operation
+    ///
+    /// The changes are moved out of the current version of the working-copy
+    /// revision they were snapshotted into, even if that revision has been
+    /// modified since.
+    #[arg(
+        long,
+        value_name = "OPERATION",
+        conflicts_with_all = [
+            "revision",
+            "from",
+            "onto",
+            "insert_after",
+            "insert_before",
+            "interactive",
+            "tool",
+            "paths",
+            "paths_from_file",
+        ],
+        add = ArgValueCandidates::new(complete::operations),
+    )]
+    from_op: Option<String>,
+
     /// Revision to squash into (default: @)
+    ///
+    /// Can be repeated to distribute the changes of a single source revision
+    /// among several destinations. Each changed path is moved into the
+    /// destination which already modifies that path. If the source is
+    /// abandoned, its description is combined with the description of the
+    /// last destination.
     #[arg(
         long, short = 't',
         conflicts_with = "revision",
@@ -105,7 +175,7 @@ pub(crate) struct SquashArgs {
         value_name = "REVSET",
         add = ArgValueCompleter::new(complete::revset_expression_mutable),
     )]
-    into: Option<RevisionArg>,
+    into: Vec<RevisionArg>,
 
     /// (Experimental) The revision(s) to use as parent for the new commit (can
     /// be repeated to create a merge commit)
@@ -158,6 +228,16 @@ pub(crate) struct SquashArgs {
     #[arg(long, short, conflicts_with = "message_paragraphs")]
     use_destination_message: bool,
 
+    /// Read the description for the squashed revision from the given file
+    /// (don't open editor)
+    #[arg(
+        long,
+        value_name = "PATH",
+        value_hint = clap::ValueHint::FilePath,
+        conflicts_with_all = ["message_paragraphs", "use_destination_message"]
+    )]
+    message_from_file: Option<String>,
+
     /// Open an editor to edit the change description
     ///
     /// Forces an editor to open when using `--message` to allow the
@@ -165,18 +245,51 @@ pub(crate) struct SquashArgs {
     #[arg(long)]
     editor: bool,
 
+    /// Open an editor on the resulting description, even if it was combined
+    /// automatically
+    ///
+    /// The editor is seeded with the description that would otherwise have
+    /// been used, e.g. the only non-empty one.
+    #[arg(
+        long,
+        conflicts_with_all = ["message_paragraphs", "message_from_file", "use_destination_message"]
+    )]
+    describe_after: bool,
+
+    /// Keep the squash even if the combined description is left empty in
+    /// the editor
+    ///
+    /// By default, leaving the combined description empty aborts the squash.
+    #[arg(
+        long,
+        conflicts_with_all = ["message_paragraphs", "message_from_file", "use_destination_message"]
+    )]
+    allow_empty_description: bool,
+
     /// Interactively choose which parts to squash
     #[arg(long, short)]
     interactive: bool,
 
     /// Specify diff editor to be used (implies --interactive)
+    ///
+    /// This overrides the `ui.diff-editor` setting for this invocation only.
     #[arg(
         long,
+        visible_alias = "interactive-tool",
         value_name = "NAME",
         add = ArgValueCandidates::new(complete::diff_editors),
     )]
     tool: Option<String>,
 
+    /// How to handle changed binary files
+    ///
+    /// With `move`, binary files are moved wholesale without being passed to
+    /// the diff editor. With `skip`, they are left in the source revision.
+    /// With `prompt`, they are passed to the diff editor along with the other
+    /// files.
+    #[arg(long, value_enum, default_value_t, value_name = "MODE")]
+    binary: BinaryFilesMode,
+
     /// Move only changes to these paths (instead of all paths)
     #[arg(
         value_name = "FILESETS",
@@ -185,9 +298,42 @@ pub(crate) struct SquashArgs {
     )]
     paths: Vec<String>,
 
+    /// Read additional filesets from the given file
+    ///
+    /// The file should contain one fileset expression per line. Blank lines
+    /// and lines starting with `#` are ignored. The filesets are combined
+    /// with the ones specified as positional arguments.
+    #[arg(long, value_name = "PATH", value_hint = clap::ValueHint::FilePath)]
+    paths_from_file: Option<String>,
+
     /// The source revision will not be abandoned
     #[arg(long, short)]
     keep_emptied: bool,
+
+    /// Delete bookmarks pointing to the source revisions which are abandoned
+    ///
+    /// By default, such bookmarks are moved to the parents of the abandoned
+    /// revisions.
+    #[arg(long, conflicts_with = "keep_emptied")]
+    delete_emptied_bookmarks: bool,
+
+    /// Don't rebase the descendants of the squashed revisions
+    ///
+    /// The descendants are left on top of the old revisions, which stay
+    /// visible. The destination revision becomes divergent with its old
+    /// version.
+    #[arg(long, conflicts_with_all = ["onto", "insert_after", "insert_before"])]
+    no_rebase_descendants: bool,
+
+    /// Which revision's author the squashed revision keeps
+    ///
+    /// With `source`, there must be a single source revision.
+    #[arg(long, value_enum, default_value_t, value_name = "REVISION")]
+    keep_author: KeepAuthor,
+
+    /// Explain why nothing was squashed, if that's the case
+    #[arg(long)]
+    verbose: bool,
 }
 
 #[instrument(skip_all)]
@@ -200,27 +346,82 @@ pub(crate) fn cmd_squash(
         args.onto.is_some() || args.insert_after.is_some() || args.insert_before.is_some();
 
     let mut workspace_command = command.workspace_helper(ui)?;
+    let paths = collect_filesets(command, args)?;
 
+    if args.into.len() > 1 {
+        if args.from_op.is_some() {
+            return Err(user_error(
+                "Cannot squash the changes of an operation into multiple revisions",
+            ));
+        }
+        return squash_into_multiple(ui, command, &mut workspace_command, args, &paths);
+    }
+
+    let snapshot_changes = args
+        .from_op
+        .as_deref()
+        .map(|op_str| resolve_snapshot_changes(&workspace_command, op_str))
+        .transpose()?;
     let mut sources: Vec<Commit>;
     let pre_existing_destination;
 
-    if !args.from.is_empty() || args.into.is_some() || insert_destination_commit {
-        sources = if args.from.is_empty() {
-            workspace_command.parse_revset(ui, &RevisionArg::AT)?
+    if !args.from.is_empty()
+        || !args.into.is_empty()
+        || insert_destination_commit
+        || snapshot_changes.is_some()
+    {
+        sources = if let Some(changes) = &snapshot_changes {
+            vec![changes.commit.clone()]
+        } else if args.from.is_empty() {
+            workspace_command
+                .parse_revset(ui, &RevisionArg::AT)?
+                .evaluate_to_commits()?
+                .try_collect()?
         } else {
-            workspace_command.parse_union_revsets(ui, &args.from)?
-        }
-        .evaluate_to_commits()?
-        .try_collect()?;
+            workspace_command
+                .parse_union_revsets(ui, &args.from)?
+                .evaluate_to_commits()?
+                .try_collect()?
+        };
         if insert_destination_commit {
             pre_existing_destination = None;
         } else {
-            let destination = workspace_command
-                .resolve_single_rev(ui, args.into.as_ref().unwrap_or(&RevisionArg::AT))?;
+            let destination = resolve_destination(
+                ui,
+                &workspace_command,
+                args.into.first().unwrap_or(&RevisionArg::AT),
+                &sources,
+            )?;
+            if let Some(changes) = &snapshot_changes
+                && changes.commit.id() == destination.id()
+            {
+                return Err(user_error(
+                    "Cannot squash the snapshotted changes into the revision they were \
+                     snapshotted into",
+                ));
+            }
             // remove the destination from the sources
             sources.retain(|source| source.id() != destination.id());
             pre_existing_destination = Some(destination);
         }
+        if !args.from.is_empty()
+            && let Some(wc_commit_id) = workspace_command.get_wc_commit_id()
+            && sources.iter().any(|source| source.id() == wc_commit_id)
+        {
+            if args.no_working_copy {
+                let wc_commit_id = wc_commit_id.clone();
+                sources.retain(|source| *source.id() != wc_commit_id);
+            } else {
+                writeln!(
+                    ui.warning_default(),
+                    "The source revisions include the working-copy commit"
+                )?;
+                writeln!(
+                    ui.hint_default(),
+                    "Use `--no-working-copy` to exclude it from the source revisions."
+                )?;
+            }
+        }
         // Reverse the set so we apply the oldest commits first. It shouldn't affect the
         // result, but it avoids creating transient conflicts and is therefore probably
         // a little faster.
@@ -230,9 +431,11 @@ pub(crate) fn cmd_squash(
             .resolve_single_rev(ui, args.revision.as_ref().unwrap_or(&RevisionArg::AT))?;
         let mut parents: Vec<_> = source.parents().try_collect()?;
         if parents.len() != 1 {
-            return Err(user_error_with_hint(
+            return Err(merge_parents_error(
+                &workspace_command,
                 "Cannot squash merge commits without a specified destination",
-                "Use `--into` to specify which parent to squash into",
+                &source,
+                &parents,
             ));
         }
         sources = vec![source];
@@ -313,17 +516,44 @@ pub(crate) fn cmd_squash(
         commit
     };
 
-    let fileset_expression = tx
-        .base_workspace_helper()
-        .parse_file_patterns(ui, &args.paths)?;
+    let fileset_expression = tx.base_workspace_helper().parse_file_patterns(ui, &paths)?;
     let matcher = fileset_expression.to_matcher();
     let diff_selector =
         tx.base_workspace_helper()
             .diff_selector(ui, args.tool.as_deref(), args.interactive)?;
-    let text_editor = tx.base_workspace_helper().text_editor()?;
-    let squashed_description = SquashedDescription::from_args(args);
+    let squashed_description = SquashedDescription::from_args(command, args)?;
+    let source_author = match (args.keep_author, sources.as_slice()) {
+        (KeepAuthor::Destination, _) => None,
+        (KeepAuthor::Source, [source]) => Some(source.author().clone()),
+        (KeepAuthor::Source, _) => {
+            return Err(user_error(
+                "Cannot keep the author of the source with multiple source revisions",
+            ));
+        }
+    };
 
-    let source_commits = select_diff(&tx, &sources, &destination, &matcher, &diff_selector)?;
+    let (source_commits, keep_emptied) = if let Some(changes) = snapshot_changes {
+        // The snapshotted changes may be only part of the changes in the
+        // source, in which case it shouldn't be abandoned.
+        let keep_emptied = args.keep_emptied
+            || changes.old_tree.tree_ids() != changes.commit.parent_tree(tx.repo())?.tree_ids();
+        let selection = CommitWithSelection {
+            commit: changes.commit,
+            selected_tree: changes.new_tree,
+            parent_tree: changes.old_tree,
+        };
+        (vec![selection], keep_emptied)
+    } else {
+        let source_commits = select_diff(
+            &tx,
+            &sources,
+            &destination,
+            &matcher,
+            &diff_selector,
+            args.binary,
+        )?;
+        (source_commits, args.keep_emptied)
+    };
 
     print_unmatched_explicit_paths(
         ui,
@@ -332,55 +562,35 @@ pub(crate) fn cmd_squash(
         source_commits.iter().map(|commit| &commit.selected_tree),
     )?;
 
-    if let Some(squashed) = rewrite::squash_commits(
-        tx.repo_mut(),
-        &source_commits,
-        &destination,
-        args.keep_emptied,
-    )? {
-        let mut commit_builder = squashed.commit_builder.detach();
-        let single_description = match squashed_description {
-            SquashedDescription::Exact(description) => Some(description),
-            SquashedDescription::UseDestination => Some(destination.description().to_owned()),
-            SquashedDescription::Combine => {
-                let abandoned_commits = &squashed.abandoned_commits;
-                try_combine_messages(abandoned_commits, &destination)
-            }
-        };
-        let description = if let Some(description) = single_description {
-            if description.is_empty() && !args.editor {
-                description
-            } else {
-                commit_builder.set_description(&description);
-                let description_with_trailers = add_trailers(ui, &tx, &commit_builder)?;
-                if args.editor {
-                    commit_builder.set_description(&description_with_trailers);
-                    let temp_commit = commit_builder.write_hidden()?;
-                    let intro = "";
-                    let template = description_template(ui, &tx, intro, &temp_commit)?;
-                    edit_description(&text_editor, &template)?
-                } else {
-                    description_with_trailers
-                }
-            }
+    if let Some(squashed) =
+        rewrite::squash_commits(tx.repo_mut(), &source_commits, &destination, keep_emptied)?
+    {
+        let SquashedCommit {
+            commit_builder,
+            abandoned_commits,
+        } = squashed;
+        let mut commit_builder = commit_builder.detach();
+        if let Some(author) = source_author {
+            commit_builder.set_author(author);
+        }
+        let deleted_bookmarks = if args.delete_emptied_bookmarks {
+            emptied_bookmarks(
+                tx.base_repo().view(),
+                abandoned_commits.iter().map(|commit| commit.id()),
+            )
         } else {
-            // edit combined
-            let abandoned_commits = &squashed.abandoned_commits;
-            let combined = combine_messages_for_editing(
-                ui,
-                &tx,
-                abandoned_commits,
-                (!insert_destination_commit).then_some(&destination),
-                &commit_builder,
-            )?;
-            // It's weird that commit.description() contains "JJ: " lines, but works.
-            commit_builder.set_description(combined);
-            let temp_commit = commit_builder.write_hidden()?;
-            let intro = "Enter a description for the combined commit.";
-            let template = description_template(ui, &tx, intro, &temp_commit)?;
-            edit_description(&text_editor, &template)?
+            vec![]
         };
-        commit_builder.set_description(description);
+        describe_squashed_commit(
+            ui,
+            &tx,
+            args,
+            &squashed_description,
+            &abandoned_commits,
+            &destination,
+            insert_destination_commit,
+            &mut commit_builder,
+        )?;
         if insert_destination_commit {
             // forget about the intermediate commit
             commit_builder.set_predecessors(
@@ -393,21 +603,74 @@ pub(crate) fn cmd_squash(
             );
         }
         let commit = commit_builder.write(tx.repo_mut())?;
+        let num_left_in_place = if args.no_rebase_descendants {
+            let abandoned_ids = abandoned_commits
+                .iter()
+                .map(|commit| commit.id())
+                .collect_vec();
+            leave_descendants_in_place(
+                tx.repo_mut(),
+                once(&destination).chain(&sources),
+                &abandoned_ids,
+                commit.id(),
+            )?
+        } else {
+            0
+        };
         let num_rebased = tx.repo_mut().rebase_descendants()?;
+        for name in &deleted_bookmarks {
+            tx.repo_mut()
+                .set_local_bookmark_target(name, RefTarget::absent());
+        }
         if let Some(mut formatter) = ui.status_formatter() {
             if insert_destination_commit {
                 write!(formatter, "Created new commit ")?;
                 tx.write_commit_summary(formatter.as_mut(), &commit)?;
                 writeln!(formatter)?;
             }
+            if !deleted_bookmarks.is_empty() {
+                writeln!(
+                    formatter,
+                    "Deleted bookmarks: {}",
+                    deleted_bookmarks.iter().map(|n| n.as_symbol()).join(", ")
+                )?;
+            }
             if num_rebased > 0 {
                 writeln!(formatter, "Rebased {num_rebased} descendant commits")?;
             }
         }
+        if num_left_in_place > 0 {
+            writeln!(
+                ui.warning_default(),
+                "Left {num_left_in_place} descendant commits on top of the old revisions"
+            )?;
+            writeln!(
+                ui.hint_default(),
+                "The destination revision is now divergent with its old version. Use `jj rebase` \
+                 to move the descendants onto the new revision."
+            )?;
+        }
     } else {
         if diff_selector.is_interactive() {
             return Err(user_error("No changes selected"));
         }
+        if args.verbose {
+            if sources.is_empty() {
+                writeln!(ui.status(), "No source commits matched")?;
+            } else if paths.is_empty() {
+                writeln!(
+                    ui.status(),
+                    "{} source commits matched but none had changes to squash",
+                    sources.len()
+                )?;
+            } else {
+                writeln!(
+                    ui.status(),
+                    "{} source commits matched but none touched the requested paths",
+                    sources.len()
+                )?;
+            }
+        }
 
         if let Some(mut formatter) = ui.status_formatter() {
             if insert_destination_commit {
@@ -421,7 +684,8 @@ pub(crate) fn cmd_squash(
         }
 
         if let [only_path] = &*args.paths {
-            let no_rev_arg = args.revision.is_none() && args.from.is_empty() && args.into.is_none();
+            let no_rev_arg =
+                args.revision.is_none() && args.from.is_empty() && args.into.is_empty();
             if no_rev_arg
                 && tx
                     .base_workspace_helper()
@@ -440,6 +704,550 @@ pub(crate) fn cmd_squash(
     Ok(())
 }
 
+/// Sets the description of the squashed commit, opening an editor if the
+/// descriptions can't be combined automatically or if requested.
+#[expect(clippy::too_many_arguments)]
+fn describe_squashed_commit(
+    ui: &Ui,
+    tx: &WorkspaceCommandTransaction,
+    args: &SquashArgs,
+    squashed_description: &SquashedDescription,
+    abandoned_commits: &[Commit],
+    destination: &Commit,
+    insert_destination_commit: bool,
+    commit_builder: &mut DetachedCommitBuilder,
+) -> Result<(), CommandError> {
+    let text_editor = tx.base_workspace_helper().text_editor()?;
+    let force_editor = args.editor || args.describe_after;
+    let single_description = match squashed_description {
+        SquashedDescription::Exact(description) => Some(description.clone()),
+        SquashedDescription::UseDestination => Some(destination.description().to_owned()),
+        SquashedDescription::Combine => try_combine_messages(abandoned_commits, destination),
+    };
+    let description = if let Some(description) = single_description {
+        if description.is_empty() && !force_editor {
+            description
+        } else {
+            commit_builder.set_description(&description);
+            let description_with_trailers = add_trailers(ui, tx, commit_builder)?;
+            if force_editor {
+                commit_builder.set_description(&description_with_trailers);
+                let temp_commit = commit_builder.write_hidden()?;
+                let intro = "";
+                let template = description_template(ui, tx, intro, &temp_commit)?;
+                edit_description(&text_editor, &template)?
+            } else {
+                description_with_trailers
+            }
+        }
+    } else {
+        // edit combined
+        let combined = combine_messages_for_editing(
+            ui,
+            tx,
+            abandoned_commits,
+            (!insert_destination_commit).then_some(destination),
+            commit_builder,
+        )?;
+        // It's weird that commit.description() contains "JJ: " lines, but works.
+        commit_builder.set_description(combined);
+        let temp_commit = commit_builder.write_hidden()?;
+        let intro = "Enter a description for the combined commit.";
+        let template = description_template(ui, tx, intro, &temp_commit)?;
+        let description = edit_description(&text_editor, &template)?;
+        if description.is_empty() && !args.allow_empty_description {
+            return Err(user_error_with_hint(
+                "Aborted: empty description",
+                "Use --allow-empty-description to squash with an empty description",
+            ));
+        }
+        description
+    };
+    commit_builder.set_description(description);
+    Ok(())
+}
+
+/// Returns the local bookmarks pointing to the given abandoned commits. They
+/// would otherwise follow the abandoned commits to their parents.
+fn emptied_bookmarks<'a>(
+    view: &View,
+    abandoned_ids: impl IntoIterator<Item = &'a CommitId>,
+) -> Vec<RefNameBuf> {
+    abandoned_ids
+        .into_iter()
+        .flat_map(|id| {
+            view.local_bookmarks_for_commit(id)
+                .filter(move |(_, target)| target.as_normal() == Some(id))
+                .map(|(name, _)| name.to_owned())
+        })
+        .collect()
+}
+
+/// Records the rewrites of `old_commits` which have children as divergent, so
+/// that their descendants aren't rebased. The abandoned commits are considered
+/// to be replaced by the new destination. Returns the number of children left
+/// in place.
+fn leave_descendants_in_place<'a>(
+    repo: &mut MutableRepo,
+    old_commits: impl IntoIterator<Item = &'a Commit>,
+    abandoned_ids: &[&CommitId],
+    new_destination_id: &CommitId,
+) -> Result<usize, CommandError> {
+    let old_ids = old_commits
+        .into_iter()
+        .map(|commit| commit.id().clone())
+        .collect_vec();
+    let old_commits_expression = RevsetExpression::commits(old_ids.clone());
+    let children: Vec<Commit> = old_commits_expression
+        .children()
+        .minus(&old_commits_expression)
+        .evaluate(repo)?
+        .iter()
+        .commits(repo.store())
+        .try_collect()?;
+    for old_id in &old_ids {
+        if !children
+            .iter()
+            .any(|child| child.parent_ids().contains(old_id))
+        {
+            continue;
+        }
+        let new_ids = if abandoned_ids.contains(&old_id) {
+            vec![new_destination_id.clone()]
+        } else {
+            repo.new_parents(slice::from_ref(old_id))
+        };
+        repo.set_divergent_rewrite(old_id.clone(), new_ids);
+    }
+    Ok(children.len())
+}
+
+/// Returns the positional filesets followed by the ones read from
+/// `--paths-from-file`.
+fn collect_filesets(
+    command: &CommandHelper,
+    args: &SquashArgs,
+) -> Result<Vec<String>, CommandError> {
+    let mut paths = args.paths.clone();
+    if let Some(file_path) = &args.paths_from_file {
+        let content = fs::read_to_string(command.cwd().join(file_path))
+            .map_err(|err| user_error_with_message(format!("Failed to read {file_path}"), err))?;
+        paths.extend(
+            content
+                .lines()
+                .map(|line| line.trim())
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(|line| line.to_owned()),
+        );
+        if paths.is_empty() {
+            return Err(user_error(format!("No filesets found in {file_path}")));
+        }
+    }
+    Ok(paths)
+}
+
+/// Resolves the `--into` revision. If it resolves to the parents of a merge
+/// source, e.g. `--into @-`, the error lists the parents to choose from.
+fn resolve_destination(
+    ui: &Ui,
+    workspace_command: &WorkspaceCommandHelper,
+    into: &RevisionArg,
+    sources: &[Commit],
+) -> Result<Commit, CommandError> {
+    let err = match workspace_command.resolve_single_rev(ui, into) {
+        Ok(destination) => return Ok(destination),
+        Err(err) => err,
+    };
+    let ids: HashSet<CommitId> = workspace_command
+        .parse_revset(ui, into)?
+        .evaluate_to_commit_ids()?
+        .try_collect()?;
+    let merge_source = sources.iter().find(|source| {
+        source.parent_ids().len() > 1
+            && source.parent_ids().len() == ids.len()
+            && source.parent_ids().iter().all(|id| ids.contains(id))
+    });
+    if let Some(source) = merge_source {
+        let parents: Vec<_> = source.parents().try_collect()?;
+        Err(merge_parents_error(
+            workspace_command,
+            format!("Revset `{into}` resolved to more than one revision"),
+            source,
+            &parents,
+        ))
+    } else {
+        Err(err)
+    }
+}
+
+/// Creates an error for an ambiguous destination among the parents of a
+/// merge commit, suggesting the `--into` arguments to pick one of them.
+fn merge_parents_error(
+    workspace_command: &WorkspaceCommandHelper,
+    message: impl Into<String>,
+    merge: &Commit,
+    parents: &[Commit],
+) -> CommandError {
+    let template = workspace_command.commit_summary_template();
+    let mut err = user_error(message.into());
+    err.add_formatted_hint_with(|formatter| {
+        writeln!(
+            formatter,
+            "Merge commit {} has these parents:",
+            short_commit_hash(merge.id())
+        )?;
+        for parent in parents {
+            write!(formatter, "  ")?;
+            template.format(parent, formatter)?;
+            writeln!(formatter)?;
+        }
+        Ok(())
+    });
+    err.add_hint(format!(
+        "Use {} to specify which parent to squash into",
+        parents
+            .iter()
+            .map(|parent| format!("`--into {}`", short_commit_hash(parent.id())))
+            .join(" or ")
+    ));
+    err
+}
+
+/// Squashes a single source revision into several destinations. Each path
+/// changed in the source is moved into the destination which already modifies
+/// it.
+fn squash_into_multiple(
+    ui: &mut Ui,
+    command: &CommandHelper,
+    workspace_command: &mut WorkspaceCommandHelper,
+    args: &SquashArgs,
+    paths: &[String],
+) -> Result<(), CommandError> {
+    if args.interactive || args.tool.is_some() {
+        return Err(user_error(
+            "Cannot squash interactively into multiple destinations",
+        ));
+    }
+    if !args.message_paragraphs.is_empty()
+        || args.message_from_file.is_some()
+        || args.editor
+        || args.describe_after
+    {
+        return Err(user_error_with_hint(
+            "Cannot set the description when squashing into multiple destinations",
+            "Use `jj describe` to update the descriptions afterwards",
+        ));
+    }
+    if args.no_rebase_descendants {
+        return Err(user_error(
+            "--no-rebase-descendants cannot be used when squashing into multiple destinations",
+        ));
+    }
+
+    let sources: Vec<Commit> = if args.from.is_empty() {
+        workspace_command.parse_revset(ui, &RevisionArg::AT)?
+    } else {
+        workspace_command.parse_union_revsets(ui, &args.from)?
+    }
+    .evaluate_to_commits()?
+    .try_collect()?;
+    let [mut source] = <[Commit; 1]>::try_from(sources).map_err(|_| {
+        user_error("Cannot squash more than one revision into multiple destinations")
+    })?;
+    let mut destinations: Vec<Commit> = args
+        .into
+        .iter()
+        .map(|arg| workspace_command.resolve_single_rev(ui, arg))
+        .try_collect()?;
+    if !destinations.iter().map(|commit| commit.id()).all_unique() {
+        return Err(user_error("Destinations must be distinct revisions"));
+    }
+    for destination in &destinations {
+        if destination.id() == source.id() {
+            return Err(user_error(
+                "The source revision cannot also be a destination",
+            ));
+        }
+        if workspace_command
+            .repo()
+            .index()
+            .is_ancestor(source.id(), destination.id())?
+        {
+            return Err(user_error(format!(
+                "Cannot squash into descendant {} of the source revision when squashing into \
+                 multiple destinations",
+                short_commit_hash(destination.id()),
+            )));
+        }
+    }
+    workspace_command
+        .check_rewritable(once(&source).chain(&destinations).map(|commit| commit.id()))?;
+
+    let fileset_expression = workspace_command.parse_file_patterns(ui, paths)?;
+    let matcher = fileset_expression.to_matcher();
+    print_unmatched_explicit_paths(ui, workspace_command, &fileset_expression, [&source.tree()])?;
+
+    // Assign each path changed in the source to the destination that modifies
+    // it.
+    let repo = workspace_command.repo().as_ref();
+    let mut path_owners: HashMap<RepoPathBuf, Vec<usize>> = HashMap::new();
+    for (index, destination) in destinations.iter().enumerate() {
+        for path in changed_paths(
+            &destination.parent_tree(repo)?,
+            &destination.tree(),
+            &EverythingMatcher,
+        ) {
+            path_owners.entry(path).or_default().push(index);
+        }
+    }
+    let mut assigned_paths = vec![vec![]; destinations.len()];
+    let mut unowned_paths = vec![];
+    for path in changed_paths(&source.parent_tree(repo)?, &source.tree(), matcher.as_ref()) {
+        match path_owners.get(&path).map(|owners| owners.as_slice()) {
+            Some(&[index]) => assigned_paths[index].push(path),
+            Some(owners) => {
+                return Err(user_error(format!(
+                    "Path {} is modified by more than one destination: {}",
+                    workspace_command.format_file_path(&path),
+                    owners
+                        .iter()
+                        .map(|&index| short_commit_hash(destinations[index].id()))
+                        .join(", "),
+                )));
+            }
+            None => unowned_paths.push(path),
+        }
+    }
+    if !unowned_paths.is_empty() {
+        return Err(user_error_with_hint(
+            format!(
+                "No destination modifies {}",
+                unowned_paths
+                    .iter()
+                    .map(|path| workspace_command.format_file_path(path))
+                    .join(", "),
+            ),
+            "Each path must already be modified by one of the destinations",
+        ));
+    }
+
+    let tx_description = format!(
+        "squash commit {} into commits {}",
+        source.id().hex(),
+        destinations
+            .iter()
+            .map(|commit| commit.id().hex())
+            .join(", "),
+    );
+    let squashed_description = SquashedDescription::from_args(command, args)?;
+    let original_source_id = source.id().clone();
+    let mut deleted_bookmarks = vec![];
+    let mut tx = workspace_command.start_transaction();
+    let mut rebased_change_ids = HashSet::new();
+    for (index, paths) in assigned_paths.iter().enumerate() {
+        if paths.is_empty() {
+            continue;
+        }
+        let source_commits = select_diff(
+            &tx,
+            slice::from_ref(&source),
+            &destinations[index],
+            &FilesMatcher::new(paths),
+            &DiffSelector::NonInteractive,
+            args.binary,
+        )?;
+        let Some(squashed) = rewrite::squash_commits(
+            tx.repo_mut(),
+            &source_commits,
+            &destinations[index],
+            args.keep_emptied,
+        )?
+        else {
+            continue;
+        };
+        let SquashedCommit {
+            commit_builder,
+            abandoned_commits,
+        } = squashed;
+        let source_abandoned = !abandoned_commits.is_empty();
+        let mut commit_builder = commit_builder.detach();
+        if args.keep_author == KeepAuthor::Source {
+            commit_builder.set_author(source.author().clone());
+        }
+        describe_squashed_commit(
+            ui,
+            &tx,
+            args,
+            &squashed_description,
+            &abandoned_commits,
+            &destinations[index],
+            false,
+            &mut commit_builder,
+        )?;
+        commit_builder.write(tx.repo_mut())?;
+
+        // Follow the rewritten source and destinations, including the rebases
+        // of their descendants, so the next destination sees the new commits.
+        let new_ids = if source_abandoned {
+            vec![]
+        } else {
+            once(&source)
+                .chain(&destinations)
+                .map(|commit| {
+                    let new_ids = tx.repo().new_parents(slice::from_ref(commit.id()));
+                    new_ids.into_iter().exactly_one().map_err(|_| {
+                        internal_error(format!(
+                            "Cannot find the rewritten commit of {}",
+                            short_commit_hash(commit.id())
+                        ))
+                    })
+                })
+                .try_collect()?
+        };
+        let mut rebased = HashMap::new();
+        tx.repo_mut().rebase_descendants_with_options(
+            &RebaseOptions::default(),
+            |old_commit, rebased_commit| {
+                rebased_change_ids.insert(old_commit.change_id().clone());
+                if let RebasedCommit::Rewritten(new_commit) = rebased_commit {
+                    rebased.insert(old_commit.id().clone(), new_commit);
+                }
+            },
+        )?;
+        if source_abandoned {
+            if args.delete_emptied_bookmarks {
+                deleted_bookmarks = emptied_bookmarks(tx.base_repo().view(), [&original_source_id]);
+            }
+            break;
+        }
+        let mut new_commits: Vec<Commit> = new_ids
+            .iter()
+            .map(|id| match rebased.remove(id) {
+                Some(commit) => Ok(commit),
+                None => tx.repo().store().get_commit(id),
+            })
+            .try_collect()?;
+        destinations = new_commits.split_off(1);
+        source = new_commits.pop().unwrap();
+    }
+
+    for name in &deleted_bookmarks {
+        tx.repo_mut()
+            .set_local_bookmark_target(name, RefTarget::absent());
+    }
+    for commit in once(&source).chain(&destinations) {
+        rebased_change_ids.remove(commit.change_id());
+    }
+    if let Some(mut formatter) = ui.status_formatter() {
+        if !deleted_bookmarks.is_empty() {
+            writeln!(
+                formatter,
+                "Deleted bookmarks: {}",
+                deleted_bookmarks.iter().map(|n| n.as_symbol()).join(", ")
+            )?;
+        }
+        if !rebased_change_ids.is_empty() {
+            writeln!(
+                formatter,
+                "Rebased {} descendant commits",
+                rebased_change_ids.len()
+            )?;
+        }
+    }
+    tx.finish(ui, tx_description)?;
+    Ok(())
+}
+
+/// Returns the paths which differ between `from` and `to`.
+fn changed_paths(from: &MergedTree, to: &MergedTree, matcher: &dyn Matcher) -> Vec<RepoPathBuf> {
+    from.diff_stream(to, matcher)
+        .map(|TreeDiffEntry { path, .. }| path)
+        .collect()
+        .block_on()
+}
+
+/// How binary files in the source diff are selected.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum BinaryFilesMode {
+    /// Move all changes to binary files
+    #[default]
+    Move,
+    /// Leave changes to binary files in the source
+    Skip,
+    /// Select changes to binary files like any other file
+    Prompt,
+}
+
+/// Working-copy changes snapshotted by an operation.
+struct SnapshotChanges {
+    /// Current version of the revision the changes were snapshotted into.
+    commit: Commit,
+    /// Working-copy tree before the snapshot.
+    old_tree: MergedTree,
+    /// Working-copy tree after the snapshot.
+    new_tree: MergedTree,
+}
+
+/// Resolves the working-copy changes snapshotted by the given operation.
+fn resolve_snapshot_changes(
+    workspace_command: &WorkspaceCommandHelper,
+    op_str: &str,
+) -> Result<SnapshotChanges, CommandError> {
+    let op = workspace_command.resolve_single_op(op_str)?;
+    let parent_ops: Vec<_> = op.parents().try_collect()?;
+    let [parent_op] = parent_ops.as_slice() else {
+        return Err(user_error(format!(
+            "Operation {} doesn't have exactly one parent",
+            short_operation_hash(op.id())
+        )));
+    };
+    let repo = workspace_command.repo();
+    let workspace_name = workspace_command.workspace_name();
+    let old_wc_commit_id = parent_op.view()?.get_wc_commit_id(workspace_name).cloned();
+    let new_wc_commit_id = op.view()?.get_wc_commit_id(workspace_name).cloned();
+    let (Some(old_wc_commit_id), Some(new_wc_commit_id)) = (old_wc_commit_id, new_wc_commit_id)
+    else {
+        return Err(user_error(format!(
+            "Operation {} didn't snapshot the working copy of this workspace",
+            short_operation_hash(op.id())
+        )));
+    };
+    let old_wc_commit = repo.store().get_commit(&old_wc_commit_id)?;
+    let new_wc_commit = repo.store().get_commit(&new_wc_commit_id)?;
+    if old_wc_commit.change_id() != new_wc_commit.change_id()
+        || old_wc_commit.tree_ids() == new_wc_commit.tree_ids()
+    {
+        return Err(user_error(format!(
+            "Operation {} didn't snapshot the working copy of this workspace",
+            short_operation_hash(op.id())
+        )));
+    }
+    let commit_ids = repo
+        .resolve_change_id(new_wc_commit.change_id())?
+        .unwrap_or_default();
+    let [commit_id] = commit_ids.as_slice() else {
+        return Err(user_error(format!(
+            "The revision snapshotted by operation {} is hidden or divergent",
+            short_operation_hash(op.id())
+        )));
+    };
+    Ok(SnapshotChanges {
+        commit: repo.store().get_commit(commit_id)?,
+        old_tree: old_wc_commit.tree(),
+        new_tree: new_wc_commit.tree(),
+    })
+}
+
+/// Which revision's author the squashed revision keeps.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum KeepAuthor {
+    /// Keep the author of the destination revision
+    #[default]
+    Destination,
+    /// Take the author of the source revision
+    Source,
+}
+
 enum SquashedDescription {
     // Use this exact description.
     Exact(String),
@@ -451,17 +1259,22 @@ enum SquashedDescription {
 }
 
 impl SquashedDescription {
-    fn from_args(args: &SquashArgs) -> Self {
+    fn from_args(command: &CommandHelper, args: &SquashArgs) -> Result<Self, CommandError> {
         // These options are incompatible and Clap is configured to prevent this.
         assert!(args.message_paragraphs.is_empty() || !args.use_destination_message);
 
         if !args.message_paragraphs.is_empty() {
             let desc = join_message_paragraphs(&args.message_paragraphs);
-            Self::Exact(desc)
+            Ok(Self::Exact(desc))
+        } else if let Some(file_path) = &args.message_from_file {
+            let content = fs::read_to_string(command.cwd().join(file_path)).map_err(|err| {
+                user_error_with_message(format!("Failed to read {file_path}"), err)
+            })?;
+            Ok(Self::Exact(complete_newline(content)))
         } else if args.use_destination_message {
-            Self::UseDestination
+            Ok(Self::UseDestination)
         } else {
-            Self::Combine
+            Ok(Self::Combine)
         }
     }
 }
@@ -472,6 +1285,7 @@ fn select_diff(
     destination: &Commit,
     matcher: &dyn Matcher,
     diff_selector: &DiffSelector,
+    binary: BinaryFilesMode,
 ) -> Result<Vec<CommitWithSelection>, CommandError> {
     let mut source_commits = vec![];
     for source in sources {
@@ -494,11 +1308,23 @@ fn select_diff(
                 destination = tx.format_commit_summary(destination),
             }
         };
-        let selected_tree = diff_selector.select(
-            Diff::new(&parent_tree, &source_tree),
-            matcher,
-            format_instructions,
-        )?;
+        let trees = Diff::new(&parent_tree, &source_tree);
+        let selected_tree = if binary == BinaryFilesMode::Prompt
+            || (binary == BinaryFilesMode::Move && !diff_selector.is_interactive())
+        {
+            diff_selector.select(trees, matcher, format_instructions)?
+        } else {
+            let binary_paths = changed_binary_paths(tx.repo().store(), trees, matcher)?;
+            let binary_matcher = FilesMatcher::new(&binary_paths);
+            let text_matcher = DifferenceMatcher::new(matcher, &binary_matcher);
+            let selected_tree = diff_selector.select(trees, &text_matcher, format_instructions)?;
+            match binary {
+                BinaryFilesMode::Move => {
+                    restore_tree(&source_tree, &selected_tree, &binary_matcher).block_on()?
+                }
+                BinaryFilesMode::Skip | BinaryFilesMode::Prompt => selected_tree,
+            }
+        };
         source_commits.push(CommitWithSelection {
             commit: source.clone(),
             selected_tree,
@@ -507,3 +1333,33 @@ fn select_diff(
     }
     Ok(source_commits)
 }
+
+/// Returns the matching paths which are binary files on either side of the
+/// diff.
+fn changed_binary_paths(
+    store: &Store,
+    trees: Diff<&MergedTree>,
+    matcher: &dyn Matcher,
+) -> Result<Vec<RepoPathBuf>, CommandError> {
+    let entries: Vec<_> = trees
+        .before
+        .diff_stream(trees.after, matcher)
+        .collect()
+        .block_on();
+    let mut binary_paths = vec![];
+    for TreeDiffEntry { path, values } in entries {
+        let values = values?;
+        for value in [values.before, values.after] {
+            let MaterializedTreeValue::File(mut file) =
+                materialize_tree_value(store, &path, value).block_on()?
+            else {
+                continue;
+            };
+            if file_content_for_diff(&path, &mut file, |contents| contents)?.is_binary {
+                binary_paths.push(path);
+                break;
+            }
+        }
+    }
+    Ok(binary_paths)
+}
@@ -34,6 +34,7 @@ use jj_lib::backend::ChangeId;
 use jj_lib::backend::CommitId;
 use jj_lib::backend::TreeValue;
 use jj_lib::commit::Commit;
+use jj_lib::config::ConfigGetResultExt as _;
 use jj_lib::conflicts;
 use jj_lib::conflicts::ConflictMarkerStyle;
 use jj_lib::copies::CopiesTreeDiffEntry;
@@ -137,6 +138,7 @@ pub struct CommitTemplateLanguage<'repo> {
     id_prefix_context: &'repo IdPrefixContext,
     immutable_expression: Arc<UserRevsetExpression>,
     conflict_marker_style: ConflictMarkerStyle,
+    mark_working_copy: bool,
     build_fn_table: CommitTemplateBuildFnTable<'repo>,
     keyword_cache: CommitKeywordCache<'repo>,
     cache_extensions: ExtensionsMap,
@@ -174,11 +176,17 @@ impl<'repo> CommitTemplateLanguage<'repo> {
             id_prefix_context,
             immutable_expression,
             conflict_marker_style,
+            mark_working_copy: false,
             build_fn_table,
             keyword_cache: CommitKeywordCache::default(),
             cache_extensions,
         }
     }
+
+    /// Enables the `CommitRef.working_copy_marker()` keyword.
+    pub fn set_mark_working_copy(&mut self, mark_working_copy: bool) {
+        self.mark_working_copy = mark_working_copy;
+    }
 }
 
 impl<'repo> TemplateLanguage<'repo> for CommitTemplateLanguage<'repo> {
@@ -1769,6 +1777,98 @@ fn builtin_commit_ref_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo,
             Ok(out_property.into_dyn_wrapped())
         },
     );
+    map.insert(
+        "annotation",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let settings = language.settings().clone();
+            let out_property = self_property.and_then(move |commit_ref| {
+                if commit_ref.remote.is_some() {
+                    return Ok(String::new());
+                }
+                let description = settings
+                    .get_string(["bookmark-descriptions", commit_ref.name.as_ref()])
+                    .optional()?;
+                Ok(description.unwrap_or_default())
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "at_working_copy",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let view = language.repo.view();
+            let wc_id = view.get_wc_commit_id(&language.workspace_name).cloned();
+            let out_property = self_property.map(move |commit_ref| {
+                wc_id
+                    .as_ref()
+                    .is_some_and(|wc_id| commit_ref.target.added_ids().contains(wc_id))
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "at_working_copy_parent",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let repo = language.repo;
+            let wc_id = repo
+                .view()
+                .get_wc_commit_id(&language.workspace_name)
+                .cloned();
+            // Looked up lazily, but only once for all refs.
+            let parent_ids = OnceCell::new();
+            let out_property = self_property.and_then(move |commit_ref| {
+                let Some(wc_id) = &wc_id else {
+                    return Ok(false);
+                };
+                let parent_ids = parent_ids.get_or_try_init(|| -> BackendResult<_> {
+                    Ok(repo.store().get_commit(wc_id)?.parent_ids().to_vec())
+                })?;
+                Ok(commit_ref
+                    .target
+                    .added_ids()
+                    .any(|id| parent_ids.contains(id)))
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
+    map.insert(
+        "working_copy_marker",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let repo = language.repo;
+            let wc_id = language
+                .mark_working_copy
+                .then(|| repo.view().get_wc_commit_id(&language.workspace_name))
+                .flatten()
+                .cloned();
+            // Looked up lazily, but only once for all refs.
+            let parent_ids = OnceCell::new();
+            let out_property = self_property.and_then(move |commit_ref| {
+                let Some(wc_id) = &wc_id else {
+                    return Ok(String::new());
+                };
+                if commit_ref.target.added_ids().contains(wc_id) {
+                    return Ok("@".to_owned());
+                }
+                let parent_ids = parent_ids.get_or_try_init(|| -> BackendResult<_> {
+                    Ok(repo.store().get_commit(wc_id)?.parent_ids().to_vec())
+                })?;
+                if commit_ref
+                    .target
+                    .added_ids()
+                    .any(|id| parent_ids.contains(id))
+                {
+                    Ok("@-".to_owned())
+                } else {
+                    Ok(String::new())
+                }
+            });
+            Ok(out_property.into_dyn_wrapped())
+        },
+    );
     map
 }
 
@@ -40,6 +40,7 @@ use jj_lib::conflicts::ConflictMaterializeOptions;
 use jj_lib::conflicts::MaterializedTreeDiffEntry;
 use jj_lib::conflicts::MaterializedTreeValue;
 use jj_lib::conflicts::materialize_merge_result_to_bytes;
+use jj_lib::conflicts::materialize_tree_value;
 use jj_lib::conflicts::materialized_diff_stream;
 use jj_lib::copies::CopiesTreeDiffEntry;
 use jj_lib::copies::CopiesTreeDiffEntryPath;
@@ -602,6 +603,11 @@ impl<'a> DiffRenderer<'a> {
     /// Generates diff between `from_commits` and `to_commit` based off their
     /// parents. The `from_commits` will temporarily be rebased onto the
     /// `to_commit` parents to exclude unrelated changes.
+    ///
+    /// If `rename_threshold` is set, files renamed or copied from the
+    /// `from_commits` are detected if their contents are at least that
+    /// percentage similar.
+    #[expect(clippy::too_many_arguments)]
     pub async fn show_inter_diff(
         &self,
         ui: &Ui,
@@ -609,6 +615,7 @@ impl<'a> DiffRenderer<'a> {
         from_commits: &[Commit],
         to_commit: &Commit,
         matcher: &dyn Matcher,
+        rename_threshold: Option<u8>,
         width: usize,
     ) -> Result<(), DiffRenderError> {
         let mut formatter = formatter.labeled("diff");
@@ -626,7 +633,22 @@ impl<'a> DiffRenderer<'a> {
         let to_description = Merge::resolved(to_commit.description());
         let from_tree = rebase_to_dest_parent(self.repo, from_commits, to_commit)?;
         let to_tree = to_commit.tree();
-        let copy_records = CopyRecords::default(); // TODO
+        let mut copy_records = CopyRecords::default();
+        // Renames can only be detected if the source tree wasn't rebased.
+        if let Some(threshold) = rename_threshold
+            && let [from_commit] = from_commits
+            && from_commit.parent_ids() == to_commit.parent_ids()
+        {
+            let store = self.repo.store();
+            let mut similar_records = vec![];
+            for record in get_copy_records(store, from_commit.id(), to_commit.id(), matcher)? {
+                let record = record?;
+                if copy_similarity(store, &from_tree, &to_tree, &record).await? >= threshold {
+                    similar_records.push(Ok(record));
+                }
+            }
+            copy_records.add_records(similar_records)?;
+        }
         self.show_diff_commit_descriptions(
             *formatter,
             Diff::new(&from_description, &to_description),
@@ -682,6 +704,39 @@ pub fn get_copy_records<'a>(
     Ok(block_on_stream(stream).filter_ok(|record| matcher.matches(&record.target)))
 }
 
+/// Returns how similar the contents of the copy source in `from_tree` and the
+/// copy target in `to_tree` are, in percent.
+async fn copy_similarity(
+    store: &Store,
+    from_tree: &MergedTree,
+    to_tree: &MergedTree,
+    record: &CopyRecord,
+) -> BackendResult<u8> {
+    let read_file = async |tree: &MergedTree, path: &RepoPath| {
+        let value = tree.path_value_async(path).await?;
+        match materialize_tree_value(store, path, value).await? {
+            MaterializedTreeValue::File(mut file) => file.read_all(path).await.map(Some),
+            _ => Ok(None),
+        }
+    };
+    let (Some(source), Some(target)) = (
+        read_file(from_tree, &record.source).await?,
+        read_file(to_tree, &record.target).await?,
+    ) else {
+        return Ok(0);
+    };
+    let total_len = source.len() + target.len();
+    if total_len == 0 {
+        return Ok(100);
+    }
+    let matching_len: usize = ContentDiff::by_line([&source, &target])
+        .hunks()
+        .filter(|hunk| hunk.kind == DiffHunkKind::Matching)
+        .map(|hunk| hunk.contents[0].len())
+        .sum();
+    Ok((matching_len * 2 * 100 / total_len) as u8)
+}
+
 /// How conflicts are processed and rendered in diffs.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -285,6 +285,7 @@ pub fn print_git_import_stats(
     repo: &dyn Repo,
     stats: &GitImportStats,
     show_ref_stats: bool,
+    dry_run: bool,
 ) -> Result<(), CommandError> {
     let Some(mut formatter) = ui.status_formatter() else {
         return Ok(());
@@ -310,9 +311,10 @@ pub fn print_git_import_stats(
     }
 
     if !stats.abandoned_commits.is_empty() {
+        let verb = if dry_run { "Would abandon" } else { "Abandoned" };
         writeln!(
             formatter,
-            "Abandoned {} commits that are no longer reachable.",
+            "{verb} {} commits that are no longer reachable.",
             stats.abandoned_commits.len()
         )?;
     }
@@ -140,6 +140,45 @@ fn test_bookmark_at_root() {
     ");
 }
 
+#[test]
+fn test_bookmark_set_at_root() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    let output = work_dir.run_jj(["bookmark", "set", "fred", "-r=root()"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Created 1 bookmarks pointing to zzzzzzzz 00000000 fred | (empty) (no description set)
+    Bookmark fred now points to the root commit
+    [EOF]
+    ");
+
+    // Moving the bookmark forward from the root doesn't need --allow-backwards
+    work_dir
+        .run_jj(["describe", "-m", "placeholder moved"])
+        .success();
+    work_dir.run_jj(["bookmark", "set", "fred"]).success();
+    let output = work_dir.run_jj([
+        "bookmark",
+        "list",
+        "-T",
+        r#"name ++ " " ++ normal_target.description()"#,
+    ]);
+    insta::assert_snapshot!(output, @r"
+    fred placeholder moved
+    [EOF]
+    ");
+
+    let output = work_dir.run_jj(["bookmark", "set", "bar", "baz", "-r=root()"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Created 2 bookmarks pointing to zzzzzzzz 00000000 bar baz | (empty) (no description set)
+    Bookmarks bar, baz now point to the root commit
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_bookmark_bad_name() {
     let test_env = TestEnvironment::default();
@@ -292,7 +331,7 @@ fn test_bookmark_move() {
     let output = work_dir.run_jj(["bookmark", "set", "-r@-", "foo"]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
-    Error: Refusing to move bookmark backwards or sideways: foo
+    Error: Refusing to move bookmark backwards or sideways: foo (from 8afc18ff677d to e8849ae12c70)
     Hint: Use --allow-backwards to allow it.
     [EOF]
     [exit status: 1]
@@ -317,7 +356,7 @@ fn test_bookmark_move() {
     let output = work_dir.run_jj(["bookmark", "move", "--to=@-", "foo"]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
-    Error: Refusing to move bookmark backwards or sideways: foo
+    Error: Refusing to move bookmark backwards or sideways: foo (from 8afc18ff677d to e8849ae12c70)
     Hint: Use --allow-backwards to allow it.
     [EOF]
     [exit status: 1]
@@ -476,7 +515,7 @@ fn test_bookmark_move_matching() {
     let output = work_dir.run_jj(["bookmark", "move", "glob:?1"]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
-    Error: Refusing to move bookmark backwards or sideways: a1
+    Error: Refusing to move bookmark backwards or sideways: a1 (from e8849ae12c70 to 0dd9a4b12283)
     Hint: Use --allow-backwards to allow it.
     [EOF]
     [exit status: 1]
@@ -509,6 +548,30 @@ fn test_bookmark_move_matching() {
     ◆   000000000000
     [EOF]
     ");
+    work_dir.run_jj(["op", "restore", &setup_opid]).success();
+
+    // Move bookmarks from distinct revisions, one of which is sideways
+    let output = work_dir.run_jj(["bookmark", "move", "--from=a1 | b1", "--to=c1"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Refusing to move bookmark backwards or sideways: a1 (from e8849ae12c70 to 2cbf65662e56)
+    Hint: Use --allow-backwards to allow it.
+    [EOF]
+    [exit status: 1]
+    ");
+    work_dir
+        .run_jj(["bookmark", "move", "--from=a1 | b1", "--to=c1", "-B"])
+        .success();
+    insta::assert_snapshot!(get_log_output(&work_dir), @r"
+    @   0dd9a4b12283
+    ○  a1 a2 b1 c1 2cbf65662e56
+    ○   c2934cfbfb19
+    │ ○   9328ecc52471
+    │ ○   e8849ae12c70
+    ├─╯
+    ◆   000000000000
+    [EOF]
+    ");
 }
 
 #[test]
@@ -559,14 +622,44 @@ fn test_bookmark_move_conflicting() {
     let output = work_dir.run_jj(["bookmark", "set", "-rdescription(C0)", "foo"]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
-    Error: Refusing to move bookmark backwards or sideways: foo
+    Error: Refusing to move bookmark backwards or sideways: foo (from f7addb25e91d, 61b17b816638 to c7420bf715d6)
+    Hint: Use --allow-backwards to allow it.
+    [EOF]
+    [exit status: 1]
+    ");
+
+    // Same for "bookmark move"
+    let output = work_dir.run_jj(["bookmark", "move", "--to=description(C0)", "foo"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Refusing to move bookmark backwards or sideways: foo (from f7addb25e91d, 61b17b816638 to c7420bf715d6)
     Hint: Use --allow-backwards to allow it.
     [EOF]
     [exit status: 1]
     ");
 
+    // Multiple destinations aren't allowed
+    let output = work_dir.run_jj(["bookmark", "move", "--to=description(C0)|@", "foo"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Revset `description(C0)|@` resolved to more than one revision
+    Hint: The revset `description(C0)|@` resolved to these revisions:
+      mzvwutvl 0f5f3e2c (empty) A1
+      zsuskuln c7420bf7 (empty) C0
+    [EOF]
+    [exit status: 1]
+    ");
+
     // Can move the bookmark to A1 since it's descendant of A0. It's not
     // descendant of B0, though.
+    let setup_opid = work_dir.current_operation_id();
+    let output = work_dir.run_jj(["bookmark", "move", "--to=description(A1)", "foo"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Moved 1 bookmarks to mzvwutvl 0f5f3e2c foo | (empty) A1
+    [EOF]
+    ");
+    work_dir.run_jj(["op", "restore", &setup_opid]).success();
     let output = work_dir.run_jj(["bookmark", "set", "-rdescription(A1)", "foo"]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
@@ -670,6 +763,65 @@ fn test_bookmark_rename() {
     ");
 }
 
+#[test]
+fn test_bookmark_rename_retrack() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    // Set up remotes
+    for remote in ["origin", "upstream"] {
+        let git_repo_path = test_env.env_root().join(format!("{remote}-repo"));
+        git::init_bare(git_repo_path);
+        work_dir
+            .run_jj(["git", "remote", "add", remote, &format!("../{remote}-repo")])
+            .success();
+    }
+
+    // "old" is tracked on both remotes, "new" exists only on origin
+    work_dir.run_jj(["describe", "-m=commit"]).success();
+    work_dir.run_jj(["bookmark", "create", "old"]).success();
+    for remote in ["origin", "upstream"] {
+        work_dir
+            .run_jj([
+                "git",
+                "push",
+                "--allow-new",
+                "-b=old",
+                &format!("--remote={remote}"),
+            ])
+            .success();
+    }
+    work_dir
+        .run_jj(["git", "push", "--named", "new=@", "--remote=origin"])
+        .success();
+    work_dir.run_jj(["bookmark", "forget", "new"]).success();
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    new@origin: qpvuntsm f5c9b630 (empty) commit
+    old: qpvuntsm f5c9b630 (empty) commit
+      @origin: qpvuntsm f5c9b630 (empty) commit
+      @upstream: qpvuntsm f5c9b630 (empty) commit
+    [EOF]
+    ");
+
+    let output = work_dir.run_jj(["bookmark", "rename", "--retrack", "old", "new"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Warning: The renamed bookmark doesn't exist on the remote 'upstream', tracking state was dropped.
+    Warning: Tracked remote bookmarks for bookmark old were not renamed.
+    Hint: To rename the bookmark on the remote, you can `jj git push --bookmark old` first (to delete it on the remote), and then `jj git push --bookmark new`. `jj git push --all --deleted` would also be sufficient.
+    [EOF]
+    ");
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    new: qpvuntsm f5c9b630 (empty) commit
+      @origin: qpvuntsm f5c9b630 (empty) commit
+    old (deleted)
+      @origin: qpvuntsm f5c9b630 (empty) commit
+      @upstream: qpvuntsm f5c9b630 (empty) commit
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_bookmark_rename_colocated() {
     let test_env = TestEnvironment::default();
@@ -857,7 +1009,7 @@ fn test_bookmark_delete_glob() {
     let output = work_dir.run_jj(["bookmark", "delete", "glob:foo-[1-3"]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
-    error: invalid value 'glob:foo-[1-3' for '<NAMES>...': error parsing glob 'foo-[1-3': unclosed character class; missing ']'
+    error: invalid value 'glob:foo-[1-3' for '[NAMES]...': error parsing glob 'foo-[1-3': unclosed character class; missing ']'
 
     For more information, try '--help'.
     [EOF]
@@ -877,6 +1029,44 @@ fn test_bookmark_delete_glob() {
     ");
 }
 
+#[test]
+fn test_bookmark_delete_by_revisions() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    let list_names = || work_dir.run_jj(["bookmark", "list", "-T", r#"name ++ "\n""#]);
+
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "foo", "bar"])
+        .success();
+    work_dir.run_jj(["new"]).success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "baz"])
+        .success();
+
+    // Deleting nothing is not an error
+    let output = work_dir.run_jj(["bookmark", "delete", "-rroot()"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Warning: No bookmarks point to the specified revisions.
+    Nothing changed.
+    [EOF]
+    ");
+
+    let output = work_dir.run_jj(["bookmark", "delete", "-r@-"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Deleting bookmark bar
+    Deleting bookmark foo
+    Deleted 2 bookmarks.
+    [EOF]
+    ");
+    insta::assert_snapshot!(list_names(), @r"
+    baz
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_bookmark_delete_export() {
     let test_env = TestEnvironment::default();
@@ -921,6 +1111,8 @@ fn test_bookmark_forget_export() {
     let output = work_dir.run_jj(["bookmark", "forget", "--include-remotes", "foo"]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
+    Local bookmarks to forget: foo
+    Remote bookmarks to forget on git: foo
     Forgot 1 local bookmarks.
     Forgot 1 remote bookmarks.
     [EOF]
@@ -1052,6 +1244,8 @@ fn test_bookmark_forget_fetched_bookmark() {
     let output = work_dir.run_jj(["bookmark", "forget", "--include-remotes", "feature1"]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
+    Local bookmarks to forget: feature1
+    Remote bookmarks to forget on origin: feature1
     Forgot 1 local bookmarks.
     Forgot 1 remote bookmarks.
     [EOF]
@@ -1091,6 +1285,76 @@ fn test_bookmark_forget_fetched_bookmark() {
     ");
 }
 
+#[test]
+fn test_bookmark_forget_include_remotes_confirmation() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = true");
+    test_env.add_config("ui.bookmark-forget-confirm-threshold = 3");
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    for (remote, names) in [
+        ("origin", ["feature-a", "feature-b"]),
+        ("upstream", ["feature-a", "feature-c"]),
+    ] {
+        let git_repo = git::init_bare(test_env.env_root().join(remote));
+        for name in names {
+            git::add_commit(
+                &git_repo,
+                &format!("refs/heads/{name}"),
+                "file",
+                b"content",
+                "message",
+                &[],
+            );
+        }
+        work_dir
+            .run_jj(["git", "remote", "add", remote, &format!("../{remote}")])
+            .success();
+    }
+    work_dir.run_jj(["git", "fetch", "--all-remotes"]).success();
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    feature-a: qomsplrm ebeb70d8 message
+      @origin: qomsplrm ebeb70d8 message
+      @upstream: qomsplrm ebeb70d8 message
+    feature-b: qomsplrm ebeb70d8 message
+      @origin: qomsplrm ebeb70d8 message
+    feature-c: qomsplrm ebeb70d8 message
+      @upstream: qomsplrm ebeb70d8 message
+    [EOF]
+    ");
+
+    // Confirmation is required if many bookmarks would be forgotten
+    let output = work_dir.run_jj(["bookmark", "forget", "--include-remotes", "glob:feature-*"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Local bookmarks to forget: feature-a, feature-b, feature-c
+    Remote bookmarks to forget on origin: feature-a, feature-b
+    Remote bookmarks to forget on upstream: feature-a, feature-c
+    Error: Refusing to forget 7 local and remote bookmarks
+    Hint: Use `--yes` to forget them without confirmation.
+    [EOF]
+    [exit status: 1]
+    ");
+
+    let output = work_dir.run_jj([
+        "bookmark",
+        "forget",
+        "--include-remotes",
+        "--yes",
+        "glob:feature-*",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Local bookmarks to forget: feature-a, feature-b, feature-c
+    Remote bookmarks to forget on origin: feature-a, feature-b
+    Remote bookmarks to forget on upstream: feature-a, feature-c
+    Forgot 3 local bookmarks.
+    Forgot 4 remote bookmarks.
+    [EOF]
+    ");
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @"");
+}
+
 #[test]
 fn test_bookmark_forget_deleted_or_nonexistent_bookmark() {
     // Much of this test is borrowed from `test_git_fetch_remote_only_bookmark` in
@@ -1334,6 +1598,66 @@ fn test_bookmark_track_untrack() {
     ");
 }
 
+#[test]
+fn test_bookmark_create_at_remote() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = false");
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    // Set up remote
+    let git_repo_path = test_env.env_root().join("git-repo");
+    let git_repo = git::init(git_repo_path);
+    work_dir
+        .run_jj(["git", "remote", "add", "origin", "../git-repo"])
+        .success();
+    create_commit_with_refs(
+        &git_repo,
+        "commit 1",
+        b"content 1",
+        &["refs/heads/feature1", "refs/heads/feature2"],
+    );
+    work_dir.run_jj(["git", "fetch"]).success();
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    feature1@origin: qxxqrkql bd843888 commit 1
+    feature2@origin: qxxqrkql bd843888 commit 1
+    [EOF]
+    ");
+
+    // Create a local bookmark from the untracked remote bookmark
+    let output = work_dir.run_jj(["bookmark", "create", "feature2", "--at-remote", "origin"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Created 1 bookmarks tracking remote origin
+    [EOF]
+    ");
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    feature1@origin: qxxqrkql bd843888 commit 1
+    feature2: qxxqrkql bd843888 commit 1
+      @origin: qxxqrkql bd843888 commit 1
+    [EOF]
+    ");
+
+    // Nonexistent remote bookmark
+    let output = work_dir.run_jj(["bookmark", "create", "feature3", "--at-remote", "origin"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: No bookmark feature3 found on remote origin
+    [EOF]
+    [exit status: 1]
+    ");
+
+    // Existing local bookmark
+    let output = work_dir.run_jj(["bookmark", "create", "feature2", "--at-remote", "origin"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Bookmark already exists: feature2
+    Hint: Use `jj bookmark set` to update it.
+    [EOF]
+    [exit status: 1]
+    ");
+}
+
 #[test]
 fn test_bookmark_track_conflict() {
     let test_env = TestEnvironment::default();
@@ -1369,51 +1693,330 @@ fn test_bookmark_track_conflict() {
 }
 
 #[test]
-fn test_bookmark_track_untrack_patterns() {
+fn test_bookmark_spread() {
     let test_env = TestEnvironment::default();
     test_env.run_jj_in(".", ["git", "init", "repo"]).success();
     let work_dir = test_env.work_dir("repo");
+    for (path, content, message) in [
+        ("frontend/a", "1", "front 1"),
+        ("backend/b", "1", "back 1"),
+        ("frontend/a", "2", "front 2"),
+    ] {
+        work_dir.write_file(path, content);
+        work_dir.run_jj(["commit", "-m", message]).success();
+    }
+    let summary_config = "--config=templates.commit_summary=description.first_line()";
 
-    // Set up remote
-    let git_repo_path = test_env.env_root().join("git-repo");
-    let git_repo = git::init(git_repo_path);
-    work_dir
-        .run_jj(["git", "remote", "add", "origin", "../git-repo"])
-        .success();
+    let output = work_dir.run_jj([
+        "bookmark",
+        "spread",
+        "topic",
+        "--by-path=frontend",
+        "--by-path=backend",
+        "--by-path=docs",
+        summary_config,
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Warning: No revisions modify docs, not creating bookmark topic-3
+    Created bookmark topic-1 pointing to front 2
+    Created bookmark topic-2 pointing to back 1
+    [EOF]
+    ");
+    let template = r#"separate(" ", description.first_line(), bookmarks) ++ "\n""#;
+    insta::assert_snapshot!(work_dir.run_jj(["log", "-T", template]), @r"
+    @
+    ○  front 2 topic-1
+    ○  back 1 topic-2
+    ○  front 1
+    ◆
+    [EOF]
+    ");
 
-    // Create remote commit
-    create_commit_with_refs(
-        &git_repo,
-        "commit",
-        b"content",
-        &["refs/heads/feature1", "refs/heads/feature2"],
-    );
+    // Only the given revisions are searched
+    let output = work_dir.run_jj([
+        "bookmark",
+        "spread",
+        "early",
+        "--by-path=frontend",
+        "-r=::@---",
+        summary_config,
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Created bookmark early-1 pointing to front 1
+    [EOF]
+    ");
 
-    // Fetch new commit without auto tracking
-    test_env.add_config("git.auto-local-bookmark = false");
-    let output = work_dir.run_jj(["git", "fetch"]);
+    // Existing bookmarks aren't overwritten
+    let output = work_dir.run_jj(["bookmark", "spread", "topic", "--by-path=backend"]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
-    bookmark: feature1@origin [new] untracked
-    bookmark: feature2@origin [new] untracked
+    Error: Bookmark already exists: topic-1
+    Hint: Use `jj bookmark set` to update it.
     [EOF]
+    [exit status: 1]
     ");
+}
 
-    // Track local bookmark
-    work_dir.run_jj(["bookmark", "create", "main"]).success();
-    insta::assert_snapshot!(work_dir.run_jj(["bookmark", "track", "main"]), @r"
+#[test]
+fn test_bookmark_track_all() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = false");
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    // Set up remotes
+    for (remote, refs) in [
+        (
+            "origin",
+            ["refs/heads/feature1", "refs/heads/feature2"].as_slice(),
+        ),
+        ("upstream", ["refs/heads/feature3"].as_slice()),
+    ] {
+        let git_repo_path = test_env.env_root().join(remote);
+        let git_repo = git::init(git_repo_path);
+        create_commit_with_refs(&git_repo, remote, b"content", refs);
+        work_dir
+            .run_jj(["git", "remote", "add", remote, &format!("../{remote}")])
+            .success();
+    }
+    work_dir.run_jj(["git", "fetch", "--all-remotes"]).success();
+    work_dir
+        .run_jj(["bookmark", "track", "feature1@origin"])
+        .success();
+    // Git-tracking bookmarks aren't affected
+    work_dir.run_jj(["git", "export"]).success();
+
+    // Names and --all are mutually exclusive
+    let output = work_dir.run_jj(["bookmark", "track", "--all", "feature2@origin"]);
+    insta::assert_snapshot!(output, @r"
     ------- stderr -------
-    error: invalid value 'main' for '<BOOKMARK@REMOTE>...': remote bookmark must be specified in bookmark@remote form
+    error: the argument '--all' cannot be used with '[BOOKMARK@REMOTE]...'
+
+    Usage: jj bookmark track <BOOKMARK@REMOTE|--all>
 
     For more information, try '--help'.
     [EOF]
     [exit status: 2]
     ");
 
-    // Track/untrack new bookmark that doesn't exist at remote
-    insta::assert_snapshot!(work_dir.run_jj(["bookmark", "track", "main@origin"]), @r"
+    let output = work_dir.run_jj(["bookmark", "track", "--all"]);
+    insta::assert_snapshot!(output, @r"
     ------- stderr -------
-    Started tracking 1 remote bookmarks.
+    Remote bookmark already tracked: feature1@origin
+    Tracking remote bookmark: feature2@origin
+    Tracking remote bookmark: feature3@upstream
+    Started tracking 2 remote bookmarks.
+    [EOF]
+    ");
+
+    let output = work_dir.run_jj(["bookmark", "track", "--all"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Remote bookmark already tracked: feature1@origin
+    Remote bookmark already tracked: feature2@origin
+    Remote bookmark already tracked: feature3@upstream
+    Nothing changed.
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_bookmark_untrack_all() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = false");
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    // Set up remotes
+    for (remote, refs) in [
+        (
+            "origin",
+            ["refs/heads/feature1", "refs/heads/feature2"].as_slice(),
+        ),
+        ("upstream", ["refs/heads/feature1"].as_slice()),
+    ] {
+        let git_repo_path = test_env.env_root().join(remote);
+        let git_repo = git::init(git_repo_path);
+        create_commit_with_refs(&git_repo, remote, b"content", refs);
+        work_dir
+            .run_jj(["git", "remote", "add", remote, &format!("../{remote}")])
+            .success();
+    }
+    work_dir.run_jj(["git", "fetch", "--all-remotes"]).success();
+    work_dir
+        .run_jj(["bookmark", "track", "feature1@origin", "feature2@origin"])
+        .success();
+    // Git-tracking bookmarks aren't affected
+    work_dir.run_jj(["git", "export"]).success();
+
+    let template =
+        r#"separate(" ", name ++ if(remote, "@" ++ remote), if(tracked, "(tracked)")) ++ "\n""#;
+    let list_bookmarks = || work_dir.run_jj(["bookmark", "list", "--all-remotes", "-T", template]);
+    insta::assert_snapshot!(list_bookmarks(), @r"
+    feature1
+    feature1@git (tracked)
+    feature1@origin (tracked)
+    feature1@upstream
+    feature2
+    feature2@git (tracked)
+    feature2@origin (tracked)
+    [EOF]
+    ");
+
+    // Names and --all are mutually exclusive
+    let output = work_dir.run_jj(["bookmark", "untrack", "--all", "feature2@origin"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    error: the argument '--all' cannot be used with '[BOOKMARK@REMOTE]...'
+
+    Usage: jj bookmark untrack <BOOKMARK@REMOTE|--all>
+
+    For more information, try '--help'.
+    [EOF]
+    [exit status: 2]
+    ");
+
+    let output = work_dir.run_jj(["bookmark", "untrack", "--all"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Untracking remote bookmark: feature1@origin
+    Untracking remote bookmark: feature2@origin
+    Stopped tracking 2 remote bookmarks.
+    [EOF]
+    ");
+
+    // The remote bookmarks are kept, but no longer associated with the local
+    // bookmarks
+    insta::assert_snapshot!(list_bookmarks(), @r"
+    feature1
+    feature1@git (tracked)
+    feature1@origin
+    feature1@upstream
+    feature2
+    feature2@git (tracked)
+    feature2@origin
+    [EOF]
+    ");
+
+    let output = work_dir.run_jj(["bookmark", "untrack", "--all"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Nothing changed.
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_bookmark_track_print_plan() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = false");
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    // Set up remotes
+    for (remote, refs) in [
+        (
+            "origin",
+            ["refs/heads/feature1", "refs/heads/feature2"].as_slice(),
+        ),
+        (
+            "upstream",
+            ["refs/heads/feature1", "refs/heads/feature3"].as_slice(),
+        ),
+    ] {
+        let git_repo_path = test_env.env_root().join(remote);
+        let git_repo = git::init(git_repo_path);
+        create_commit_with_refs(&git_repo, remote, b"content", refs);
+        work_dir
+            .run_jj(["git", "remote", "add", remote, &format!("../{remote}")])
+            .success();
+    }
+    work_dir.run_jj(["git", "fetch", "--all-remotes"]).success();
+    work_dir
+        .run_jj(["bookmark", "track", "feature1@origin"])
+        .success();
+    let op_log_before = work_dir.run_jj(["op", "log"]).success();
+
+    let output = work_dir.run_jj([
+        "bookmark",
+        "track",
+        "--print-plan",
+        "glob:feature*@origin",
+        "glob:feature*@upstream",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    Would associate local bookmark feature1 with feature1@upstream
+    Would create local bookmark feature2 tracking feature2@origin
+    Would create local bookmark feature3 tracking feature3@upstream
+    [EOF]
+    ------- stderr -------
+    Warning: Remote bookmark already tracked: feature1@origin
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["bookmark", "track", "--print-plan", "--all"]);
+    insta::assert_snapshot!(output, @r"
+    Would associate local bookmark feature1 with feature1@upstream
+    Would create local bookmark feature2 tracking feature2@origin
+    Would create local bookmark feature3 tracking feature3@upstream
+    [EOF]
+    ------- stderr -------
+    Remote bookmark already tracked: feature1@origin
+    [EOF]
+    ");
+
+    // No operation was recorded
+    let op_log_after = work_dir.run_jj(["op", "log"]).success();
+    assert_eq!(op_log_before.stdout.raw(), op_log_after.stdout.raw());
+}
+
+#[test]
+fn test_bookmark_track_untrack_patterns() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    // Set up remote
+    let git_repo_path = test_env.env_root().join("git-repo");
+    let git_repo = git::init(git_repo_path);
+    work_dir
+        .run_jj(["git", "remote", "add", "origin", "../git-repo"])
+        .success();
+
+    // Create remote commit
+    create_commit_with_refs(
+        &git_repo,
+        "commit",
+        b"content",
+        &["refs/heads/feature1", "refs/heads/feature2"],
+    );
+
+    // Fetch new commit without auto tracking
+    test_env.add_config("git.auto-local-bookmark = false");
+    let output = work_dir.run_jj(["git", "fetch"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: feature1@origin [new] untracked
+    bookmark: feature2@origin [new] untracked
+    [EOF]
+    ");
+
+    // Track local bookmark
+    work_dir.run_jj(["bookmark", "create", "main"]).success();
+    insta::assert_snapshot!(work_dir.run_jj(["bookmark", "track", "main"]), @r"
+    ------- stderr -------
+    error: invalid value 'main' for '[BOOKMARK@REMOTE]...': remote bookmark must be specified in bookmark@remote form
+
+    For more information, try '--help'.
+    [EOF]
+    [exit status: 2]
+    ");
+
+    // Track/untrack new bookmark that doesn't exist at remote
+    insta::assert_snapshot!(work_dir.run_jj(["bookmark", "track", "main@origin"]), @r"
+    ------- stderr -------
+    Started tracking 1 remote bookmarks.
     [EOF]
     ");
     insta::assert_snapshot!(work_dir.run_jj(["bookmark", "untrack", "main@origin"]), @r"
@@ -1524,6 +2127,125 @@ fn test_bookmark_track_untrack_patterns() {
     ");
 }
 
+#[test]
+fn test_bookmark_list_at_working_copy() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "grandparent"])
+        .success();
+    work_dir.run_jj(["new"]).success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "parent"])
+        .success();
+    work_dir.run_jj(["new"]).success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "wc"])
+        .success();
+
+    let template = r#"name ++ ": " ++ at_working_copy ++ " " ++ at_working_copy_parent ++ "\n""#;
+    let output = work_dir.run_jj(["bookmark", "list", "-T", template]);
+    insta::assert_snapshot!(output, @r"
+    grandparent: false false
+    parent: false true
+    wc: true false
+    [EOF]
+    ");
+
+    // The markers are omitted if the output isn't colored
+    let output = work_dir.run_jj(["bookmark", "list"]);
+    insta::assert_snapshot!(output, @r"
+    grandparent: qpvuntsm e8849ae1 (empty) (no description set)
+    parent: kkmpptxz 1c1c95df (empty) (no description set)
+    wc: mzvwutvl b4bed0ec (empty) (no description set)
+    [EOF]
+    ");
+
+    // ...unless requested
+    let output = work_dir.run_jj(["bookmark", "list", "--mark-working-copy"]);
+    insta::assert_snapshot!(output, @r"
+    grandparent: qpvuntsm e8849ae1 (empty) (no description set)
+    parent @-: kkmpptxz 1c1c95df (empty) (no description set)
+    wc @: mzvwutvl b4bed0ec (empty) (no description set)
+    [EOF]
+    ");
+
+    let output = work_dir.run_jj(["bookmark", "list", "--color=always"]);
+    insta::assert_snapshot!(output, @r"
+    [38;5;5mgrandparent[39m: [1m[38;5;5mq[0m[38;5;8mpvuntsm[39m [1m[38;5;4me[0m[38;5;8m8849ae1[39m [38;5;2m(empty)[39m [38;5;2m(no description set)[39m
+    [38;5;5mparent[39m [1m@-[0m: [1m[38;5;5mk[0m[38;5;8mkmpptxz[39m [1m[38;5;4m1[0m[38;5;8mc1c95df[39m [38;5;2m(empty)[39m [38;5;2m(no description set)[39m
+    [1m[38;5;13mwc[0m [1m@[0m: [1m[38;5;13mm[38;5;8mzvwutvl[39m [38;5;12mb[38;5;8m4bed0ec[39m [38;5;10m(empty)[39m [38;5;10m(no description set)[0m
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_bookmark_list_count() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = false");
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    // Set up remote
+    let git_repo_path = test_env.env_root().join("git-repo");
+    let git_repo = git::init(git_repo_path);
+    create_commit_with_refs(
+        &git_repo,
+        "commit 1",
+        b"content 1",
+        &["refs/heads/foo", "refs/heads/remote-only"],
+    );
+    work_dir
+        .run_jj(["git", "remote", "add", "origin", "../git-repo"])
+        .success();
+    work_dir.run_jj(["git", "fetch"]).success();
+    work_dir
+        .run_jj(["bookmark", "track", "foo@origin"])
+        .success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "bar"])
+        .success();
+
+    // Remote bookmarks in sync with the local bookmarks aren't listed by default
+    let output = work_dir.run_jj(["bookmark", "list", "--count"]);
+    insta::assert_snapshot!(output, @r"
+    2
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["bookmark", "list", "--count", "--all-remotes"]);
+    insta::assert_snapshot!(output, @r"
+    4
+    [EOF]
+    ");
+
+    // Patterns select bookmarks the same way as the normal listing
+    let output = work_dir.run_jj(["bookmark", "list", "--count", "glob:f*"]);
+    insta::assert_snapshot!(output, @r"
+    1
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["bookmark", "list", "--count", "glob:nothing*"]);
+    insta::assert_snapshot!(output, @r"
+    0
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["bookmark", "list", "--count", "missing"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: No such bookmark: missing
+    [EOF]
+    [exit status: 1]
+    ");
+
+    // The count can't be combined with other output formats
+    let output = work_dir.run_jj(["bookmark", "list", "--count", "-Tname"]);
+    assert_eq!(output.status.code(), Some(2));
+    let output = work_dir.run_jj(["bookmark", "list", "--count", "--format=json"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
 #[test]
 fn test_bookmark_list() {
     let test_env = TestEnvironment::default();
@@ -1620,14 +2342,14 @@ fn test_bookmark_list() {
 
     let output = local_dir.run_jj(["bookmark", "list", "--all-remotes", "--color=always"]);
     insta::assert_snapshot!(output, @r"
-    [38;5;5mabsent-tracked[39m: [1m[38;5;13mw[38;5;8mqnwkozp[39m [38;5;12m03[38;5;8m53dd35[39m [38;5;10m(empty)[39m local-only[0m
+    [1m[38;5;13mabsent-tracked[0m [1m@[0m: [1m[38;5;13mw[38;5;8mqnwkozp[39m [38;5;12m03[38;5;8m53dd35[39m [38;5;10m(empty)[39m local-only[0m
       [38;5;5m@origin[39m (not created yet)
-    [38;5;5mlocal-only[39m: [1m[38;5;13mw[38;5;8mqnwkozp[39m [38;5;12m03[38;5;8m53dd35[39m [38;5;10m(empty)[39m local-only[0m
+    [1m[38;5;13mlocal-only[0m [1m@[0m: [1m[38;5;13mw[38;5;8mqnwkozp[39m [38;5;12m03[38;5;8m53dd35[39m [38;5;10m(empty)[39m local-only[0m
     [38;5;5mremote-delete[39m (deleted)
       [38;5;5m@origin[39m: [1m[38;5;5mv[0m[38;5;8mruxwmqv[39m [1m[38;5;4mb[0m[38;5;8m32031cf[39m [38;5;2m(empty)[39m remote-delete
     [38;5;5mremote-sync[39m: [1m[38;5;5mr[0m[38;5;8mlvkpnrz[39m [1m[38;5;4m7[0m[38;5;8ma07dbee[39m [38;5;2m(empty)[39m remote-sync
       [38;5;5m@origin[39m: [1m[38;5;5mr[0m[38;5;8mlvkpnrz[39m [1m[38;5;4m7[0m[38;5;8ma07dbee[39m [38;5;2m(empty)[39m remote-sync
-    [38;5;5mremote-unsync[39m: [1m[38;5;13mw[38;5;8mqnwkozp[39m [38;5;12m03[38;5;8m53dd35[39m [38;5;10m(empty)[39m local-only[0m
+    [1m[38;5;13mremote-unsync[0m [1m@[0m: [1m[38;5;13mw[38;5;8mqnwkozp[39m [38;5;12m03[38;5;8m53dd35[39m [38;5;10m(empty)[39m local-only[0m
       [38;5;5m@origin[39m (ahead by 1 commits, behind by 1 commits): [1m[38;5;5mzs[0m[38;5;8muskuln[39m [1m[38;5;4m5[0m[38;5;8m53203ba[39m [38;5;2m(empty)[39m remote-unsync
     [38;5;5mremote-untrack@origin[39m: [1m[38;5;5mro[0m[38;5;8myxmykx[39m [1m[38;5;4m1[0m[38;5;8m49bc756[39m [38;5;2m(empty)[39m remote-untrack
     [EOF]
@@ -2085,6 +2807,166 @@ fn test_bookmark_list_much_remote_divergence() {
     ");
 }
 
+#[test]
+fn test_bookmark_list_json() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    git::init_bare(test_env.env_root().join("git-repo"));
+    work_dir
+        .run_jj(["git", "remote", "add", "origin", "../git-repo"])
+        .success();
+    let get_commit_id = |rev: &str| {
+        work_dir
+            .run_jj([
+                "log",
+                "--no-graph",
+                "--color=never",
+                "-T=commit_id",
+                "-r",
+                rev,
+            ])
+            .success()
+            .stdout
+            .into_raw()
+    };
+
+    work_dir.run_jj(["describe", "-m=commit1"]).success();
+    work_dir.run_jj(["bookmark", "create", "foo"]).success();
+    work_dir.run_jj(["git", "push", "--all"]).success();
+    work_dir.run_jj(["new", "-m=commit2"]).success();
+    work_dir
+        .run_jj(["bookmark", "move", "foo", "--to=@"])
+        .success();
+    work_dir.run_jj(["bookmark", "create", "bar"]).success();
+    let commit1 = get_commit_id("@-");
+    let commit2 = get_commit_id("@");
+
+    let output = work_dir.run_jj(["bookmark", "list", "--format=json"]);
+    insta::assert_snapshot!(
+        output.normalize_stdout_with(|s| s.replace(&commit1, "<commit1>").replace(&commit2, "<commit2>")), @r#"
+    {"name":"bar","present":true,"conflicted":false,"targets":["<commit2>"],"remotes":[]}
+    {"name":"foo","present":true,"conflicted":false,"targets":["<commit2>"],"remotes":[{"remote":"origin","target":["<commit1>"],"tracked":true,"ahead":0,"behind":1}]}
+    [EOF]
+    "#);
+}
+
+#[test]
+fn test_bookmark_list_template_file() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    work_dir.run_jj(["describe", "-m=commit1"]).success();
+    work_dir.run_jj(["bookmark", "create", "foo"]).success();
+    work_dir.run_jj(["new", "-m=commit2"]).success();
+    work_dir.run_jj(["bookmark", "create", "bar"]).success();
+
+    let template = r#"name ++ ": " ++ normal_target.description().first_line() ++ "\n""#;
+    std::fs::write(test_env.env_root().join("bookmarks.tmpl"), template).unwrap();
+    let inline_output = work_dir.run_jj(["bookmark", "list", "-T", template]);
+    let file_output = work_dir.run_jj(["bookmark", "list", "--template-file=../bookmarks.tmpl"]);
+    insta::assert_snapshot!(file_output, @r"
+    bar: commit2
+    foo: commit1
+    [EOF]
+    ");
+    assert_eq!(file_output.stdout.raw(), inline_output.stdout.raw());
+
+    // Cannot be combined with an inline template or JSON output
+    let output = work_dir.run_jj([
+        "bookmark",
+        "list",
+        "-T=name",
+        "--template-file=../bookmarks.tmpl",
+    ]);
+    assert!(output.stderr.raw().contains("cannot be used with"));
+    let output = work_dir.run_jj([
+        "bookmark",
+        "list",
+        "--format=json",
+        "--template-file=../bookmarks.tmpl",
+    ]);
+    assert!(output.stderr.raw().contains("cannot be used with"));
+
+    // Parse errors mention the template file
+    std::fs::write(
+        test_env.env_root().join("bad.tmpl"),
+        "name ++ no_such_keyword_here",
+    )
+    .unwrap();
+    let output = work_dir.run_jj(["bookmark", "list", "--template-file=../bad.tmpl"]);
+    assert!(!output.status.success());
+    assert!(
+        output
+            .stderr
+            .raw()
+            .contains("Hint: The template was read from ../bad.tmpl")
+    );
+}
+
+#[test]
+fn test_bookmark_set_description() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    let output = work_dir.run_jj(["bookmark", "set", "foo", "--description=Feature work"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Warning: Target revision is empty.
+    Created 1 bookmarks pointing to qpvuntsm e8849ae1 foo | (empty) (no description set)
+    [EOF]
+    ");
+    work_dir.run_jj(["bookmark", "set", "bar"]).success();
+    let config = work_dir.read_file(".jj/repo/config.toml").to_string();
+    assert!(config.contains("[bookmark-descriptions]"));
+    assert!(config.contains(r#"foo = "Feature work""#));
+    assert!(!config.contains("bar"));
+
+    // The description is shown as a trailing annotation
+    let output = work_dir.run_jj(["bookmark", "list"]);
+    insta::assert_snapshot!(output, @r"
+    bar: qpvuntsm e8849ae1 (empty) (no description set)
+    foo: qpvuntsm e8849ae1 (empty) (no description set) # Feature work
+    [EOF]
+    ");
+
+    // The description is available to custom templates
+    let template = r#"name ++ " [" ++ annotation ++ "]\n""#;
+    let output = work_dir.run_jj(["bookmark", "list", "-T", template]);
+    insta::assert_snapshot!(output, @r"
+    bar []
+    foo [Feature work]
+    [EOF]
+    ");
+
+    // Moving the bookmark keeps the description, setting it again replaces it
+    work_dir.run_jj(["new"]).success();
+    work_dir.run_jj(["bookmark", "set", "foo"]).success();
+    work_dir
+        .run_jj(["bookmark", "set", "foo", "--description=Ready for review"])
+        .success();
+    let output = work_dir.run_jj(["bookmark", "list", "-T", template]);
+    insta::assert_snapshot!(output, @r"
+    bar []
+    foo [Ready for review]
+    [EOF]
+    ");
+
+    // Deleting the bookmark clears the description
+    work_dir.run_jj(["bookmark", "delete", "foo"]).success();
+    let config = work_dir.read_file(".jj/repo/config.toml").to_string();
+    assert!(!config.contains("foo"));
+    work_dir.run_jj(["bookmark", "set", "foo"]).success();
+    let output = work_dir.run_jj(["bookmark", "list", "-T", template]);
+    insta::assert_snapshot!(output, @r"
+    bar []
+    foo []
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_bookmark_list_tracked() {
     let test_env = TestEnvironment::default();
@@ -2244,6 +3126,22 @@ fn test_bookmark_list_tracked() {
     [EOF]
     ");
 
+    // Only the entries of the matching remotes are listed
+    let output = local_dir.run_jj(["bookmark", "list", "--remote", "upstream"]);
+    insta::assert_snapshot!(output, @r"
+    remote-unsync: nmzmmopx 2a685e16 (empty) local-only
+      @upstream (ahead by 1 commits, behind by 1 commits): zsuskuln 553203ba (empty) remote-unsync
+    upstream-sync: lylxulpl 169ba7d9 (empty) upstream-sync
+      @upstream: lylxulpl 169ba7d9 (empty) upstream-sync
+    [EOF]
+    ");
+    let output = local_dir.run_jj(["bookmark", "list", "--remote", "glob:up*", "remote-unsync"]);
+    insta::assert_snapshot!(output, @r"
+    remote-unsync: nmzmmopx 2a685e16 (empty) local-only
+      @upstream (ahead by 1 commits, behind by 1 commits): zsuskuln 553203ba (empty) remote-unsync
+    [EOF]
+    ");
+
     let output = local_dir.run_jj(["bookmark", "list", "--tracked", "remote-unsync"]);
     insta::assert_snapshot!(output, @r"
     remote-unsync: nmzmmopx 2a685e16 (empty) local-only
@@ -2421,6 +3319,31 @@ fn test_bookmark_list_sort_overriding_config() {
     ");
 }
 
+#[test]
+fn test_bookmark_list_sort_descending_prefix() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    for bookmark in ["b", "c", "a"] {
+        work_dir.run_jj(["new", "root()", "-m", "fix"]).success();
+        work_dir.run_jj(["bookmark", "create", bookmark]).success();
+    }
+
+    let template = r#"name ++ "\n""#;
+    insta::assert_snapshot!(work_dir.run_jj(["bookmark", "list", "-T", template, "--sort=-committer-date"]), @r"
+    a
+    c
+    b
+    [EOF]
+    ");
+    insta::assert_snapshot!(work_dir.run_jj(["bookmark", "list", "-T", template, "--sort=-name"]), @r"
+    c
+    b
+    a
+    [EOF]
+    ");
+}
+
 #[must_use]
 fn get_log_output(work_dir: &TestWorkDir) -> CommandOutput {
     let template = r#"bookmarks ++ " " ++ commit_id.short()"#;
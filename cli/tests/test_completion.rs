@@ -204,17 +204,20 @@ fn test_bookmark_names() {
     aaa-tracked	x
     bbb-local	x
     bbb-tracked	x
+    --retrack	Track existing remote bookmarks of the new name
+    --remote-too	Also rename the bookmark on the given remote by the next push
+    --help	Print help (see more with '--help')
     --repository	Path to repository to operate on
     --ignore-working-copy	Don't snapshot the working copy, and don't update it
     --ignore-immutable	Allow rewriting immutable commits
     --at-operation	Operation to load the repo at
+    --op-tag	Attach a tag to the operation created by this command (can be repeated)
     --debug	Enable debug logging
     --color	When to colorize output
     --quiet	Silence non-primary command output
     --no-pager	Disable the pager
     --config	Additional configuration options (can be repeated)
     --config-file	Additional configuration files (can be repeated)
-    --help	Print help (see more with '--help')
     [EOF]
     ");
 
@@ -17,6 +17,8 @@ use regex::Regex;
 
 use crate::common::CommandOutput;
 use crate::common::TestEnvironment;
+use crate::common::create_commit;
+use crate::common::create_commit_with_files;
 
 #[test]
 fn test_debug_fileset() {
@@ -147,6 +149,155 @@ fn test_debug_revset() {
     ");
 }
 
+#[test]
+fn test_debug_revset_explain() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    // c d
+    // |X|
+    // a b
+    // |/
+    // base
+    create_commit(&work_dir, "base", &[]);
+    create_commit(&work_dir, "a", &["base"]);
+    create_commit(&work_dir, "b", &["base"]);
+    create_commit(&work_dir, "c", &["a", "b"]);
+    create_commit(&work_dir, "d", &["a", "b"]);
+
+    let run_explain = |args: &[&str]| {
+        work_dir
+            .run_jj_with(|cmd| cmd.args(["debug", "revset", "--explain"]).args(args))
+            .normalize_stdout_with(|text| text.split("-- Explain:\n").nth(1).unwrap().to_owned())
+    };
+
+    let output = run_explain(&["a..d"]);
+    assert_snapshot!(output, @r"
+    Range (commits: 2)
+      roots: Commits (commits: 1)
+      heads: Commits (commits: 1)
+    [EOF]
+    ");
+    let output = run_explain(&["--count", "a..(c | d)"]);
+    assert_snapshot!(output, @r"
+    Range (commits: 3)
+      roots: Commits (commits: 1)
+      heads: Union (commits: 2)
+        Commits (commits: 1)
+        Commits (commits: 1)
+    [EOF]
+    ");
+
+    let output = run_explain(&["files(b) & ::d"]);
+    assert_snapshot!(output, @r"
+    FilterWithin (commits: 1)
+      candidates: Ancestors (commits: 5)
+        heads: Commits (commits: 1)
+      predicate: Filter File (changed-path index: no)
+    [EOF]
+    ");
+    work_dir.run_jj(["debug", "index-changed-paths"]).success();
+    let output = run_explain(&["files(b) & ::d"]);
+    assert_snapshot!(output, @r"
+    FilterWithin (commits: 1)
+      candidates: Ancestors (commits: 5)
+        heads: Commits (commits: 1)
+      predicate: Filter File (changed-path index: yes)
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_debug_changed_paths_bench() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    create_commit_with_files(&work_dir, "a", &[], &[("file1", "1\n")]);
+    create_commit_with_files(&work_dir, "b", &["a"], &[("file2", "2\n")]);
+    create_commit_with_files(&work_dir, "c", &["b"], &[("file1", "3\n")]);
+    let duration_regex = Regex::new(r"in [0-9.]+(ns|µs|ms|s)\b").unwrap();
+    let run_bench = |args: &[&str]| {
+        work_dir
+            .run_jj_with(|cmd| cmd.args(["debug", "changed-paths-bench"]).args(args))
+            .normalize_stdout_with(|text| {
+                duration_regex
+                    .replace_all(&text, "in [duration]")
+                    .into_owned()
+            })
+    };
+
+    // Without the changed-path index, only the scan is run
+    let output = run_bench(&["::c ~ root()", "file1"]);
+    assert_snapshot!(output, @r"
+    Changed-path index is disabled, only running full scan.
+    Full scan: 2 of 3 commits matched in [duration]
+    [EOF]
+    ");
+
+    // Both methods find the same commits
+    work_dir.run_jj(["debug", "index-changed-paths"]).success();
+    let output = run_bench(&["::c ~ root()", "file1"]);
+    assert_snapshot!(output, @r"
+    Changed-path index: 2 of 3 commits matched (0 not indexed) in [duration]
+    Full scan: 2 of 3 commits matched in [duration]
+    Both methods matched the same commits.
+    [EOF]
+    ");
+    let output = run_bench(&["::c ~ root()", "file2"]);
+    assert_snapshot!(output, @r"
+    Changed-path index: 1 of 3 commits matched (0 not indexed) in [duration]
+    Full scan: 1 of 3 commits matched in [duration]
+    Both methods matched the same commits.
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_debug_snapshot_bench() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    work_dir.write_file("file1", "1\n");
+    work_dir.write_file("file2", "22\n");
+    work_dir.create_dir("dir");
+    work_dir.write_file("dir/file3", "333\n");
+    let duration_regex = Regex::new(r"took [0-9.]+(ns|µs|ms|s)\b").unwrap();
+    let run_bench = || {
+        work_dir
+            .run_jj(["debug", "snapshot-bench"])
+            .normalize_stdout_with(|text| {
+                duration_regex
+                    .replace_all(&text, "took [duration]")
+                    .into_owned()
+            })
+    };
+
+    let output = run_bench();
+    assert_snapshot!(output, @r"
+    Files scanned: 3
+    Files hashed: 3 (9 bytes)
+    Snapshot took [duration]
+    [EOF]
+    ");
+
+    // The snapshot isn't recorded, so the files are scanned and hashed again
+    let output = run_bench();
+    assert_snapshot!(output, @r"
+    Files scanned: 3
+    Files hashed: 3 (9 bytes)
+    Snapshot took [duration]
+    [EOF]
+    ");
+    let output = work_dir.run_jj([
+        "log",
+        "--ignore-working-copy",
+        "--no-graph",
+        "-r@",
+        "-T=empty",
+    ]);
+    assert_snapshot!(output, @"true[EOF]");
+}
+
 #[test]
 fn test_debug_index() {
     let test_env = TestEnvironment::default();
@@ -165,18 +316,34 @@ fn test_debug_index() {
         Number of commits: 2
         Name: [hash]
     === Changed paths ===
+    Enabled: no
     Indexed commits: none
     Stats per level:
     [EOF]
     ");
 
+    let segments_dir = work_dir
+        .root()
+        .join(".jj")
+        .join("repo")
+        .join("index")
+        .join("changed_paths");
+    let count_segment_files = || {
+        std::fs::read_dir(&segments_dir)
+            .map(|entries| entries.count())
+            .unwrap_or(0)
+    };
+    assert_eq!(count_segment_files(), 0);
+
     // Enable changed-path index, index one commit
     let output = work_dir.run_jj(["debug", "index-changed-paths", "-n1"]);
     assert_snapshot!(output, @r"
     ------- stderr -------
     Finished indexing 1..2 commits.
+      Level 0: 1 commits, 0 changed paths, 0 paths
     [EOF]
     ");
+    assert_ne!(count_segment_files(), 0);
     let output = work_dir.run_jj(["debug", "index"]);
     assert_snapshot!(filter_index_stats(output), @r"
     === Commits ===
@@ -190,6 +357,7 @@ fn test_debug_index() {
         Number of commits: 2
         Name: [hash]
     === Changed paths ===
+    Enabled: yes
     Indexed commits: 1..2
     Stats per level:
       Level 0:
@@ -199,6 +367,121 @@ fn test_debug_index() {
         Name: [hash]
     [EOF]
     ");
+
+    // Disable changed-path index
+    let output = work_dir.run_jj(["debug", "index-changed-paths", "--disable"]);
+    assert_snapshot!(output, @r"
+    ------- stderr -------
+    Disabled changed-path index.
+    [EOF]
+    ");
+    work_dir.run_jj(["new"]).success();
+    let output = work_dir.run_jj(["debug", "index"]);
+    assert_snapshot!(filter_index_stats(output), @r"
+    === Commits ===
+    Number of commits: 3
+    Number of merges: 0
+    Max generation number: 2
+    Number of heads: 1
+    Number of changes: 3
+    Stats per level:
+      Level 0:
+        Number of commits: 3
+        Name: [hash]
+    === Changed paths ===
+    Enabled: no
+    Indexed commits: none
+    Stats per level:
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_debug_ancestry() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    // c
+    // a b
+    // |/
+    // base
+    work_dir.run_jj(["describe", "-m=base"]).success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "base"])
+        .success();
+    work_dir.run_jj(["new", "-m=a"]).success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "a"])
+        .success();
+    work_dir.run_jj(["new", "-m=c"]).success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "c"])
+        .success();
+    work_dir.run_jj(["new", "base", "-m=b"]).success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "b"])
+        .success();
+
+    // Replace commit hashes with bookmark names
+    let hashes: Vec<(String, &str)> = ["base", "a", "b", "c"]
+        .into_iter()
+        .map(|name| {
+            let output = work_dir
+                .run_jj(["log", "--no-graph", "-r", name, "-Tcommit_id.short()"])
+                .success();
+            (output.stdout.into_raw(), name)
+        })
+        .collect();
+    let run_ancestry = |rev_a: &str, rev_b: &str| {
+        work_dir
+            .run_jj(["debug", "ancestry", rev_a, rev_b])
+            .normalize_stdout_with(|mut text| {
+                for (hash, name) in &hashes {
+                    text = text.replace(hash, name);
+                }
+                text
+            })
+    };
+
+    let output = run_ancestry("base", "c");
+    assert_snapshot!(output, @r"
+    A: base (generation 1)
+    B: c (generation 3)
+    A is an ancestor of B: true
+    B is an ancestor of A: false
+    Merge bases: base
+    [EOF]
+    ");
+
+    let output = run_ancestry("c", "base");
+    assert_snapshot!(output, @r"
+    A: c (generation 3)
+    B: base (generation 1)
+    A is an ancestor of B: false
+    B is an ancestor of A: true
+    Merge bases: base
+    [EOF]
+    ");
+
+    let output = run_ancestry("c", "b");
+    assert_snapshot!(output, @r"
+    A: c (generation 3)
+    B: b (generation 2)
+    A is an ancestor of B: false
+    B is an ancestor of A: false
+    Merge bases: base
+    [EOF]
+    ");
+
+    let output = run_ancestry("a", "a");
+    assert_snapshot!(output, @r"
+    A: a (generation 2)
+    B: a (generation 2)
+    A is an ancestor of B: true
+    B is an ancestor of A: true
+    Merge bases: a
+    [EOF]
+    ");
 }
 
 #[test]
@@ -224,6 +507,7 @@ fn test_debug_reindex() {
         Number of commits: 1
         Name: [hash]
     === Changed paths ===
+    Enabled: no
     Indexed commits: none
     Stats per level:
     [EOF]
@@ -247,12 +531,151 @@ fn test_debug_reindex() {
         Number of commits: 4
         Name: [hash]
     === Changed paths ===
+    Enabled: no
     Indexed commits: none
     Stats per level:
     [EOF]
     ");
 }
 
+#[test]
+fn test_debug_index_rebuild() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    work_dir.run_jj(["new"]).success();
+    work_dir.run_jj(["new"]).success();
+    let output = work_dir.run_jj(["debug", "index", "rebuild"]);
+    insta::assert_snapshot!(output, @r"
+    === Before ===
+    Number of commits: 4
+    Number of merges: 0
+    Max generation number: 3
+    Commits per level:
+      Level 0: 3
+      Level 1: 1
+    === After ===
+    Number of commits: 4
+    Number of merges: 0
+    Max generation number: 3
+    Commits per level:
+      Level 0: 4
+    [EOF]
+    ");
+
+    // The changed-path index is rebuilt if it was enabled
+    work_dir
+        .run_jj(["debug", "index-changed-paths", "-n2"])
+        .success();
+    let output = work_dir.run_jj(["debug", "index", "rebuild"]);
+    insta::assert_snapshot!(output.stderr, @r"
+    Rebuilt changed-path index of 2 commits.
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["debug", "index"]);
+    insta::assert_snapshot!(filter_index_stats(output), @r"
+    === Commits ===
+    Number of commits: 4
+    Number of merges: 0
+    Max generation number: 3
+    Number of heads: 1
+    Number of changes: 4
+    Stats per level:
+      Level 0:
+        Number of commits: 4
+        Name: [hash]
+    === Changed paths ===
+    Enabled: yes
+    Indexed commits: 2..4
+    Stats per level:
+      Level 0:
+        Number of commits: 2
+        Number of changed paths: 0
+        Number of paths: 0
+        Name: [hash]
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_debug_index_reindexed() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    work_dir.run_jj(["new"]).success();
+    let corrupt_segments = || {
+        let segments_dir = work_dir
+            .root()
+            .join(".jj")
+            .join("repo")
+            .join("index")
+            .join("segments");
+        for entry in segments_dir.read_dir().unwrap() {
+            std::fs::write(entry.unwrap().path(), b"\0".repeat(24)).unwrap();
+        }
+    };
+
+    // The index is rebuilt with a warning
+    corrupt_segments();
+    let output = work_dir.run_jj(["log", "-r@", "-Tcommit_id.short()"]);
+    insta::assert_snapshot!(output, @r"
+    @  43444d88b009
+    │
+    ~
+    [EOF]
+    ------- stderr -------
+    Found commit index format version 0, expected version 6. Reindexing...
+    Warning: The index was rebuilt at operation dbcb2561b6fe because 1 index files were missing or corrupt
+    [EOF]
+    ");
+
+    // The number of broken index files is remembered
+    let output = work_dir.run_jj(["log", "-r@", "-Tcommit_id.short()"]);
+    insta::assert_snapshot!(output, @r"
+    @  43444d88b009
+    │
+    ~
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["debug", "index"]);
+    assert_snapshot!(filter_index_stats(output), @r"
+    === Commits ===
+    Number of commits: 3
+    Number of merges: 0
+    Max generation number: 2
+    Number of heads: 1
+    Number of changes: 3
+    Reindexed due to 1 missing segments
+    Stats per level:
+      Level 0:
+        Number of commits: 3
+        Name: [hash]
+    === Changed paths ===
+    Enabled: no
+    Indexed commits: none
+    Stats per level:
+    [EOF]
+    ");
+
+    // The warning can be disabled
+    corrupt_segments();
+    let output = work_dir.run_jj([
+        "log",
+        "-r@",
+        "-Tcommit_id.short()",
+        "--config=ui.warn-on-reindex=false",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    @  43444d88b009
+    │
+    ~
+    [EOF]
+    ------- stderr -------
+    Found commit index format version 0, expected version 6. Reindexing...
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_debug_tree() {
     let test_env = TestEnvironment::default();
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use itertools::Itertools as _;
+
 use crate::common::TestEnvironment;
 use crate::common::to_toml_value;
 
@@ -202,6 +204,174 @@ fn test_evolog_with_or_without_diff() {
     +my description
     [EOF]
     ");
+
+    // Test `--color-words` format with `--limit`, over the resolution step
+    let output = work_dir.run_jj(["evolog", "--no-graph", "--color-words", "--limit=1"]);
+    insta::assert_snapshot!(output, @r"
+    rlvkpnrz test.user@example.com 2001-02-03 08:05:10 33c10ace
+    my description
+    -- operation 62777a103786 snapshot working copy
+    Resolved conflict in file1:
+       1     : <<<<<<< Conflict 1 of 1
+       2     : %%%%%%% Changes from base to side #1
+       3     : -foo
+       4     : +++++++ Contents of side #2
+       5     : foo
+       6     : bar
+       7    1: >>>>>>> Conflict 1 of 1 endsresolved
+    [EOF]
+    ");
+
+    // `--color-words` and `--git` are mutually exclusive
+    let output = work_dir.run_jj(["evolog", "--color-words", "--git"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stderr.raw().contains("cannot be used with"));
+}
+
+#[test]
+fn test_evolog_with_renamed_file() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    work_dir.write_file("file1", "1\n2\n3\n4\n");
+    work_dir.run_jj(["debug", "snapshot"]).success();
+    work_dir.remove_file("file1");
+    work_dir.write_file("file2", "1\n2\n3\n5\n");
+
+    // Renames aren't detected by default
+    let template = r#"if(operation, operation.description().first_line()) ++ "\n""#;
+    let output = work_dir.run_jj(["evolog", "-s", "--no-graph", "-T", template]);
+    insta::assert_snapshot!(output, @r"
+    snapshot working copy
+    D file1
+    A file2
+    snapshot working copy
+    A file1
+    add workspace 'default'
+    [EOF]
+    ");
+
+    // The rename is shown instead of a deletion and an addition
+    let output = work_dir.run_jj([
+        "evolog",
+        "-p",
+        "--find-renames",
+        "--no-graph",
+        "-T",
+        template,
+    ]);
+    insta::assert_snapshot!(output, @r"
+    snapshot working copy
+    Modified regular file file2 (file1 => file2):
+       1    1: 1
+       2    2: 2
+       3    3: 3
+       4    4: 45
+    snapshot working copy
+    Added regular file file1:
+            1: 1
+            2: 2
+            3: 3
+            4: 4
+    add workspace 'default'
+    [EOF]
+    ");
+
+    let output = work_dir.run_jj([
+        "evolog",
+        "-s",
+        "--find-renames",
+        "--no-graph",
+        "-T",
+        template,
+    ]);
+    insta::assert_snapshot!(output, @r"
+    snapshot working copy
+    R {file1 => file2}
+    snapshot working copy
+    A file1
+    add workspace 'default'
+    [EOF]
+    ");
+
+    // The contents are 75% similar
+    let output = work_dir.run_jj([
+        "evolog",
+        "-s",
+        "--find-renames=80",
+        "--no-graph",
+        "-T",
+        template,
+    ]);
+    insta::assert_snapshot!(output, @r"
+    snapshot working copy
+    D file1
+    A file2
+    snapshot working copy
+    A file1
+    add workspace 'default'
+    [EOF]
+    ");
+
+    let output = work_dir.run_jj(["evolog", "-s", "--find-renames=20"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    error: invalid value '20' for '--find-renames[=<THRESHOLD>]': 20 is not in 50..=100
+
+    For more information, try '--help'.
+    [EOF]
+    [exit status: 2]
+    ");
+}
+
+#[test]
+fn test_evolog_filter_by_operation() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    work_dir.write_file("file1", "foo\n");
+    work_dir.run_jj(["new", "-m", "my description"]).success();
+    work_dir.write_file("file1", "foo\nbar\n");
+    work_dir.write_file("file2", "foo\n");
+    work_dir
+        .run_jj(["rebase", "-r", "@", "-o", "root()"])
+        .success();
+    work_dir.write_file("file1", "resolved\n");
+    work_dir.run_jj(["evolog"]).success();
+
+    // Only the entry created by the rebase operation
+    let output = work_dir.run_jj(["evolog", "--op", "ad81b0a6af14"]);
+    insta::assert_snapshot!(output, @r"
+    ×  rlvkpnrz hidden test.user@example.com 2001-02-03 08:05:09 7f56b2a0 conflict
+    │  my description
+    ~  -- operation ad81b0a6af14 rebase commit 51e08f95160c897080d035d330aead3ee6ed5588
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["evolog", "--op", "ad81b0a6af14", "--no-graph"]);
+    insta::assert_snapshot!(output, @r"
+    rlvkpnrz hidden test.user@example.com 2001-02-03 08:05:09 7f56b2a0 conflict
+    my description
+    -- operation ad81b0a6af14 rebase commit 51e08f95160c897080d035d330aead3ee6ed5588
+    [EOF]
+    ");
+
+    // The rebase operation and its descendants
+    let output = work_dir.run_jj(["evolog", "--op", "ad81b0a6af14", "--op-range"]);
+    insta::assert_snapshot!(output, @r"
+    @  rlvkpnrz test.user@example.com 2001-02-03 08:05:10 33c10ace
+    │  my description
+    │  -- operation 62777a103786 snapshot working copy
+    ×  rlvkpnrz hidden test.user@example.com 2001-02-03 08:05:09 7f56b2a0 conflict
+    │  my description
+    ~  -- operation ad81b0a6af14 rebase commit 51e08f95160c897080d035d330aead3ee6ed5588
+    [EOF]
+    ");
+
+    // No entries were created by the operation
+    let output = work_dir.run_jj(["evolog", "--op", "8f47435a3990"]);
+    insta::assert_snapshot!(output, @"");
 }
 
 #[test]
@@ -516,6 +686,120 @@ fn test_evolog_squash() {
        -- operation 8f47435a3990 add workspace 'default'
     [EOF]
     ");
+
+    let output = work_dir.run_jj(["evolog", "--stat", "-r", "description('squash')"]);
+    insta::assert_snapshot!(output, @r"
+    ○      qpvuntsm test.user@example.com 2001-02-03 08:05:15 5f3281c6
+    ├─┬─╮  squashed 3
+    │ │ │  -- operation 838e6d867fda squash commits into 5ec0619af5cb4f7707a556a71a6f96af0bc294d2
+    │ │ │  0 files changed, 0 insertions(+), 0 deletions(-)
+    │ │ ○  vruxwmqv hidden test.user@example.com 2001-02-03 08:05:15 770795d0
+    │ │ │  fifth
+    │ │ │  -- operation 1d38c000b52d snapshot working copy
+    │ │ │  file5 | 1 +
+    │ │ │  1 file changed, 1 insertion(+), 0 deletions(-)
+    │ │ ○  vruxwmqv hidden test.user@example.com 2001-02-03 08:05:14 2e0123d1
+    │ │    (empty) fifth
+    │ │    -- operation fc852ed87801 new empty commit
+    │ │    0 files changed, 0 insertions(+), 0 deletions(-)
+    │ ○  yqosqzyt hidden test.user@example.com 2001-02-03 08:05:14 ea8161b6
+    │ │  fourth
+    │ │  -- operation 3b09d55dfa6e snapshot working copy
+    │ │  file4 | 1 +
+    │ │  1 file changed, 1 insertion(+), 0 deletions(-)
+    │ ○  yqosqzyt hidden test.user@example.com 2001-02-03 08:05:13 1de5fdb6
+    │    (empty) fourth
+    │    -- operation 9404a551035a new empty commit
+    │    0 files changed, 0 insertions(+), 0 deletions(-)
+    ○    qpvuntsm hidden test.user@example.com 2001-02-03 08:05:12 5ec0619a
+    ├─╮  squashed 2
+    │ │  -- operation fa9796d12627 squash commits into 690858846504af0e42fde980fdacf9851559ebb8
+    │ │  file2 | 1 -
+    │ │  file3 | 1 -
+    │ │  2 files changed, 0 insertions(+), 2 deletions(-)
+    │ ○  zsuskuln hidden test.user@example.com 2001-02-03 08:05:12 cce957f1
+    │ │  third
+    │ │  -- operation de96267cd621 snapshot working copy
+    │ │  file1 | 1 +
+    │ │  file2 | 1 +
+    │ │  file3 | 1 +
+    │ │  3 files changed, 3 insertions(+), 0 deletions(-)
+    │ ○  zsuskuln hidden test.user@example.com 2001-02-03 08:05:11 3a2a4253
+    │ │  (empty) third
+    │ │  -- operation 4611a6121e8a describe commit ebec10f449ad7ab92c7293efab5e3db2d8e9fea1
+    │ │  0 files changed, 0 insertions(+), 0 deletions(-)
+    │ ○  zsuskuln hidden test.user@example.com 2001-02-03 08:05:10 ebec10f4
+    │    (empty) (no description set)
+    │    -- operation 65c81703100d squash commits into 5878cbe03cdf599c9353e5a1a52a01f4c5e0e0fa
+    │    0 files changed, 0 insertions(+), 0 deletions(-)
+    ○    qpvuntsm hidden test.user@example.com 2001-02-03 08:05:10 69085884
+    ├─╮  squashed 1
+    │ │  -- operation 65c81703100d squash commits into 5878cbe03cdf599c9353e5a1a52a01f4c5e0e0fa
+    │ │  0 files changed, 0 insertions(+), 0 deletions(-)
+    │ ○  kkmpptxz hidden test.user@example.com 2001-02-03 08:05:10 a3759c9d
+    │ │  second
+    │ │  -- operation a7b202f56742 snapshot working copy
+    │ │  file1 | 1 +
+    │ │  1 file changed, 1 insertion(+), 0 deletions(-)
+    │ ○  kkmpptxz hidden test.user@example.com 2001-02-03 08:05:09 a5b2f625
+    │    (empty) second
+    │    -- operation 26f649a0cdfa new empty commit
+    │    0 files changed, 0 insertions(+), 0 deletions(-)
+    ○  qpvuntsm hidden test.user@example.com 2001-02-03 08:05:09 5878cbe0
+    │  first
+    │  -- operation af15122a5868 snapshot working copy
+    │  file1 | 1 +
+    │  1 file changed, 1 insertion(+), 0 deletions(-)
+    ○  qpvuntsm hidden test.user@example.com 2001-02-03 08:05:08 68a50538
+    │  (empty) first
+    │  -- operation 75545f7ff2df describe commit e8849ae12c709f2321908879bc724fdb2ab8a781
+    │  0 files changed, 0 insertions(+), 0 deletions(-)
+    ○  qpvuntsm hidden test.user@example.com 2001-02-03 08:05:07 e8849ae1
+       (empty) (no description set)
+       -- operation 8f47435a3990 add workspace 'default'
+       0 files changed, 0 insertions(+), 0 deletions(-)
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_evolog_shared_predecessors() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    work_dir.run_jj(["describe", "-m", "original"]).success();
+    work_dir.write_file("file1", "foo\n");
+    work_dir.write_file("file2", "foo\n");
+    // Both halves of the split have the snapshotted commit as predecessor
+    work_dir.run_jj(["split", "-m", "first", "file1"]).success();
+
+    let template = r#"if(commit.description(), commit.description().first_line(), "(no description)") ++ "\n""#;
+    let output = work_dir.run_jj(["evolog", "-r", "@ | @-", "-T", template]);
+    insta::assert_snapshot!(output, @r"
+    @  original
+    │ ○  first
+    ├─╯
+    ○  original
+    ○  original
+    ○  (no description)
+    [EOF]
+    ");
+
+    // Shared predecessors are listed once
+    let output = work_dir
+        .run_jj([
+            "evolog",
+            "--no-graph",
+            "-r",
+            "@ | @-",
+            "-T",
+            r#"commit.commit_id() ++ "\n""#,
+        ])
+        .success();
+    let commit_ids = output.stdout.raw().lines().collect_vec();
+    assert_eq!(commit_ids.len(), 5);
+    assert!(commit_ids.iter().all_unique());
 }
 
 #[test]
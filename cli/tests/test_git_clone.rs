@@ -819,6 +819,29 @@ fn test_git_clone_with_remote_name() {
     Added 1 files, modified 0 files, removed 0 files
     [EOF]
     "#);
+
+    // The remote name can also be specified by --remote-name
+    root_dir
+        .run_jj([
+            "git",
+            "clone",
+            "source",
+            "clone2",
+            "--remote-name",
+            "upstream",
+        ])
+        .success();
+    let clone_dir = test_env.work_dir("clone2");
+    let output = clone_dir.run_jj(["git", "remote", "list"]);
+    insta::assert_snapshot!(output, @r"
+    upstream $TEST_ENV/source
+    [EOF]
+    ");
+    let output = clone_dir.run_jj(["config", "list", "--repo", "revset-aliases.'trunk()'"]);
+    insta::assert_snapshot!(output, @r#"
+    revset-aliases.'trunk()' = "main@upstream"
+    [EOF]
+    "#);
 }
 
 #[test]
@@ -877,6 +900,8 @@ fn test_git_clone_trunk_deleted() {
     let output = clone_dir.run_jj(["bookmark", "forget", "--include-remotes", "main"]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
+    Local bookmarks to forget: main
+    Remote bookmarks to forget on origin: main
     Forgot 1 local bookmarks.
     Forgot 1 remote bookmarks.
     Warning: Failed to resolve `revset-aliases.trunk()`: Revision `main@origin` doesn't exist
@@ -1244,6 +1269,58 @@ fn test_git_clone_branch() {
     [EOF]
     ");
 }
+#[test]
+fn test_git_clone_some_bookmarks() {
+    let test_env = TestEnvironment::default();
+    let root_dir = test_env.work_dir("");
+    let git_repo_path = test_env.env_root().join("source");
+    let git_repo = git::init(&git_repo_path);
+    set_up_non_empty_git_repo(&git_repo);
+    for name in ["release/1", "feature1"] {
+        git::add_commit(
+            &git_repo,
+            &format!("refs/heads/{name}"),
+            "file",
+            b"content",
+            "message",
+            &[],
+        );
+    }
+
+    // Only the matching bookmarks are fetched and tracked
+    let output = root_dir
+        .run_jj([
+            "git",
+            "clone",
+            "source",
+            "clone",
+            "--bookmark=main",
+            "--bookmark=glob:release/*",
+        ])
+        .success()
+        .normalize_stderr_with(|stderr| {
+            stderr
+                .lines()
+                .filter(|line| line.starts_with("bookmark:") || line.starts_with("Setting"))
+                .map(|line| format!("{line}\n"))
+                .collect()
+        });
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: main@origin      [new] tracked
+    bookmark: release/1@origin [new] tracked
+    Setting the revset alias `trunk()` to `main@origin`
+    [EOF]
+    ");
+    let clone_dir = test_env.work_dir("clone");
+    insta::assert_snapshot!(get_bookmark_output(&clone_dir), @r"
+    main: qomsplrm ebeb70d8 message
+      @origin: qomsplrm ebeb70d8 message
+    release/1: qomsplrm ebeb70d8 message
+      @origin: qomsplrm ebeb70d8 message
+    [EOF]
+    ");
+}
 
 #[must_use]
 fn get_bookmark_output(work_dir: &TestWorkDir) -> CommandOutput {
@@ -504,6 +504,8 @@ fn test_git_colocated_bookmark_forget() {
     let output = work_dir.run_jj(["bookmark", "forget", "--include-remotes", "foo"]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
+    Local bookmarks to forget: foo
+    Remote bookmarks to forget on git: foo
     Forgot 1 local bookmarks.
     Forgot 1 remote bookmarks.
     [EOF]
@@ -237,6 +237,81 @@ fn test_git_fetch_multiple_remotes() {
     ");
 }
 
+#[test]
+fn test_git_fetch_remote_qualified_branch() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    let rem1_repo = add_git_remote(&test_env, &work_dir, "rem1");
+    let rem2_repo = add_git_remote(&test_env, &work_dir, "rem2");
+    add_commit_to_branch(&rem1_repo, "shared", "shared in rem1");
+    add_commit_to_branch(&rem2_repo, "shared", "shared in rem2");
+    let get_remote_bookmarks = || {
+        work_dir.run_jj([
+            "bookmark",
+            "list",
+            "--all-remotes",
+            "-T",
+            r#"separate("@", name, remote) ++ "\n""#,
+        ])
+    };
+
+    // Only the qualified remote is fetched
+    let output = work_dir.run_jj(["git", "fetch", "--branch", "shared@rem2"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: shared@rem2 [new] untracked
+    [EOF]
+    ");
+    insta::assert_snapshot!(get_remote_bookmarks(), @r"
+    shared@rem2
+    [EOF]
+    ");
+
+    // Qualified and unqualified branches can be mixed
+    let output = work_dir.run_jj([
+        "git",
+        "fetch",
+        "--remote=rem1",
+        "--remote=rem2",
+        "--branch=glob:rem*",
+        "--branch=shared@rem1",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: rem1@rem1   [new] untracked
+    bookmark: rem2@rem2   [new] untracked
+    bookmark: shared@rem1 [new] untracked
+    [EOF]
+    ");
+    insta::assert_snapshot!(get_remote_bookmarks(), @r"
+    rem1@rem1
+    rem2@rem2
+    shared@rem1
+    shared@rem2
+    [EOF]
+    ");
+
+    // The qualified remote must be selected by --remote if specified
+    let output = work_dir.run_jj(["git", "fetch", "--remote=rem1", "--branch=shared@rem2"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Remote rem2 is not selected by --remote
+    Hint: Specify the remote with `--remote` too, or omit `--remote`.
+    [EOF]
+    [exit status: 1]
+    ");
+
+    // The qualifier must name an existing remote
+    let output = work_dir.run_jj(["git", "fetch", "--branch", "shared@rem3"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: No git remote named 'rem3'
+    [EOF]
+    [exit status: 1]
+    ");
+}
+
 #[test]
 fn test_git_fetch_with_ignored_refspecs() {
     let test_env = TestEnvironment::default();
@@ -324,6 +399,46 @@ fn test_git_fetch_with_glob_and_exact_match() {
     ");
 }
 
+#[test]
+fn test_git_fetch_with_negated_pattern() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    add_git_remote(&test_env, &work_dir, "rem1");
+    add_git_remote(&test_env, &work_dir, "rem2");
+    add_git_remote(&test_env, &work_dir, "origin");
+
+    // Every remote is excluded
+    let output = work_dir.run_jj(["git", "fetch", "--remote=glob:*", "--remote=~glob:*"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: No git remotes to fetch from
+    [EOF]
+    [exit status: 1]
+    ");
+
+    // All but origin are fetched
+    let output = work_dir.run_jj(["git", "fetch", "--remote=~origin", "--remote=glob:*"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: rem1@rem1 [new] untracked
+    bookmark: rem2@rem2 [new] untracked
+    [EOF]
+    ");
+    let output = work_dir.run_jj([
+        "bookmark",
+        "list",
+        "--all-remotes",
+        "-T",
+        r#"separate("@", name, remote) ++ "\n""#,
+    ]);
+    insta::assert_snapshot!(output, @r"
+    rem1@rem1
+    rem2@rem2
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_git_fetch_with_glob_from_config() {
     let test_env = TestEnvironment::default();
@@ -426,6 +541,71 @@ fn test_git_fetch_no_matching_remote() {
     insta::assert_snapshot!(get_bookmark_output(&work_dir), @"");
 }
 
+#[cfg(unix)]
+#[test]
+fn test_git_fetch_timeout() {
+    let mut test_env = TestEnvironment::default();
+    // Allow the "ext::" transport so that a remote can be made unresponsive.
+    test_env.add_env_var("GIT_CONFIG_COUNT", "1");
+    test_env.add_env_var("GIT_CONFIG_KEY_0", "protocol.ext.allow");
+    test_env.add_env_var("GIT_CONFIG_VALUE_0", "always");
+    test_env.add_config("git.auto-local-bookmark = true");
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    add_git_remote(&test_env, &work_dir, "rem1");
+    work_dir
+        .run_jj(["git", "remote", "add", "hung", "ext::sleep 60"])
+        .success();
+
+    // Fetching from a single remote fails
+    let output = work_dir.run_jj(["git", "fetch", "--remote", "hung", "--timeout", "1"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Failed to fetch from remote hung
+    Caused by: Git process timed out after 1s
+    [EOF]
+    [exit status: 1]
+    ");
+
+    // With multiple remotes, the unresponsive remote is skipped
+    test_env.add_config("git.fetch-timeout = 1");
+    let output = work_dir.run_jj(["git", "fetch", "--remote", "hung", "--remote", "rem1"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Warning: Failed to fetch from remote hung: Git process timed out after 1s
+    bookmark: rem1@rem1 [new] tracked
+    [EOF]
+    ");
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    rem1: ppspxspk 4acd0343 message
+      @rem1: ppspxspk 4acd0343 message
+    [EOF]
+    ");
+
+    // The same applies to --all-remotes
+    let output = work_dir.run_jj(["git", "fetch", "--all-remotes"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Warning: Failed to fetch from remote hung: Git process timed out after 1s
+    Nothing changed.
+    [EOF]
+    ");
+
+    // It's an error if no remote could be fetched from
+    work_dir
+        .run_jj(["git", "remote", "add", "hung2", "ext::sleep 60"])
+        .success();
+    let output = work_dir.run_jj(["git", "fetch", "--remote", "glob:hung*"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Warning: Failed to fetch from remote hung: Git process timed out after 1s
+    Warning: Failed to fetch from remote hung2: Git process timed out after 1s
+    Error: Failed to fetch from any of the remotes: hung, hung2
+    [EOF]
+    [exit status: 1]
+    ");
+}
+
 #[test]
 fn test_git_fetch_nonexistent_remote() {
     let test_env = TestEnvironment::default();
@@ -467,6 +647,192 @@ fn test_git_fetch_nonexistent_remote_from_config() {
     ");
 }
 
+#[test]
+fn test_git_fetch_fallback_mode() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    work_dir
+        .run_jj(["git", "remote", "add", "primary", "../bogus"])
+        .success();
+    add_git_remote(&test_env, &work_dir, "rem1");
+    add_git_remote(&test_env, &work_dir, "rem2");
+    test_env.add_config(r#"git.fetch = ["primary", "rem1", "rem2"]"#);
+    test_env.add_config(r#"git.fetch-mode = "fallback""#);
+
+    // The unreachable primary remote is skipped, and only the first remote
+    // that succeeds is fetched.
+    let output = work_dir.run_jj(["git", "fetch"]).success();
+    let stderr = output.stderr.normalized();
+    assert!(
+        stderr.starts_with("Warning: Failed to fetch from remote primary: "),
+        "{stderr}"
+    );
+    assert!(
+        stderr.ends_with("bookmark: rem1@rem1 [new] untracked\n"),
+        "{stderr}"
+    );
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    rem1@rem1: ppspxspk 4acd0343 message
+    [EOF]
+    ");
+
+    // Remotes specified on the command line are all fetched
+    let output = work_dir.run_jj(["git", "fetch", "--remote", "rem2"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: rem2@rem2 [new] untracked
+    [EOF]
+    ");
+
+    // Fails if none of the remotes can be fetched from
+    test_env.add_config(r#"git.fetch = ["primary"]"#);
+    let output = work_dir.run_jj(["git", "fetch"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = output.stderr.normalized();
+    assert!(
+        stderr.ends_with("Error: Failed to fetch from any of the remotes: primary\n"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn test_git_fetch_max_concurrent() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = true");
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    let rem1_repo = add_git_remote(&test_env, &work_dir, "rem1");
+    let rem2_repo = add_git_remote(&test_env, &work_dir, "rem2");
+
+    // The results are reported in the order of the remote names
+    let output = work_dir.run_jj(["git", "fetch", "--all-remotes", "--max-concurrent=2"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: rem1@rem1 [new] tracked
+    bookmark: rem2@rem2 [new] tracked
+    [EOF]
+    ");
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    rem1: ppspxspk 4acd0343 message
+      @rem1: ppspxspk 4acd0343 message
+    rem2: pzqqpnpo 44c57802 message
+      @rem2: pzqqpnpo 44c57802 message
+    [EOF]
+    ");
+
+    // The other remotes are imported if one of them fails
+    work_dir
+        .run_jj(["git", "remote", "add", "broken", "../bogus"])
+        .success();
+    add_commit_to_branch(&rem1_repo, "rem1", "rem1 updated");
+    add_commit_to_branch(&rem2_repo, "rem2", "rem2 updated");
+    let output = work_dir.run_jj(["git", "fetch", "--all-remotes", "--max-concurrent=3"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Warning: Failed to fetch from remote broken: Could not find repository at '$TEST_ENV/bogus'
+    bookmark: rem1@rem1 [updated] tracked
+    bookmark: rem2@rem2 [updated] tracked
+    [EOF]
+    ");
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    rem1: quoolxoo 5a02afd1 (empty) rem1 updated
+      @rem1: quoolxoo 5a02afd1 (empty) rem1 updated
+    rem2: lkuorsuw dd723bab (empty) rem2 updated
+      @rem2: lkuorsuw dd723bab (empty) rem2 updated
+    [EOF]
+    ");
+
+    // Nothing is imported with --atomic
+    add_commit_to_branch(&rem1_repo, "rem1", "rem1 updated again");
+    let output = work_dir.run_jj([
+        "git",
+        "fetch",
+        "--all-remotes",
+        "--max-concurrent=3",
+        "--atomic",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Failed to fetch from remote broken
+    Caused by: Could not find repository at '$TEST_ENV/bogus'
+    [EOF]
+    [exit status: 1]
+    ");
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    rem1: quoolxoo 5a02afd1 (empty) rem1 updated
+      @rem1: quoolxoo 5a02afd1 (empty) rem1 updated
+    rem2: lkuorsuw dd723bab (empty) rem2 updated
+      @rem2: lkuorsuw dd723bab (empty) rem2 updated
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_git_fetch_retry() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    // Nothing listens on port 1, so the connection is refused
+    work_dir
+        .run_jj([
+            "git",
+            "remote",
+            "add",
+            "unreachable",
+            "git://127.0.0.1:1/repo",
+        ])
+        .success();
+    work_dir
+        .run_jj(["git", "remote", "add", "missing", "../bogus"])
+        .success();
+    add_git_remote(&test_env, &work_dir, "rem1");
+
+    // The unreachable remote is tried once more per retry, then the fetch fails
+    let output = work_dir.run_jj([
+        "git",
+        "fetch",
+        "--remote=unreachable",
+        "--retry=2",
+        "--retry-delay=0",
+    ]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = output.stderr.normalized();
+    assert_eq!(stderr.matches("retrying in").count(), 2, "{stderr}");
+    assert!(
+        stderr.starts_with("Warning: Failed to fetch from remote unreachable (attempt 1 of 3)"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("(attempt 2 of 3)"), "{stderr}");
+    assert!(stderr.contains("\nError: "), "{stderr}");
+    // Nothing was fetched
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @"");
+
+    // A missing repository isn't a connection failure, so it isn't retried
+    let output = work_dir.run_jj([
+        "git",
+        "fetch",
+        "--remote=missing",
+        "--retry=2",
+        "--retry-delay=0",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Could not find repository at '$TEST_ENV/bogus'
+    [EOF]
+    [exit status: 1]
+    ");
+
+    // A reachable remote is fetched on the first attempt
+    work_dir
+        .run_jj(["git", "fetch", "--remote=rem1", "--retry=2"])
+        .success();
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    rem1@rem1: ppspxspk 4acd0343 message
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_git_fetch_from_remote_named_git() {
     let test_env = TestEnvironment::default();
@@ -588,43 +954,371 @@ fn test_git_fetch_prune_before_updating_tips() {
         )
         .unwrap();
 
-    work_dir.run_jj(["git", "fetch"]).success();
+    work_dir.run_jj(["git", "fetch"]).success();
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    origin/subname: qmyrypzk ab8b299e message
+      @origin: qmyrypzk ab8b299e message
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_git_fetch_prune_local_bookmarks() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = true");
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    let git_repo = add_git_remote(&test_env, &work_dir, "origin");
+    let commit_id = git_repo
+        .find_reference("refs/heads/origin")
+        .unwrap()
+        .peel_to_commit()
+        .unwrap()
+        .id()
+        .detach();
+    for name in ["refs/heads/untracked", "refs/heads/moved"] {
+        git_repo
+            .reference(
+                name,
+                commit_id,
+                gix::refs::transaction::PreviousValue::MustNotExist,
+                "create new reference",
+            )
+            .unwrap();
+    }
+    work_dir.run_jj(["git", "fetch"]).success();
+    // Keep the local bookmark alive after the remote bookmark is deleted
+    work_dir
+        .run_jj(["bookmark", "untrack", "untracked@origin"])
+        .success();
+    // Local edits should be preserved, and conflict with the deletion
+    work_dir.run_jj(["new", "moved"]).success();
+    work_dir
+        .run_jj(["bookmark", "set", "moved", "-r@"])
+        .success();
+
+    // Delete all bookmarks upstream
+    for name in [
+        "refs/heads/origin",
+        "refs/heads/untracked",
+        "refs/heads/moved",
+    ] {
+        git_repo.find_reference(name).unwrap().delete().unwrap();
+    }
+    let output = work_dir.run_jj(["git", "fetch", "--prune"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: moved@origin     [deleted] tracked
+    bookmark: origin@origin    [deleted] untracked
+    bookmark: untracked@origin [deleted] untracked
+    bookmark: origin           [pruned]
+    bookmark: untracked        [pruned]
+    [EOF]
+    ");
+    let template =
+        r#"separate(" ", name ++ if(remote, "@" ++ remote), if(conflict, "(conflicted)")) ++ "\n""#;
+    let output = work_dir.run_jj(["bookmark", "list", "-T", template]);
+    insta::assert_snapshot!(output, @r"
+    moved (conflicted)
+    moved@origin
+    [EOF]
+    ------- stderr -------
+    Hint: Some bookmarks have conflicts. Use `jj bookmark set <name> -r <rev>` to resolve.
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_git_fetch_record_changes() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    let git_repo = add_git_remote(&test_env, &work_dir, "origin");
+    let origin_id1 = git_repo
+        .find_reference("refs/heads/origin")
+        .unwrap()
+        .peel_to_id()
+        .unwrap()
+        .detach();
+    let feature_id = add_commit_to_branch(&git_repo, "feature", "feature");
+
+    let get_fetched_bookmarks = || {
+        let output = work_dir.run_jj(["op", "show", "--format=json"]).success();
+        let mut op: serde_json::Value = serde_json::from_str(output.stdout.raw()).unwrap();
+        assert_eq!(op["tags"].get("fetched_bookmarks"), None);
+        op.get_mut("fetched_bookmarks").map(serde_json::Value::take)
+    };
+
+    // Changes aren't recorded by default
+    work_dir.run_jj(["git", "fetch"]).success();
+    assert_eq!(get_fetched_bookmarks(), None);
+
+    // New bookmarks
+    work_dir.run_jj(["op", "restore", "@-"]).success();
+    work_dir
+        .run_jj(["git", "fetch", "--record-changes"])
+        .success();
+    assert_eq!(
+        get_fetched_bookmarks(),
+        Some(serde_json::json!([
+            {
+                "remote": "origin",
+                "name": "feature",
+                "kind": "new",
+                "old_target": [],
+                "new_target": [feature_id.to_string()],
+            },
+            {
+                "remote": "origin",
+                "name": "origin",
+                "kind": "new",
+                "old_target": [],
+                "new_target": [origin_id1.to_string()],
+            },
+        ]))
+    );
+
+    // Updated and deleted bookmarks
+    let origin_id2 = add_commit_to_branch(&git_repo, "origin", "updated");
+    git_repo
+        .find_reference("refs/heads/feature")
+        .unwrap()
+        .delete()
+        .unwrap();
+    work_dir
+        .run_jj(["git", "fetch", "--record-changes"])
+        .success();
+    assert_eq!(
+        get_fetched_bookmarks(),
+        Some(serde_json::json!([
+            {
+                "remote": "origin",
+                "name": "feature",
+                "kind": "deleted",
+                "old_target": [feature_id.to_string()],
+                "new_target": [],
+            },
+            {
+                "remote": "origin",
+                "name": "origin",
+                "kind": "updated",
+                "old_target": [origin_id1.to_string()],
+                "new_target": [origin_id2.to_string()],
+            },
+        ]))
+    );
+}
+
+#[test]
+fn test_git_fetch_with_depth() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = true");
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    let git_repo = add_git_remote(&test_env, &work_dir, "origin");
+    add_commit_to_branch(&git_repo, "origin", "second");
+    add_commit_to_branch(&git_repo, "origin", "third");
+
+    let output = work_dir.run_jj(["git", "fetch", "--depth", "1"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: origin@origin [new] tracked
+    [EOF]
+    ");
+    // The shallow boundary is shown as a child of the root commit
+    let template =
+        r#"separate(" ", if(root, "root", description.first_line()), bookmarks) ++ "\n""#;
+    let output = work_dir.run_jj(["log", "-r", "::origin", "-T", template]);
+    insta::assert_snapshot!(output, @r"
+    ○  third origin
+    ◆  root
+    [EOF]
+    ");
+
+    // Unshallowing brings back the full history
+    let output = work_dir.run_jj(["git", "fetch", "--unshallow"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Nothing changed.
+    Reindexed 5 commits after fetching deeper history.
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["log", "-r", "::origin", "-T", template]);
+    insta::assert_snapshot!(output, @r"
+    ○  third origin
+    ○  second
+    ○  message
+    ◆  root
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_git_fetch_since() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = true");
+    test_env
+        .run_jj_in(".", ["git", "init", "--colocate", "origin"])
+        .success();
+    let origin_dir = test_env.work_dir("origin");
+    origin_dir
+        .run_jj_with(|cmd| {
+            cmd.args(["describe", "-m=old"])
+                .env("JJ_TIMESTAMP", "2020-01-01T00:00:00+00:00")
+        })
+        .success();
+    origin_dir
+        .run_jj_with(|cmd| {
+            cmd.args(["new", "-m=recent"])
+                .env("JJ_TIMESTAMP", "2024-06-01T00:00:00+00:00")
+        })
+        .success();
+    origin_dir
+        .run_jj(["bookmark", "create", "-r@", "main"])
+        .success();
+
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    work_dir
+        .run_jj(["git", "remote", "add", "origin", "../origin"])
+        .success();
+    let output = work_dir.run_jj(["git", "fetch", "--since=2024-01-01"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: main@origin [new] tracked
+    [EOF]
+    ");
+    // Only the recent commit is fetched, and it is shown as a child of the
+    // root commit
+    let template =
+        r#"separate(" ", if(root, "root", description.first_line()), bookmarks) ++ "\n""#;
+    let output = work_dir.run_jj(["log", "-r", "::main", "-T", template]);
+    insta::assert_snapshot!(output, @r"
+    ◆  recent main
+    ◆  root
+    [EOF]
+    ");
+
+    let output = work_dir.run_jj(["git", "fetch", "--since=not a date"]);
+    assert!(!output.status.success());
+    assert!(output.stderr.raw().contains("Invalid date: not a date"));
+}
+
+#[test]
+fn test_git_fetch_conflicting_bookmarks() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = true");
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    add_git_remote(&test_env, &work_dir, "rem1");
+
+    // Create a rem1 bookmark locally
+    work_dir.run_jj(["new", "root()"]).success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "rem1"])
+        .success();
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    rem1: kkmpptxz 2b17ac71 (empty) (no description set)
+    [EOF]
+    ");
+
+    work_dir
+        .run_jj(["git", "fetch", "--remote", "rem1", "--branch", "glob:*"])
+        .success();
+    // This should result in a CONFLICTED bookmark
     insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
-    origin/subname: qmyrypzk ab8b299e message
-      @origin: qmyrypzk ab8b299e message
+    rem1 (conflicted):
+      + kkmpptxz 2b17ac71 (empty) (no description set)
+      + ppspxspk 4acd0343 message
+      @rem1 (behind by 1 commits): ppspxspk 4acd0343 message
+    [EOF]
+    ");
+
+    // Suggest a command to resolve the conflict to each of the targets
+    let get_commit_id = |rev: &str| {
+        work_dir
+            .run_jj(["log", "--no-graph", "-T=commit_id.short()", "-r", rev])
+            .success()
+            .stdout
+            .into_raw()
+    };
+    let commit1 = get_commit_id("kkmpptxz");
+    let commit2 = get_commit_id("ppspxspk");
+    let output = work_dir.run_jj(["bookmark", "list", "--all-remotes", "--merge-conflicts"]);
+    insta::assert_snapshot!(
+        output.normalize_stderr_with(|s| s.replace(&commit1, "<commit1>").replace(&commit2, "<commit2>")), @r"
+    rem1 (conflicted):
+      + kkmpptxz 2b17ac71 (empty) (no description set)
+      + ppspxspk 4acd0343 message
+      @rem1 (behind by 1 commits): ppspxspk 4acd0343 message
+    [EOF]
+    ------- stderr -------
+    Hint: Bookmark rem1 is conflicted. To resolve it, run one of:
+      jj bookmark set rem1 -r <commit1>
+      jj bookmark set rem1 -r <commit2>
     [EOF]
     ");
 }
 
 #[test]
-fn test_git_fetch_conflicting_bookmarks() {
+fn test_git_fetch_list_conflicted_bookmarks() {
     let test_env = TestEnvironment::default();
     test_env.add_config("git.auto-local-bookmark = true");
     test_env.run_jj_in(".", ["git", "init", "repo"]).success();
     let work_dir = test_env.work_dir("repo");
     add_git_remote(&test_env, &work_dir, "rem1");
 
-    // Create a rem1 bookmark locally
     work_dir.run_jj(["new", "root()"]).success();
     work_dir
-        .run_jj(["bookmark", "create", "-r@", "rem1"])
+        .run_jj(["bookmark", "create", "-r@", "rem1", "other"])
         .success();
-    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
-    rem1: kkmpptxz 2b17ac71 (empty) (no description set)
-    [EOF]
-    ");
-
     work_dir
         .run_jj(["git", "fetch", "--remote", "rem1", "--branch", "glob:*"])
         .success();
-    // This should result in a CONFLICTED bookmark
     insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    other: kkmpptxz 2b17ac71 (empty) (no description set)
+    rem1 (conflicted):
+      + kkmpptxz 2b17ac71 (empty) (no description set)
+      + ppspxspk 4acd0343 message
+      @rem1 (behind by 1 commits): ppspxspk 4acd0343 message
+    [EOF]
+    ");
+
+    // Only the conflicted bookmark is listed
+    let output = work_dir.run_jj(["bookmark", "list", "--conflicted-only"]);
+    insta::assert_snapshot!(output, @r"
+    rem1 (conflicted):
+      + kkmpptxz 2b17ac71 (empty) (no description set)
+      + ppspxspk 4acd0343 message
+      @rem1 (behind by 1 commits): ppspxspk 4acd0343 message
+    [EOF]
+    ------- stderr -------
+    Hint: Some bookmarks have conflicts. Use `jj bookmark set <name> -r <rev>` to resolve.
+    [EOF]
+    ");
+
+    // --exit-code fails if any conflicted bookmarks are listed
+    let output = work_dir.run_jj(["bookmark", "list", "--conflicted-only", "--exit-code"]);
+    insta::assert_snapshot!(output, @r"
     rem1 (conflicted):
       + kkmpptxz 2b17ac71 (empty) (no description set)
       + ppspxspk 4acd0343 message
       @rem1 (behind by 1 commits): ppspxspk 4acd0343 message
     [EOF]
+    ------- stderr -------
+    Hint: Some bookmarks have conflicts. Use `jj bookmark set <name> -r <rev>` to resolve.
+    Error: Found 1 conflicted bookmarks
+    [EOF]
+    [exit status: 1]
     ");
+    let output = work_dir.run_jj([
+        "bookmark",
+        "list",
+        "--conflicted-only",
+        "--exit-code",
+        "other",
+    ]);
+    insta::assert_snapshot!(output, @"");
 }
 
 #[test]
@@ -666,6 +1360,50 @@ fn test_git_fetch_conflicting_bookmarks_colocated() {
     ");
 }
 
+#[test]
+fn test_git_fetch_import_after_colocated() {
+    let test_env = TestEnvironment::default();
+    let work_dir = test_env.work_dir("repo");
+    let git_repo = git::init(work_dir.root());
+    work_dir
+        .run_jj(["git", "init", "--git-repo", "."])
+        .success();
+    add_git_remote(&test_env, &work_dir, "rem1");
+    let list_bookmarks = || {
+        work_dir.run_jj([
+            "bookmark",
+            "list",
+            "--all-remotes",
+            "--ignore-working-copy",
+            "-T",
+            r#"name ++ if(remote, "@" ++ remote) ++ ": "
+               ++ normal_target.description().first_line() ++ "\n""#,
+        ])
+    };
+
+    // The local Git branch isn't imported by the fetch operation
+    add_commit_to_branch(&git_repo, "local1", "local 1");
+    work_dir
+        .run_jj(["git", "fetch", "--remote=rem1", "--import-after=false"])
+        .success();
+    insta::assert_snapshot!(list_bookmarks(), @r"
+    rem1@rem1: message
+    [EOF]
+    ");
+
+    // It's imported by the next command
+    add_commit_to_branch(&git_repo, "local2", "local 2");
+    work_dir.run_jj(["git", "fetch", "--remote=rem1"]).success();
+    insta::assert_snapshot!(list_bookmarks(), @r"
+    local1: local 1
+    local1@git: local 1
+    local2: local 2
+    local2@git: local 2
+    rem1@rem1: message
+    [EOF]
+    ");
+}
+
 // Helper functions to test obtaining multiple bookmarks at once and changed
 // bookmarks
 fn create_colocated_repo_and_bookmarks_from_trunk1(work_dir: &TestWorkDir) -> String {
@@ -811,6 +1549,11 @@ fn test_git_fetch_all() {
       @origin: kkmpptxz 38288177 trunk1
     [EOF]
     ");
+    let output = target_dir.run_jj(["bookmark", "list", "--ahead-behind-only"]);
+    insta::assert_snapshot!(output, @r"
+    b@origin (ahead by 1 commits, behind by 1 commits)
+    [EOF]
+    ");
     let output = target_dir.run_jj(["git", "fetch"]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
@@ -837,6 +1580,14 @@ fn test_git_fetch_all() {
       @origin: uyznsvlq e80d998a trunk2
     [EOF]
     ");
+    let output = target_dir.run_jj(["bookmark", "list", "--ahead-behind-only"]);
+    insta::assert_snapshot!(output, @r"
+    b@origin (behind by 1 commits)
+    [EOF]
+    ------- stderr -------
+    Hint: Some bookmarks have conflicts. Use `jj bookmark set <name> -r <rev>` to resolve.
+    [EOF]
+    ");
     insta::assert_snapshot!(get_log_output(&target_dir), @r#"
     @  e8849ae12c70 ""
     │ ○  6fc6fe17dbee "b" b?? b@origin
@@ -1384,6 +2135,60 @@ fn test_fetch_undo_what() {
     ");
 }
 
+#[test]
+fn test_fetch_undo_what_bookmarks() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = true");
+    let source_dir = test_env.work_dir("source");
+    git::init(source_dir.root());
+    test_env
+        .run_jj_in(".", ["git", "clone", "source", "target"])
+        .success();
+    let work_dir = test_env.work_dir("target");
+    create_colocated_repo_and_bookmarks_from_trunk1(&source_dir);
+
+    work_dir.run_jj(["git", "fetch", "--branch", "b"]).success();
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    b: yostqsxw bc83465a b
+      @origin: yostqsxw bc83465a b
+    [EOF]
+    ");
+    let fetch_operation_id = work_dir.current_operation_id();
+
+    // Move the local bookmark by accident, and change the remote-tracking state
+    work_dir
+        .run_jj(["bookmark", "move", "b", "--to=@", "--allow-backwards"])
+        .success();
+    work_dir
+        .run_jj(["bookmark", "untrack", "b@origin"])
+        .success();
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    b: qpvuntsm e8849ae1 (empty) (no description set)
+    b@origin: yostqsxw bc83465a b
+    [EOF]
+    ");
+    let get_commit_ids = || {
+        work_dir
+            .run_jj(["log", "-r=all()", "--no-graph", r#"-Tcommit_id ++ "\n""#])
+            .success()
+            .stdout
+            .into_raw()
+    };
+    let commit_ids = get_commit_ids();
+
+    // Restoring just the local bookmarks moves `b` back, but neither the commits
+    // nor the remote-tracking bookmark are affected
+    work_dir
+        .run_jj(["op", "restore", "--what", "bookmarks", &fetch_operation_id])
+        .success();
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    b: yostqsxw bc83465a b
+    b@origin: yostqsxw bc83465a b
+    [EOF]
+    ");
+    assert_eq!(get_commit_ids(), commit_ids);
+}
+
 #[test]
 fn test_git_fetch_remove_fetch() {
     let test_env = TestEnvironment::default();
@@ -1484,6 +2289,63 @@ fn test_git_fetch_rename_fetch() {
     ");
 }
 
+#[test]
+fn test_git_fetch_rename_update_tracking() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = true");
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    add_git_remote(&test_env, &work_dir, "origin");
+    work_dir.run_jj(["git", "fetch"]).success();
+
+    // The remote bookmarks are left under the old remote name
+    work_dir
+        .run_jj([
+            "git",
+            "remote",
+            "rename",
+            "--update-tracking=false",
+            "origin",
+            "upstream",
+        ])
+        .success();
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    origin: qmyrypzk ab8b299e message
+      @origin: qmyrypzk ab8b299e message
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["git", "fetch", "--remote", "upstream"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: origin@upstream [new] tracked
+    [EOF]
+    ");
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    origin: qmyrypzk ab8b299e message
+      @origin: qmyrypzk ab8b299e message
+      @upstream: qmyrypzk ab8b299e message
+    [EOF]
+    ");
+
+    // The remote bookmarks follow the renamed remote
+    work_dir
+        .run_jj([
+            "git",
+            "remote",
+            "rename",
+            "--update-tracking=true",
+            "upstream",
+            "other",
+        ])
+        .success();
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    origin: qmyrypzk ab8b299e message
+      @origin: qmyrypzk ab8b299e message
+      @other: qmyrypzk ab8b299e message
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_git_fetch_removed_bookmark() {
     let test_env = TestEnvironment::default();
@@ -1728,6 +2590,189 @@ fn test_git_fetch_remote_only_bookmark() {
     ");
 }
 
+#[test]
+fn test_git_fetch_set_upstream() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = false");
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    let git_repo_path = test_env.env_root().join("git-repo");
+    let git_repo = git::init(git_repo_path);
+    work_dir
+        .run_jj(["git", "remote", "add", "origin", "../git-repo"])
+        .success();
+    let commit_result = git::add_commit(
+        &git_repo,
+        "refs/heads/feature1",
+        "file",
+        b"content",
+        "message",
+        &[],
+    );
+    for ref_name in ["refs/heads/feature2", "refs/heads/feature3"] {
+        git::write_commit(&git_repo, ref_name, commit_result.tree_id, "message", &[]);
+    }
+
+    // An existing local bookmark isn't affected
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "feature3"])
+        .success();
+
+    let output = work_dir.run_jj(["git", "fetch", "--set-upstream"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: feature1@origin [new] tracked
+    bookmark: feature2@origin [new] tracked
+    bookmark: feature3@origin [new] untracked
+    [EOF]
+    ");
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    feature1: qomsplrm ebeb70d8 message
+      @origin: qomsplrm ebeb70d8 message
+    feature2: qomsplrm ebeb70d8 message
+      @origin: qomsplrm ebeb70d8 message
+    feature3: qpvuntsm e8849ae1 (empty) (no description set)
+    feature3@origin: qomsplrm ebeb70d8 message
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_git_fetch_tags_and_no_tags() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = true");
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    let git_repo_path = test_env.env_root().join("git-repo");
+    let git_repo = git::init(git_repo_path);
+    work_dir
+        .run_jj(["git", "remote", "add", "origin", "../git-repo"])
+        .success();
+    let commit_result = git::add_commit(
+        &git_repo,
+        "refs/heads/feature1",
+        "file",
+        b"content",
+        "message",
+        &[],
+    );
+    let tagged_commit_id = git::write_commit(
+        &git_repo,
+        "refs/heads/feature2",
+        commit_result.tree_id,
+        "tagged",
+        &[],
+    );
+    git::write_annotated_tag(&git_repo, "v1", tagged_commit_id, "release");
+    let get_tag_output =
+        |work_dir: &TestWorkDir| work_dir.run_jj(["tag", "list", "-T", r#"name ++ "\n""#]);
+
+    // The tag points to a commit that isn't fetched
+    let output = work_dir.run_jj(["git", "fetch", "--branch=feature1"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: feature1@origin [new] tracked
+    [EOF]
+    ");
+
+    // The tagged commit is fetched, but the tag isn't
+    let output = work_dir.run_jj(["git", "fetch", "--branch=feature2", "--no-tags"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: feature2@origin [new] tracked
+    [EOF]
+    ");
+    insta::assert_snapshot!(get_tag_output(&work_dir), @"");
+
+    // All tags are fetched
+    let output = work_dir.run_jj(["git", "fetch", "--tags"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    tag: v1@git [new] 
+    [EOF]
+    ");
+    insta::assert_snapshot!(get_tag_output(&work_dir), @r"
+    v1
+    [EOF]
+    ");
+
+    // The flags are mutually exclusive
+    let output = work_dir.run_jj(["git", "fetch", "--tags", "--no-tags"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    error: the argument '--tags' cannot be used with '--no-tags'
+
+    Usage: jj git fetch --tags
+
+    For more information, try '--help'.
+    [EOF]
+    [exit status: 2]
+    ");
+}
+
+#[test]
+fn test_git_fetch_jobs() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = true");
+    let git_repo_path = test_env.env_root().join("git-repo");
+    let git_repo = git::init(git_repo_path);
+    let commit_result = git::add_commit(
+        &git_repo,
+        "refs/heads/feature1",
+        "file",
+        b"content",
+        "message",
+        &[],
+    );
+    git::write_commit(
+        &git_repo,
+        "refs/heads/feature2",
+        commit_result.tree_id,
+        "another",
+        &[commit_result.commit_id],
+    );
+
+    let fetch_into = |name: &str, extra_args: &[&str]| {
+        test_env.run_jj_in(".", ["git", "init", name]).success();
+        let work_dir = test_env.work_dir(name);
+        work_dir
+            .run_jj(["git", "remote", "add", "origin", "../git-repo"])
+            .success();
+        work_dir
+            .run_jj_with(|cmd| cmd.args(["git", "fetch"]).args(extra_args))
+            .success();
+        let template = r#"
+            name ++ if(remote, "@" ++ remote) ++ ": "
+            ++ normal_target.description().first_line() ++ "\n"
+        "#;
+        let bookmarks = work_dir.run_jj(["bookmark", "list", "--all-remotes", "-T", template]);
+        // The working-copy commits differ by timestamp, so only the fetched
+        // commits are compared
+        let log = work_dir.run_jj([
+            "log",
+            "-r",
+            "all() ~ @",
+            "-T",
+            "commit_id ++ ' ' ++ description",
+        ]);
+        (bookmarks, log)
+    };
+
+    let (default_bookmarks, default_log) = fetch_into("default", &[]);
+    let (jobs_bookmarks, jobs_log) = fetch_into("jobs", &["--jobs", "2"]);
+    insta::assert_snapshot!(jobs_bookmarks, @r"
+    feature1: message
+    feature1@origin: message
+    feature2: another
+    feature2@origin: another
+    [EOF]
+    ");
+    assert_eq!(jobs_bookmarks.stdout.raw(), default_bookmarks.stdout.raw());
+    assert_eq!(jobs_log.stdout.raw(), default_log.stdout.raw());
+}
+
 #[test]
 fn test_git_fetch_preserve_commits_across_repos() {
     let test_env = TestEnvironment::default();
@@ -150,6 +150,111 @@ fn test_git_export_undo() {
     ");
 }
 
+#[test]
+fn test_git_export_some_branches() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    let git_repo = git::open(work_dir.root().join(".jj/repo/store/git"));
+
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "main", "feature"])
+        .success();
+
+    // Only the matching bookmarks are exported
+    let output = work_dir.run_jj(["git", "export", "--branch=main", "--branch=missing"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Warning: No branch matching `missing` found
+    Exported bookmarks: main
+    Skipped bookmarks: feature
+    [EOF]
+    ");
+    insta::assert_debug_snapshot!(get_git_repo_refs(&git_repo), @r#"
+    [
+        (
+            "refs/heads/main",
+            CommitId(
+                "e8849ae12c709f2321908879bc724fdb2ab8a781",
+            ),
+        ),
+    ]
+    "#);
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    feature: qpvuntsm e8849ae1 (empty) (no description set)
+    main: qpvuntsm e8849ae1 (empty) (no description set)
+      @git: qpvuntsm e8849ae1 (empty) (no description set)
+    [EOF]
+    ");
+
+    // Nothing to export
+    let output = work_dir.run_jj(["git", "export", "--branch=main"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Skipped bookmarks: feature
+    Nothing changed.
+    [EOF]
+    ");
+
+    // The other bookmarks are still exportable
+    let output = work_dir.run_jj(["git", "export"]);
+    insta::assert_snapshot!(output, @"");
+    insta::assert_debug_snapshot!(get_git_repo_refs(&git_repo), @r#"
+    [
+        (
+            "refs/heads/feature",
+            CommitId(
+                "e8849ae12c709f2321908879bc724fdb2ab8a781",
+            ),
+        ),
+        (
+            "refs/heads/main",
+            CommitId(
+                "e8849ae12c709f2321908879bc724fdb2ab8a781",
+            ),
+        ),
+    ]
+    "#);
+}
+
+#[test]
+fn test_git_export_some_branches_colocated() {
+    let test_env = TestEnvironment::default();
+    test_env
+        .run_jj_in(".", ["git", "init", "--colocate", "repo"])
+        .success();
+    let work_dir = test_env.work_dir("repo");
+    let git_repo = git::open(work_dir.root());
+
+    // All bookmarks are exported automatically in colocated workspace, so
+    // --branch doesn't limit what ends up in the Git repo.
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "main", "feature"])
+        .success();
+    let output = work_dir.run_jj(["git", "export", "--branch=main"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Nothing changed.
+    [EOF]
+    ");
+    insta::assert_debug_snapshot!(get_git_repo_refs(&git_repo), @r#"
+    [
+        (
+            "refs/heads/feature",
+            CommitId(
+                "e8849ae12c709f2321908879bc724fdb2ab8a781",
+            ),
+        ),
+        (
+            "refs/heads/main",
+            CommitId(
+                "e8849ae12c709f2321908879bc724fdb2ab8a781",
+            ),
+        ),
+    ]
+    "#);
+}
+
 #[test]
 fn test_git_import_undo() {
     let test_env = TestEnvironment::default();
@@ -211,6 +316,153 @@ fn test_git_import_undo() {
     ");
 }
 
+#[test]
+fn test_git_import_some_branches() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    let git_repo = git::open(work_dir.root().join(".jj/repo/store/git"));
+
+    let commit_id = work_dir
+        .run_jj(&["log", "-Tcommit_id", "--no-graph", "-r@"])
+        .success()
+        .stdout
+        .into_raw();
+    let commit_id = gix::ObjectId::from_hex(commit_id.as_bytes()).unwrap();
+    for name in [
+        "refs/heads/release/1",
+        "refs/heads/release/2",
+        "refs/heads/feature",
+        "refs/tags/v1",
+    ] {
+        git_repo
+            .reference(
+                name,
+                commit_id,
+                gix::refs::transaction::PreviousValue::Any,
+                "",
+            )
+            .unwrap();
+    }
+
+    // Only the matching branches are imported
+    let output = work_dir.run_jj([
+        "git",
+        "import",
+        "--branch=glob:release/*",
+        "--branch=missing",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: release/1@git [new] tracked
+    bookmark: release/2@git [new] tracked
+    Warning: No branch matching `missing` found in the Git repo
+    [EOF]
+    ");
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    release/1: qpvuntsm e8849ae1 (empty) (no description set)
+      @git: qpvuntsm e8849ae1 (empty) (no description set)
+    release/2: qpvuntsm e8849ae1 (empty) (no description set)
+      @git: qpvuntsm e8849ae1 (empty) (no description set)
+    [EOF]
+    ");
+
+    // Nothing to import
+    let output = work_dir.run_jj(["git", "import", "--branch=glob:release/*"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Nothing changed.
+    [EOF]
+    ");
+
+    // The other refs are still importable
+    let output = work_dir.run_jj(["git", "import"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: feature@git [new] tracked
+    tag: v1@git [new] 
+    Warning: The working-copy commit in workspace 'default' became immutable, so a new commit has been created on top of it.
+    Working copy  (@) now at: royxmykx e7d0d5fd (empty) (no description set)
+    Parent commit (@-)      : qpvuntsm e8849ae1 feature release/1 release/2 | (empty) (no description set)
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_git_import_dry_run() {
+    let test_env = TestEnvironment::default();
+    test_env
+        .run_jj_in(".", ["git", "init", "--colocate", "repo"])
+        .success();
+    let work_dir = test_env.work_dir("repo");
+    let git_repo = git::open(work_dir.root());
+
+    work_dir
+        .run_jj(["bookmark", "set", "-r@", "foo", "old"])
+        .success();
+    work_dir.run_jj(["new", "--no-edit", "root()"]).success();
+    let other_commit_id = work_dir
+        .run_jj(&["log", "-Tcommit_id", "--no-graph", "-rvisible_heads() ~ @"])
+        .success()
+        .stdout
+        .into_raw();
+    let other_commit_id = gix::ObjectId::from_hex(other_commit_id.as_bytes()).unwrap();
+    // The remote named "git" is reserved, so its refs can't be imported
+    for name in ["refs/heads/foo", "refs/heads/new", "refs/remotes/git/main"] {
+        git_repo
+            .reference(
+                name,
+                other_commit_id,
+                gix::refs::transaction::PreviousValue::Any,
+                "",
+            )
+            .unwrap();
+    }
+    git_repo
+        .find_reference("refs/heads/old")
+        .unwrap()
+        .delete()
+        .unwrap();
+
+    // --ignore-working-copy prevents the implicit import in colocated workspace
+    let output = work_dir.run_jj(["git", "import", "--dry-run", "--ignore-working-copy"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    bookmark: foo@git [updated] tracked
+    bookmark: new@git [new] tracked
+    bookmark: old@git [deleted] untracked
+    Would abandon 1 commits that are no longer reachable.
+    Warning: Failed to import some Git refs:
+      refs/remotes/git/main
+    Hint: Git remote named 'git' is reserved for local Git repository.
+    Use `jj git remote rename` to give a different name.
+    Dry-run requested, not importing.
+    [EOF]
+    ");
+
+    // No operation is recorded and the bookmarks are unchanged
+    let output = work_dir.run_jj([
+        "op",
+        "log",
+        "--ignore-working-copy",
+        "--no-graph",
+        "-n1",
+        "-Tdescription",
+    ]);
+    insta::assert_snapshot!(output, @"new empty commit[EOF]");
+    let output = work_dir.run_jj([
+        "bookmark",
+        "list",
+        "--ignore-working-copy",
+        r#"-Tname ++ "\n""#,
+    ]);
+    insta::assert_snapshot!(output, @r"
+    foo
+    old
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_git_import_move_export_with_default_undo() {
     let test_env = TestEnvironment::default();
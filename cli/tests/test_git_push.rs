@@ -83,6 +83,65 @@ fn test_git_push_nothing() {
     ");
 }
 
+#[test]
+fn test_git_push_dry_run_changes_nothing() {
+    let test_env = TestEnvironment::default();
+    set_up(&test_env);
+    let work_dir = test_env.work_dir("local");
+    let origin_dir = test_env.work_dir("origin");
+    let origin_git_repo = git::open(git_repo_dir_for_jj_repo(&origin_dir));
+    let get_remote_ref_ids = || {
+        ["bookmark1", "bookmark2", "bookmark3"].map(|name| {
+            origin_git_repo
+                .try_find_reference(&format!("refs/heads/{name}"))
+                .unwrap()
+                .map(|r| r.id().detach())
+        })
+    };
+    work_dir
+        .run_jj([
+            "bookmark",
+            "set",
+            "bookmark1",
+            "-rbookmark2",
+            "--allow-backwards",
+        ])
+        .success();
+    work_dir
+        .run_jj(["bookmark", "delete", "bookmark2"])
+        .success();
+    work_dir
+        .run_jj(["bookmark", "create", "-rbookmark1", "bookmark3"])
+        .success();
+    let op_log_before = work_dir.run_jj(["op", "log", "-Tid"]).success();
+    let remote_ref_ids_before = get_remote_ref_ids();
+
+    let output = work_dir.run_jj(["git", "push", "--all", "--deleted", "--dry-run"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Changes to push to origin:
+      Move sideways bookmark bookmark1 from 9b2e76de3920 to 38a204733702
+      Delete bookmark bookmark2 from 38a204733702
+      Add bookmark bookmark3 to 38a204733702
+    Dry-run requested, not pushing.
+    [EOF]
+    ");
+
+    // No operation was recorded, and the remote is untouched
+    let op_log_after = work_dir.run_jj(["op", "log", "-Tid"]).success();
+    assert_eq!(op_log_before.stdout.raw(), op_log_after.stdout.raw());
+    assert_eq!(remote_ref_ids_before, get_remote_ref_ids());
+    assert!(remote_ref_ids_before[2].is_none());
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    bookmark1: zsuskuln 38a20473 (empty) description 2
+      @origin (ahead by 1 commits, behind by 1 commits): qpvuntsm 9b2e76de (empty) description 1
+    bookmark2 (deleted)
+      @origin: zsuskuln 38a20473 (empty) description 2
+    bookmark3: zsuskuln 38a20473 (empty) description 2
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_git_push_current_bookmark() {
     let test_env = TestEnvironment::default();
@@ -306,6 +365,20 @@ fn test_git_push_forward_unexpectedly_moved() {
         .run_jj(["bookmark", "set", "bookmark1", "-r@"])
         .success();
 
+    // A dry run should fail with the same error as a real push
+    let output = work_dir.run_jj(["git", "push", "--dry-run"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Changes to push to origin:
+      Move forward bookmark bookmark1 from 9b2e76de3920 to 624f94a35f00
+    Error: Failed to push some bookmarks
+    Hint: The following references unexpectedly moved on the remote:
+      refs/heads/bookmark1 (reason: stale info)
+    Hint: Try fetching from the remote, then make the bookmark point to where you want it to be, and push again.
+    [EOF]
+    [exit status: 1]
+    ");
+
     // Pushing should fail
     let output = work_dir.run_jj(["git", "push"]);
     insta::assert_snapshot!(output, @r"
@@ -794,6 +867,81 @@ fn test_git_push_multiple() {
     ");
 }
 
+#[test]
+fn test_git_push_bookmarks_by_glob() {
+    let test_env = TestEnvironment::default();
+    set_up(&test_env);
+    let work_dir = test_env.work_dir("local");
+    let origin_dir = test_env.work_dir("origin");
+    let origin_git_repo = git::open(git_repo_dir_for_jj_repo(&origin_dir));
+    let get_remote_ref_id = |name: &str| {
+        origin_git_repo
+            .try_find_reference(&format!("refs/heads/{name}"))
+            .unwrap()
+            .map(|r| r.id().to_string())
+    };
+    work_dir
+        .run_jj([
+            "bookmark",
+            "create",
+            "-rbookmark1",
+            "feature/a",
+            "feature/c",
+        ])
+        .success();
+    work_dir
+        .run_jj(["bookmark", "create", "-rbookmark2", "feature/b", "other"])
+        .success();
+
+    // All bookmarks matching the pattern are pushed with one argument
+    let output = work_dir.run_jj(["git", "push", "--allow-new", "-b=glob:feature/*"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Changes to push to origin:
+      Add bookmark feature/a to 9b2e76de3920
+      Add bookmark feature/b to 38a204733702
+      Add bookmark feature/c to 9b2e76de3920
+    [EOF]
+    ");
+    assert_eq!(
+        get_remote_ref_id("feature/a"),
+        get_remote_ref_id("bookmark1")
+    );
+    assert_eq!(
+        get_remote_ref_id("feature/b"),
+        get_remote_ref_id("bookmark2")
+    );
+    assert_eq!(
+        get_remote_ref_id("feature/c"),
+        get_remote_ref_id("bookmark1")
+    );
+    assert_eq!(get_remote_ref_id("other"), None);
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    bookmark1: qpvuntsm 9b2e76de (empty) description 1
+      @origin: qpvuntsm 9b2e76de (empty) description 1
+    bookmark2: zsuskuln 38a20473 (empty) description 2
+      @origin: zsuskuln 38a20473 (empty) description 2
+    feature/a: qpvuntsm 9b2e76de (empty) description 1
+      @origin: qpvuntsm 9b2e76de (empty) description 1
+    feature/b: zsuskuln 38a20473 (empty) description 2
+      @origin: zsuskuln 38a20473 (empty) description 2
+    feature/c: qpvuntsm 9b2e76de (empty) description 1
+      @origin: qpvuntsm 9b2e76de (empty) description 1
+    other: zsuskuln 38a20473 (empty) description 2
+    [EOF]
+    ");
+
+    // A pattern that matches nothing is an error, like in `jj bookmark`
+    // commands, even if other patterns match
+    let output = work_dir.run_jj(["git", "push", "-b=glob:feature/*", "-b=glob:fix/*"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: No matching bookmarks for patterns: fix/*
+    [EOF]
+    [exit status: 1]
+    ");
+}
+
 #[test]
 fn test_git_push_changes() {
     let test_env = TestEnvironment::default();
@@ -927,6 +1075,266 @@ fn test_git_push_changes() {
     ");
 }
 
+#[test]
+fn test_git_push_changes_with_colliding_names() {
+    let test_env = TestEnvironment::default();
+    set_up(&test_env);
+    let work_dir = test_env.work_dir("local");
+    // Two unnamed sibling commits for which the template generates the same name
+    work_dir
+        .run_jj(["new", "root()", "-m", "wip: first"])
+        .success();
+    let first_change_id = work_dir
+        .run_jj(["log", "--no-graph", "-r@", "-Tchange_id"])
+        .success()
+        .stdout
+        .into_raw();
+    work_dir
+        .run_jj(["new", "root()", "-m", "wip: second"])
+        .success();
+
+    let template_config = format!(
+        "--config=templates.git_push_bookmark={}",
+        to_toml_value("'push-' ++ description.first_line().substr(0, 3)")
+    );
+    let push_both = || {
+        work_dir
+            .run_jj([
+                "git",
+                "push",
+                &template_config,
+                "--change",
+                &first_change_id,
+                "--change=@",
+            ])
+            .normalize_stderr_with(|stderr| {
+                // Strip the change ids and commit hashes
+                stderr
+                    .lines()
+                    .map(|line| {
+                        let line = line.split(" for revision ").next().unwrap();
+                        let line = if line.starts_with("  Add bookmark ") {
+                            line.split(" to ").next().unwrap()
+                        } else {
+                            line
+                        };
+                        format!("{line}\n")
+                    })
+                    .collect()
+            })
+    };
+    let output = push_both();
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Creating bookmark push-wip
+    Creating bookmark push-wip-2
+    Changes to push to origin:
+      Add bookmark push-wip
+      Add bookmark push-wip-2
+    [EOF]
+    ");
+    let output = work_dir.run_jj([
+        "bookmark",
+        "list",
+        "--all-remotes",
+        "-T",
+        r#"name ++ if(remote, "@" ++ remote) ++ ": " ++ normal_target.description()"#,
+        "glob:push-*",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    push-wip: wip: first
+    push-wip@origin: wip: first
+    push-wip-2: wip: second
+    push-wip-2@origin: wip: second
+    [EOF]
+    ");
+
+    // The generated names are stable when pushing again
+    let output = push_both();
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Bookmark push-wip@origin already matches push-wip
+    Bookmark push-wip-2@origin already matches push-wip-2
+    Nothing changed.
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_git_push_named_with_remote_name() {
+    let test_env = TestEnvironment::default();
+    set_up(&test_env);
+    let work_dir = test_env.work_dir("local");
+    let origin_dir = test_env.work_dir("origin");
+    let origin_git_repo = git::open(git_repo_dir_for_jj_repo(&origin_dir));
+    let get_remote_ref_id = |name: &str| {
+        origin_git_repo
+            .try_find_reference(&format!("refs/heads/{name}"))
+            .unwrap()
+            .map(|r| r.id().to_string())
+    };
+
+    // Push an existing bookmark under a different name
+    let output = work_dir.run_jj(["git", "push", "--named", "bookmark1:team/bookmark1"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Changes to push to origin:
+      Add bookmark team/bookmark1 to 9b2e76de3920
+    [EOF]
+    ");
+    assert_eq!(
+        get_remote_ref_id("team/bookmark1"),
+        get_remote_ref_id("bookmark1")
+    );
+    // The local bookmark isn't renamed, and the new remote bookmark isn't
+    // tracked
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    bookmark1: qpvuntsm 9b2e76de (empty) description 1
+      @origin: qpvuntsm 9b2e76de (empty) description 1
+    bookmark2: zsuskuln 38a20473 (empty) description 2
+      @origin: zsuskuln 38a20473 (empty) description 2
+    team/bookmark1@origin: qpvuntsm 9b2e76de (empty) description 1
+    [EOF]
+    ");
+
+    // Pushing again is a no-op
+    let output = work_dir.run_jj(["git", "push", "--named", "bookmark1:team/bookmark1"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Bookmark team/bookmark1@origin already matches bookmark1
+    Nothing changed.
+    [EOF]
+    ");
+
+    // The remote bookmark is moved as if it were tracked by the local bookmark
+    work_dir
+        .run_jj([
+            "bookmark",
+            "set",
+            "bookmark1",
+            "-rbookmark2",
+            "--allow-backwards",
+        ])
+        .success();
+    let output = work_dir.run_jj(["git", "push", "--named", "bookmark1:team/bookmark1"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Changes to push to origin:
+      Move sideways bookmark team/bookmark1 from 9b2e76de3920 to 38a204733702
+    [EOF]
+    ");
+    assert_eq!(
+        get_remote_ref_id("team/bookmark1"),
+        get_remote_ref_id("bookmark2")
+    );
+
+    // The local bookmark must exist
+    let output = work_dir.run_jj(["git", "push", "--named", "bookmark3:team/bookmark3"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: No such bookmark: bookmark3
+    [EOF]
+    [exit status: 1]
+    ");
+}
+
+#[test]
+fn test_git_push_bookmark_renamed_with_remote_too() {
+    let test_env = TestEnvironment::default();
+    set_up(&test_env);
+    let work_dir = test_env.work_dir("local");
+    let origin_dir = test_env.work_dir("origin");
+    let origin_git_repo = git::open(git_repo_dir_for_jj_repo(&origin_dir));
+    let get_remote_ref_id = |name: &str| {
+        origin_git_repo
+            .try_find_reference(&format!("refs/heads/{name}"))
+            .unwrap()
+            .map(|r| r.id().to_string())
+    };
+    let bookmark1_id = get_remote_ref_id("bookmark1");
+
+    // The remote bookmark of the old name must be tracked
+    let output = work_dir.run_jj([
+        "bookmark",
+        "rename",
+        "bookmark1",
+        "renamed",
+        "--remote-too=foo",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Bookmark bookmark1 is not tracked on remote foo
+    [EOF]
+    [exit status: 1]
+    ");
+
+    work_dir
+        .run_jj([
+            "bookmark",
+            "rename",
+            "bookmark1",
+            "renamed",
+            "--remote-too=origin",
+        ])
+        .success();
+    let output = work_dir.run_jj(["git", "push"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Warning: No bookmarks found in the default push revset: remote_bookmarks(remote=origin)..@
+    Changes to push to origin:
+      Delete bookmark bookmark1 from 9b2e76de3920
+      Add bookmark renamed to 9b2e76de3920
+    [EOF]
+    ");
+    assert_eq!(get_remote_ref_id("bookmark1"), None);
+    assert_eq!(get_remote_ref_id("renamed"), bookmark1_id);
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    bookmark2: zsuskuln 38a20473 (empty) description 2
+      @origin: zsuskuln 38a20473 (empty) description 2
+    renamed: qpvuntsm 9b2e76de (empty) description 1
+      @origin: qpvuntsm 9b2e76de (empty) description 1
+    [EOF]
+    ");
+
+    // The pending rename has been consumed
+    let output = work_dir.run_jj(["git", "push"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Warning: No bookmarks found in the default push revset: remote_bookmarks(remote=origin)..@
+    Nothing changed.
+    [EOF]
+    ");
+
+    // The old name is also deleted if the renamed bookmark is pushed explicitly
+    let bookmark2_id = get_remote_ref_id("bookmark2");
+    work_dir
+        .run_jj([
+            "bookmark",
+            "rename",
+            "bookmark2",
+            "renamed2",
+            "--remote-too=origin",
+        ])
+        .success();
+    let output = work_dir.run_jj(["git", "push", "-b", "renamed2"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Changes to push to origin:
+      Add bookmark renamed2 to 38a204733702
+      Delete bookmark bookmark2 from 38a204733702
+    [EOF]
+    ");
+    assert_eq!(get_remote_ref_id("bookmark2"), None);
+    assert_eq!(get_remote_ref_id("renamed2"), bookmark2_id);
+    let output = work_dir.run_jj(["git", "push", "-b", "renamed2"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Bookmark renamed2@origin already matches renamed2
+    Nothing changed.
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_git_push_changes_with_name() {
     let test_env = TestEnvironment::default();
@@ -1460,6 +1868,231 @@ fn test_git_push_no_description() {
         .success();
 }
 
+#[test]
+fn test_git_push_require_clean_conflict() {
+    let test_env = TestEnvironment::default();
+    set_up(&test_env);
+    let work_dir = test_env.work_dir("local");
+    work_dir.write_file("file", "first");
+    work_dir.run_jj(["commit", "-m", "first"]).success();
+    work_dir.write_file("file", "second");
+    work_dir.run_jj(["commit", "-m", "second"]).success();
+    work_dir.write_file("file", "third");
+    work_dir
+        .run_jj(["rebase", "-r", "@", "-o", "@--"])
+        .success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "my-bookmark"])
+        .success();
+    work_dir.run_jj(["describe", "-m", "third"]).success();
+    let output = work_dir.run_jj(["git", "push", "--all", "--require-clean"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Won't push 1 commit with conflicts or empty descriptions
+    Hint: Rejected commits:
+      yostqsxw 654e715b my-bookmark | (conflict) third
+    [EOF]
+    [exit status: 1]
+    ");
+}
+
+#[test]
+fn test_git_push_require_clean_no_description() {
+    let test_env = TestEnvironment::default();
+    set_up(&test_env);
+    let work_dir = test_env.work_dir("local");
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "my-bookmark"])
+        .success();
+    work_dir.run_jj(["describe", "-m="]).success();
+    let output = work_dir.run_jj([
+        "git",
+        "push",
+        "--allow-new",
+        "--bookmark=my-bookmark",
+        "--require-clean",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Won't push 1 commit with conflicts or empty descriptions
+    Hint: Rejected commits:
+      yqosqzyt 8d23abdd my-bookmark | (empty) (no description set)
+    [EOF]
+    [exit status: 1]
+    ");
+
+    // Cannot be combined with --allow-empty-description
+    let output = work_dir.run_jj([
+        "git",
+        "push",
+        "--allow-new",
+        "--bookmark=my-bookmark",
+        "--require-clean",
+        "--allow-empty-description",
+    ]);
+    assert!(output.stderr.raw().contains("cannot be used with"));
+
+    // Once described, the commit can be pushed
+    work_dir.run_jj(["describe", "-m=foo"]).success();
+    work_dir
+        .run_jj([
+            "git",
+            "push",
+            "--allow-new",
+            "--bookmark=my-bookmark",
+            "--require-clean",
+        ])
+        .success();
+}
+
+#[test]
+fn test_git_push_force_with_lease() {
+    let test_env = TestEnvironment::default();
+    set_up(&test_env);
+    let work_dir = test_env.work_dir("local");
+    work_dir.run_jj(["new", "bookmark2", "-m", "foo"]).success();
+    work_dir
+        .run_jj(["bookmark", "set", "bookmark2", "-r@"])
+        .success();
+
+    // The remote bookmark isn't where the lease expects it
+    let output = work_dir.run_jj([
+        "git",
+        "push",
+        "-b=bookmark2",
+        "--force-with-lease=bookmark2:bookmark1@origin",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Refusing to push bookmark bookmark2: bookmark2@origin was last fetched at 38a204733702, not at the expected 9b2e76de3920
+    Hint: Run `jj git fetch` to see where the remote bookmark is now.
+    [EOF]
+    [exit status: 1]
+    ");
+
+    // A lease on a bookmark that isn't pushed
+    let output = work_dir.run_jj([
+        "git",
+        "push",
+        "-b=bookmark2",
+        "--force-with-lease=bookmark1:bookmark1@origin",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Bookmark bookmark1 is not being pushed to origin
+    [EOF]
+    [exit status: 1]
+    ");
+
+    // Without a value, the last-fetched position is expected
+    let output = work_dir.run_jj([
+        "git",
+        "push",
+        "-b=bookmark2",
+        "--force-with-lease",
+        "--dry-run",
+    ]);
+    assert!(
+        output
+            .stderr
+            .raw()
+            .contains("Dry-run requested, not pushing.")
+    );
+
+    // The lease holds
+    work_dir
+        .run_jj([
+            "git",
+            "push",
+            "-b=bookmark2",
+            "--force-with-lease=bookmark2:38a20473",
+        ])
+        .success();
+    let output = work_dir.run_jj([
+        "log",
+        "--no-graph",
+        "-r=remote_bookmarks(bookmark2, origin)",
+        "-T=description",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    foo
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_bookmark_create_push() {
+    let test_env = TestEnvironment::default();
+    set_up(&test_env);
+    let work_dir = test_env.work_dir("local");
+    let origin_dir = test_env.work_dir("origin");
+    let origin_git_repo_path = git_repo_dir_for_jj_repo(&origin_dir);
+    let origin_git_repo = git::open(&origin_git_repo_path);
+
+    let output = work_dir.run_jj(["bookmark", "create", "feature", "-r", "bookmark1", "--push"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Created 1 bookmarks pointing to qpvuntsm 9b2e76de bookmark1 feature | (empty) description 1
+    Changes to push to origin:
+      Add bookmark feature to 9b2e76de3920
+    [EOF]
+    ");
+    insta::assert_snapshot!(get_bookmark_output(&work_dir), @r"
+    bookmark1: qpvuntsm 9b2e76de (empty) description 1
+      @origin: qpvuntsm 9b2e76de (empty) description 1
+    bookmark2: zsuskuln 38a20473 (empty) description 2
+      @origin: zsuskuln 38a20473 (empty) description 2
+    feature: qpvuntsm 9b2e76de (empty) description 1
+      @origin: qpvuntsm 9b2e76de (empty) description 1
+    [EOF]
+    ");
+    let feature_id = origin_git_repo
+        .find_reference("refs/heads/feature")
+        .unwrap()
+        .id()
+        .detach();
+    let bookmark1_id = origin_git_repo
+        .find_reference("refs/heads/bookmark1")
+        .unwrap()
+        .id()
+        .detach();
+    assert_eq!(feature_id, bookmark1_id);
+
+    // The remote must be specified if there are several
+    work_dir
+        .run_jj([
+            "git",
+            "remote",
+            "add",
+            "upstream",
+            origin_git_repo_path.to_str().unwrap(),
+        ])
+        .success();
+    let output = work_dir.run_jj(["bookmark", "create", "other", "-r", "bookmark1", "--push"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Cannot determine which remote to push the bookmarks to
+    Hint: Use `--push=REMOTE` to specify the remote.
+    [EOF]
+    [exit status: 1]
+    ");
+    let output = work_dir.run_jj([
+        "bookmark",
+        "create",
+        "other",
+        "-r",
+        "bookmark1",
+        "--push=upstream",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Created 1 bookmarks pointing to qpvuntsm 9b2e76de bookmark1 feature other | (empty) description 1
+    Changes to push to upstream:
+      Add bookmark other to 9b2e76de3920
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_git_push_no_description_in_immutable() {
     let test_env = TestEnvironment::default();
@@ -1539,6 +2172,68 @@ fn test_git_push_missing_author() {
     ");
 }
 
+#[test]
+fn test_git_push_foreign_authors() {
+    let test_env = TestEnvironment::default();
+    set_up(&test_env);
+    let work_dir = test_env.work_dir("local");
+    work_dir
+        .run_jj([
+            "new",
+            "root()",
+            "-m=by someone else",
+            "--config=user.email=someone@example.org",
+        ])
+        .success();
+    work_dir.run_jj(["new", "-m=by me"]).success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "my-bookmark"])
+        .success();
+
+    // No check by default
+    let output = work_dir.run_jj(["git", "push", "--allow-new", "--dry-run"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Changes to push to origin:
+      Add bookmark my-bookmark to ae389858fd6c
+    Dry-run requested, not pushing.
+    [EOF]
+    ");
+
+    let output = work_dir.run_jj([
+        "git",
+        "push",
+        "--allow-new",
+        "--dry-run",
+        "--warn-foreign-authors",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Warning: The following commits to push are authored by others:
+      vruxwmqv b5a02d30 (empty) by someone else
+    Changes to push to origin:
+      Add bookmark my-bookmark to ae389858fd6c
+    Dry-run requested, not pushing.
+    [EOF]
+    ");
+
+    let output = work_dir.run_jj([
+        "git",
+        "push",
+        "--allow-new",
+        "--dry-run",
+        "--reject-foreign-authors",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Won't push 1 commit authored by others
+    Hint: Rejected commits:
+      vruxwmqv b5a02d30 (empty) by someone else
+    [EOF]
+    [exit status: 1]
+    ");
+}
+
 #[test]
 fn test_git_push_missing_author_in_immutable() {
     let test_env = TestEnvironment::default();
@@ -1847,6 +2542,71 @@ fn test_git_push_deleted_untracked() {
     ");
 }
 
+#[test]
+fn test_git_push_tracked() {
+    let test_env = TestEnvironment::default();
+    set_up(&test_env);
+    let work_dir = test_env.work_dir("local");
+    work_dir
+        .run_jj(["new", "bookmark1", "-mmoved bookmark1"])
+        .success();
+    work_dir
+        .run_jj(["bookmark", "set", "bookmark1", "-r@"])
+        .success();
+    work_dir
+        .run_jj(["new", "bookmark2", "-mmoved bookmark2"])
+        .success();
+    work_dir
+        .run_jj(["bookmark", "set", "bookmark2", "-r@"])
+        .success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "untracked"])
+        .success();
+    let get_bookmarks = || {
+        work_dir.run_jj([
+            "bookmark",
+            "list",
+            "--all-remotes",
+            "-T",
+            r#"name ++ if(remote, "@" ++ remote) ++ ": "
+               ++ normal_target.description().first_line() ++ "\n""#,
+        ])
+    };
+    insta::assert_snapshot!(get_bookmarks(), @r"
+    bookmark1: moved bookmark1
+    bookmark1@origin: description 1
+    bookmark2: moved bookmark2
+    bookmark2@origin: description 2
+    untracked: moved bookmark2
+    [EOF]
+    ");
+
+    // Both tracked bookmarks are pushed, but not the untracked one
+    work_dir.run_jj(["git", "push", "--tracked"]).success();
+    insta::assert_snapshot!(get_bookmarks(), @r"
+    bookmark1: moved bookmark1
+    bookmark1@origin: moved bookmark1
+    bookmark2: moved bookmark2
+    bookmark2@origin: moved bookmark2
+    untracked: moved bookmark2
+    [EOF]
+    ");
+
+    // Bookmarks which are up to date are skipped
+    let output = work_dir.run_jj(["git", "push", "--tracked"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Nothing changed.
+    [EOF]
+    ");
+
+    // --tracked can't be combined with explicit bookmarks or changes
+    let output = work_dir.run_jj(["git", "push", "--tracked", "--bookmark=bookmark1"]);
+    assert_eq!(output.status.code(), Some(2));
+    let output = work_dir.run_jj(["git", "push", "--tracked", "--change=@"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
 #[test]
 fn test_git_push_tracked_vs_all() {
     let test_env = TestEnvironment::default();
@@ -86,6 +86,73 @@ fn test_git_remotes() {
     "#);
 }
 
+#[test]
+fn test_git_remote_list_json() {
+    let test_env = TestEnvironment::default();
+
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    work_dir
+        .run_jj(["git", "remote", "add", "foo", "http://example.com/repo/foo"])
+        .success();
+    work_dir
+        .run_jj(["git", "remote", "add", "bar", "http://example.com/repo/bar"])
+        .success();
+    work_dir
+        .run_jj([
+            "git",
+            "remote",
+            "set-url",
+            "--push",
+            "bar",
+            "ssh://example.com/repo/bar",
+        ])
+        .success();
+    // A remote named 'git' can only be added by Git
+    git::add_remote(
+        work_dir.root().join(".jj/repo/store/git"),
+        "git",
+        "http://example.com/repo/git",
+    );
+
+    let output = work_dir.run_jj(["git", "remote", "list", "--format=json"]);
+    insta::assert_snapshot!(output, @r#"
+    {"name":"bar","fetch_url":"http://example.com/repo/bar","push_url":"ssh://example.com/repo/bar"}
+    {"name":"foo","fetch_url":"http://example.com/repo/foo","push_url":"http://example.com/repo/foo"}
+    [EOF]
+    "#);
+    let output = work_dir.run_jj(["git", "remote", "list", "--format=json", "--include-git"]);
+    insta::assert_snapshot!(output, @r#"
+    {"name":"bar","fetch_url":"http://example.com/repo/bar","push_url":"ssh://example.com/repo/bar"}
+    {"name":"foo","fetch_url":"http://example.com/repo/foo","push_url":"http://example.com/repo/foo"}
+    {"name":"git","fetch_url":"http://example.com/repo/git","push_url":"http://example.com/repo/git"}
+    [EOF]
+    "#);
+
+    // The text output is unchanged
+    let output = work_dir.run_jj(["git", "remote", "list"]);
+    insta::assert_snapshot!(output, @r"
+    bar http://example.com/repo/bar
+    foo http://example.com/repo/foo
+    git http://example.com/repo/git
+    [EOF]
+    ");
+
+    // --include-git only applies to the JSON output
+    let output = work_dir.run_jj(["git", "remote", "list", "--include-git"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    error: the following required arguments were not provided:
+      --format <FORMAT>
+
+    Usage: jj git remote list --format <FORMAT> --include-git
+
+    For more information, try '--help'.
+    [EOF]
+    [exit status: 2]
+    ");
+}
+
 #[test]
 fn test_git_remote_add() {
     let test_env = TestEnvironment::default();
@@ -229,7 +296,11 @@ fn test_git_remote_set_url() {
         "foo",
         "http://example.com/repo/bar",
     ]);
-    insta::assert_snapshot!(output, @"");
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Changed URL of remote foo from http://example.com/repo/foo to http://example.com/repo/bar
+    [EOF]
+    ");
     let output = work_dir.run_jj(["git", "remote", "list"]);
     insta::assert_snapshot!(output, @r"
     foo http://example.com/repo/bar
@@ -246,6 +317,93 @@ fn test_git_remote_set_url() {
     "#);
 }
 
+#[test]
+fn test_git_remote_set_url_push() {
+    let test_env = TestEnvironment::default();
+
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    work_dir
+        .run_jj(["git", "remote", "add", "foo", "http://example.com/repo/foo"])
+        .success();
+    let output = work_dir.run_jj([
+        "git",
+        "remote",
+        "set-url",
+        "--push",
+        "bar",
+        "http://example.com/repo/bar",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: No git remote named 'bar'
+    [EOF]
+    [exit status: 1]
+    ");
+    let output = work_dir.run_jj([
+        "git",
+        "remote",
+        "set-url",
+        "--push",
+        "git",
+        "http://example.com/repo/git",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Git remote named 'git' is reserved for local Git repository
+    [EOF]
+    [exit status: 1]
+    ");
+
+    let output = work_dir.run_jj([
+        "git",
+        "remote",
+        "set-url",
+        "--push",
+        "foo",
+        "ssh://example.com/repo/foo",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Changed push URL of remote foo from http://example.com/repo/foo to ssh://example.com/repo/foo
+    [EOF]
+    ");
+    insta::assert_snapshot!(read_git_config(work_dir.root()), @r#"
+    [core]
+    	repositoryformatversion = 0
+    	bare = true
+    	logallrefupdates = false
+    [remote "foo"]
+    	url = http://example.com/repo/foo
+    	pushurl = ssh://example.com/repo/foo
+    	fetch = +refs/heads/*:refs/remotes/foo/*
+    "#);
+
+    // Changing the fetch URL keeps the push URL
+    let output = work_dir.run_jj([
+        "git",
+        "remote",
+        "set-url",
+        "foo",
+        "http://example.com/repo/bar",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Changed URL of remote foo from http://example.com/repo/foo to http://example.com/repo/bar
+    [EOF]
+    ");
+    insta::assert_snapshot!(read_git_config(work_dir.root()), @r#"
+    [core]
+    	repositoryformatversion = 0
+    	bare = true
+    	logallrefupdates = false
+    [remote "foo"]
+    	url = http://example.com/repo/bar
+    	pushurl = ssh://example.com/repo/foo
+    	fetch = +refs/heads/*:refs/remotes/foo/*
+    "#);
+}
+
 #[test]
 fn test_git_remote_relative_path() {
     let test_env = TestEnvironment::default();
@@ -606,7 +764,11 @@ fn test_git_remote_with_global_git_remote_config() {
         "origin",
         "https://example.com/repo/origin/2",
     ]);
-    insta::assert_snapshot!(output, @"");
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Changed URL of remote origin from http://example.com/repo/origin/1 to https://example.com/repo/origin/2
+    [EOF]
+    ");
 
     let output = work_dir.run_jj(["git", "remote", "list"]);
     insta::assert_snapshot!(output, @r"
@@ -628,3 +790,68 @@ fn test_git_remote_with_global_git_remote_config() {
     	fetch = +refs/heads/*:refs/remotes/origin/*
     "#);
 }
+
+#[test]
+fn test_git_remote_show() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    let origin_repo = git::init(test_env.env_root().join("origin"));
+    let commit_id = git::add_commit(
+        &origin_repo,
+        "refs/heads/main",
+        "file",
+        b"content",
+        "message",
+        &[],
+    )
+    .commit_id;
+    origin_repo
+        .reference(
+            "refs/heads/feature",
+            commit_id,
+            gix::refs::transaction::PreviousValue::Any,
+            "",
+        )
+        .unwrap();
+    work_dir
+        .run_jj(["git", "remote", "add", "origin", "../origin"])
+        .success();
+    work_dir.run_jj(["git", "fetch"]).success();
+    work_dir
+        .run_jj(["bookmark", "track", "main@origin"])
+        .success();
+    work_dir.run_jj(["new", "main"]).success();
+    work_dir
+        .run_jj(["bookmark", "move", "main", "--to=@"])
+        .success();
+
+    let output = work_dir.run_jj(["git", "remote", "show", "origin"]);
+    insta::assert_snapshot!(output, @r"
+    Name: origin
+    Fetch URL: $TEST_ENV/origin
+    Push URL: $TEST_ENV/origin
+    Bookmarks:
+      feature: untracked
+      main: tracked (behind by 1 commits)
+    [EOF]
+    ");
+
+    let output = work_dir.run_jj(["git", "remote", "show", "nonexistent"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: No git remote named 'nonexistent'
+    [EOF]
+    [exit status: 1]
+    ");
+
+    let output = work_dir.run_jj(["git", "remote", "show", "git"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Git remote named 'git' is reserved for local Git repository
+    Hint: Run `jj git remote rename` to give a different name.
+    [EOF]
+    [exit status: 1]
+    ");
+}
@@ -1060,6 +1060,8 @@ fn test_help() {
           --ignore-working-copy          Don't snapshot the working copy, and don't update it
           --ignore-immutable             Allow rewriting immutable commits
           --at-operation <AT_OPERATION>  Operation to load the repo at [aliases: --at-op]
+          --op-tag <KEY=VALUE>           Attach a tag to the operation created by this command (can be
+                                         repeated)
           --debug                        Enable debug logging
           --color <WHEN>                 When to colorize output [possible values: always, never, debug,
                                          auto]
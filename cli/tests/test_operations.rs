@@ -233,6 +233,194 @@ fn test_op_log_limit() {
     ");
 }
 
+#[test]
+fn test_op_log_show_index_events() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "a"])
+        .success();
+    let output = work_dir
+        .run_jj(["op", "log", "-n1", "--no-graph", "-Tid"])
+        .success();
+    let op_id = output.stdout.raw().to_owned();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "b"])
+        .success();
+
+    // Delete the index link of the middle operation, and load the repo at it
+    let index_dir = work_dir.root().join(".jj").join("repo").join("index");
+    std::fs::remove_file(index_dir.join("op_links").join(&op_id)).unwrap();
+    std::fs::remove_file(index_dir.join("operations").join(&op_id)).unwrap();
+    work_dir
+        .run_jj(["--at-op", &op_id, "log", "-r@", "-Tdescription"])
+        .success();
+
+    let output = work_dir.run_jj(["op", "log", "-n3", "-Tdescription", "--show-index-events"]);
+    insta::assert_snapshot!(output, @r"
+    @  create bookmark b pointing to commit e8849ae12c709f2321908879bc724fdb2ab8a781
+    ○  create bookmark a pointing to commit e8849ae12c709f2321908879bc724fdb2ab8a781
+    │  (index was rebuilt when loaded)
+    ○  add workspace 'default'
+    [EOF]
+    ");
+    let output = work_dir.run_jj([
+        "op",
+        "log",
+        "-n3",
+        "--no-graph",
+        r#"-Tdescription ++ "\n""#,
+        "--show-index-events",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    create bookmark b pointing to commit e8849ae12c709f2321908879bc724fdb2ab8a781
+    create bookmark a pointing to commit e8849ae12c709f2321908879bc724fdb2ab8a781
+    (index was rebuilt when loaded)
+    add workspace 'default'
+    [EOF]
+    ");
+
+    // The annotation is only shown if requested
+    let output = work_dir.run_jj(["op", "log", "-n3", "-Tdescription"]);
+    insta::assert_snapshot!(output, @r"
+    @  create bookmark b pointing to commit e8849ae12c709f2321908879bc724fdb2ab8a781
+    ○  create bookmark a pointing to commit e8849ae12c709f2321908879bc724fdb2ab8a781
+    ○  add workspace 'default'
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_op_log_json() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    work_dir
+        .run_jj(["describe", "-m", "description 0"])
+        .success();
+    work_dir.run_jj(["new"]).success();
+
+    let get_ops = |args: &[&str]| {
+        let output = work_dir
+            .run_jj(["op", "log", "--format=json"].iter().chain(args))
+            .success();
+        let ops: serde_json::Value = serde_json::from_str(output.stdout.raw()).unwrap();
+        ops.as_array().unwrap().clone()
+    };
+
+    // Newest first, down to the root operation
+    let ops = get_ops(&[]);
+    assert_eq!(ops.len(), 4);
+    assert_eq!(ops[0]["description"], "new empty commit");
+    assert_eq!(ops[0]["user"], "test-username@host.example.com");
+    assert_eq!(ops[0]["parents"], serde_json::json!([ops[1]["id"]]));
+    assert_eq!(ops[3]["parents"], serde_json::json!([]));
+    for op in &ops {
+        assert_eq!(op["id"].as_str().unwrap().len(), 128);
+        assert!(op["time"]["start"].is_string());
+        assert!(op["tags"].is_object());
+    }
+
+    // Same options as the text log are respected
+    let ops = get_ops(&["--limit=1"]);
+    assert_eq!(ops.len(), 1);
+    assert_eq!(ops[0]["description"], "new empty commit");
+    let ops = get_ops(&["--reversed"]);
+    assert_eq!(ops.len(), 4);
+    assert_eq!(ops[3]["description"], "new empty commit");
+
+    // Templates can't be combined with the JSON output
+    let output = work_dir.run_jj(["op", "log", "--format=json", "-Tdescription"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_op_show_json() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    work_dir
+        .run_jj(["--op-tag", "ci-run=1", "describe", "-m", "description 0"])
+        .success();
+
+    // The operation is rendered as in the JSON log
+    let output = work_dir
+        .run_jj(["op", "show", "--format=json", "@"])
+        .success();
+    let op: serde_json::Value = serde_json::from_str(output.stdout.raw()).unwrap();
+    let output = work_dir
+        .run_jj(["op", "log", "--format=json", "--limit=1"])
+        .success();
+    let ops: serde_json::Value = serde_json::from_str(output.stdout.raw()).unwrap();
+    assert_eq!(op, ops[0]);
+    assert_eq!(op["tags"]["ci-run"], "1");
+
+    // The operation diff can't be combined with the JSON output
+    let output = work_dir.run_jj(["op", "show", "--format=json", "--patch"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_op_log_tag() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    work_dir
+        .run_jj(["--op-tag", "ci-run=1", "describe", "-m", "description 0"])
+        .success();
+    work_dir
+        .run_jj(["--op-tag", "ci-run=2", "--op-tag", "job=a=b", "new"])
+        .success();
+    let template = r#"description ++ "\n""#;
+
+    let output = work_dir.run_jj(["op", "log", "--no-graph", "-T", template, "--tag=ci-run=1"]);
+    insta::assert_snapshot!(output, @r"
+    describe commit e8849ae12c709f2321908879bc724fdb2ab8a781
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["op", "log", "--no-graph", "-T", template, "--tag=ci-run=2"]);
+    insta::assert_snapshot!(output, @r"
+    new empty commit
+    [EOF]
+    ");
+
+    // All the given tags must match
+    let output = work_dir.run_jj([
+        "op",
+        "log",
+        "--no-graph",
+        "-T",
+        template,
+        "--tag=ci-run=2",
+        "--tag=job=a=b",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    new empty commit
+    [EOF]
+    ");
+    let output = work_dir.run_jj([
+        "op",
+        "log",
+        "--no-graph",
+        "-T",
+        template,
+        "--tag=ci-run=1",
+        "--tag=job=a=b",
+    ]);
+    insta::assert_snapshot!(output, @"");
+
+    // Tags must have a key and a value
+    let output = work_dir.run_jj(["--op-tag", "ci-run", "new"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(
+        output
+            .stderr
+            .raw()
+            .contains("Operation tag must have the form KEY=VALUE")
+    );
+}
+
 #[test]
 fn test_op_log_no_graph() {
     let test_env = TestEnvironment::default();
@@ -263,6 +451,58 @@ fn test_op_log_no_graph() {
     ");
 }
 
+#[test]
+fn test_op_log_op_diff_fetch_and_bookmark_create() {
+    let test_env = TestEnvironment::default();
+    let git_repo_path = test_env.env_root().join("git-repo");
+    init_bare_git_repo(&git_repo_path);
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    work_dir
+        .run_jj(["git", "remote", "add", "origin", "../git-repo"])
+        .success();
+    work_dir.run_jj(["git", "fetch"]).success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "local-1"])
+        .success();
+
+    let output = work_dir.run_jj([
+        "op",
+        "log",
+        "--op-diff",
+        "--no-graph",
+        "-n2",
+        "-T",
+        r#"description.first_line() ++ "\n""#,
+    ]);
+    insta::assert_snapshot!(output, @r"
+    create bookmark local-1 pointing to commit e8849ae12c709f2321908879bc724fdb2ab8a781
+
+    Changed local bookmarks:
+    local-1:
+    + qpvuntsm e8849ae1 local-1 | (empty) (no description set)
+    - (absent)
+    fetch from git remote(s) origin
+
+    Changed commits:
+    + rnnslrkn 4ff62539 bookmark-2@origin | Commit 2
+    + rnnkyono 11671e4c bookmark-3@origin | Commit 3
+    + pukowqtp 0cb7e07e bookmark-1@origin | Commit 1
+
+    Changed remote bookmarks:
+    bookmark-1@origin:
+    + untracked pukowqtp 0cb7e07e bookmark-1@origin | Commit 1
+    - untracked (absent)
+    bookmark-2@origin:
+    + untracked rnnslrkn 4ff62539 bookmark-2@origin | Commit 2
+    - untracked (absent)
+    bookmark-3@origin:
+    + untracked rnnkyono 11671e4c bookmark-3@origin | Commit 3
+    - untracked (absent)
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_op_log_reversed() {
     let test_env = TestEnvironment::default();
@@ -354,6 +594,63 @@ fn test_op_log_reversed() {
     ");
 }
 
+#[test]
+fn test_op_log_reversed_linear() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    work_dir
+        .run_jj(["--op-tag", "ci-run=1", "describe", "-m", "description 0"])
+        .success();
+    work_dir.run_jj(["new"]).success();
+    work_dir
+        .run_jj(["--op-tag", "ci-run=1", "new", "-m", "description 1"])
+        .success();
+    let template = r#"description.first_line() ++ "\n""#;
+
+    // Oldest operation first, with the graph connecting consecutive operations
+    let output = work_dir.run_jj(["op", "log", "--reversed", "--limit=4", "-T", template]);
+    insta::assert_snapshot!(output, @r"
+    ○  add workspace 'default'
+    ○  describe commit e8849ae12c709f2321908879bc724fdb2ab8a781
+    ○  new empty commit
+    @  new empty commit
+    [EOF]
+    ");
+    let output = work_dir.run_jj([
+        "op",
+        "log",
+        "--reversed",
+        "--limit=4",
+        "--no-graph",
+        "-T",
+        template,
+    ]);
+    insta::assert_snapshot!(output, @r"
+    add workspace 'default'
+    describe commit e8849ae12c709f2321908879bc724fdb2ab8a781
+    new empty commit
+    new empty commit
+    [EOF]
+    ");
+
+    // Reversing applies after filtering by tag
+    let output = work_dir.run_jj([
+        "op",
+        "log",
+        "--reversed",
+        "--no-graph",
+        "--tag=ci-run=1",
+        "-T",
+        template,
+    ]);
+    insta::assert_snapshot!(output, @r"
+    describe commit e8849ae12c709f2321908879bc724fdb2ab8a781
+    new empty commit
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_op_log_no_graph_null_terminated() {
     let test_env = TestEnvironment::default();
@@ -768,6 +1065,79 @@ fn test_op_abandon_ancestors() {
     ");
 }
 
+#[test]
+fn test_op_abandon_older_than() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    // Operations created by the test environment are dated 2001. Create newer
+    // ones by overriding the operation timestamp.
+    work_dir.run_jj(["commit", "-m", "commit 1"]).success();
+    work_dir.run_jj(["commit", "-m", "commit 2"]).success();
+    for message in ["commit 3", "commit 4"] {
+        work_dir
+            .run_jj_with(|cmd| {
+                cmd.args(["commit", "-m", message])
+                    .env("JJ_OP_TIMESTAMP", "2100-01-01T00:00:00+00:00")
+            })
+            .success();
+    }
+    let op_log_template = r#"if(root, "root()", tags) ++ "\n""#;
+
+    let output = work_dir.run_jj(["op", "abandon", "--older-than", "30d"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Abandoned 3 operations and reparented 2 descendant operations.
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["op", "log", "--no-graph", "-T", op_log_template]);
+    insta::assert_snapshot!(output, @r"
+    args: jj commit -m 'commit 4'
+    args: jj commit -m 'commit 3'
+    root()
+    [EOF]
+    ");
+
+    // Commits created by the abandoned operations are still reachable
+    let output = work_dir.run_jj([
+        "log",
+        "--no-graph",
+        "-r=::@- ~ root()",
+        "-T",
+        r#"description.first_line() ++ "\n""#,
+    ]);
+    insta::assert_snapshot!(output, @r"
+    commit 4
+    commit 3
+    commit 2
+    commit 1
+    [EOF]
+    ");
+
+    // The remaining operations are newer than the cutoff
+    let output = work_dir.run_jj(["op", "abandon", "--older-than", "30d"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Nothing changed.
+    [EOF]
+    ");
+
+    // Invalid age
+    let output = work_dir.run_jj(["op", "abandon", "--older-than", "30x"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Invalid age: 30x
+    Hint: Use a number followed by one of the units s, m, h, d, or w, e.g. `30d`
+    [EOF]
+    [exit status: 1]
+    ");
+
+    // Can't be combined with an operation argument
+    let output = work_dir.run_jj(["op", "abandon", "--older-than", "30d", "@-"]);
+    assert!(output.stderr.raw().contains("cannot be used with"));
+}
+
 #[test]
 fn test_op_abandon_without_updating_working_copy() {
     let test_env = TestEnvironment::default();
@@ -2816,6 +3186,51 @@ fn test_op_show_patch() {
     ");
 }
 
+#[test]
+fn test_op_show_patch_describe_and_snapshot() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    // Describing the working copy first snapshots the new file.
+    work_dir.write_file("file", "a\n");
+    work_dir.run_jj(["describe", "-m", "first"]).success();
+    let template = r#"description ++ "\n""#;
+
+    // The snapshot operation shows the diff it introduced to the working copy.
+    let output = work_dir.run_jj(["op", "show", "@-", "-p", "--git", "-T", template]);
+    insta::assert_snapshot!(output, @r"
+    snapshot working copy
+
+    Changed commits:
+    ○  + qpvuntsm 6b57e33c (no description set)
+       - qpvuntsm hidden e8849ae1 (empty) (no description set)
+       diff --git a/file b/file
+       new file mode 100644
+       index 0000000000..7898192261
+       --- /dev/null
+       +++ b/file
+       @@ -0,0 +1,1 @@
+       +a
+
+    Changed working copy default@:
+    + qpvuntsm 6b57e33c (no description set)
+    - qpvuntsm hidden e8849ae1 (empty) (no description set)
+    [EOF]
+    ");
+
+    // The describe operation rewrote the commit without changing its content,
+    // so only the description is diffed.
+    let output = work_dir
+        .run_jj(["op", "show", "-p", "--git", "-T", template])
+        .success();
+    let stdout = output.stdout.raw();
+    assert!(stdout.starts_with("describe commit 6b57e33cc56babbeaa6bcd6e2a296236b52ad93c\n"));
+    assert!(stdout.contains("- qpvuntsm hidden 6b57e33c (no description set)"));
+    assert!(stdout.contains("diff --git a/JJ-COMMIT-DESCRIPTION b/JJ-COMMIT-DESCRIPTION"));
+    assert!(!stdout.contains("diff --git a/file b/file"));
+}
+
 #[test]
 fn test_op_show_template() {
     let test_env = TestEnvironment::default();
@@ -2865,6 +3280,70 @@ fn test_op_show_template() {
     ");
 }
 
+#[test]
+fn test_op_restore_dry_run() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    let op_id = work_dir
+        .run_jj(["op", "log", "--no-graph", "-n1", "-T=id.short()"])
+        .success()
+        .stdout
+        .into_raw();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "foo"])
+        .success();
+    work_dir.run_jj(["describe", "-m", "changed"]).success();
+
+    let get_state = || {
+        let op_log = work_dir
+            .run_jj(["op", "log", "--no-graph", r#"-T=description ++ "\n""#])
+            .success();
+        let log = work_dir
+            .run_jj([
+                "log",
+                "-T=separate(' ', description.first_line(), bookmarks)",
+            ])
+            .success();
+        (op_log.stdout.into_raw(), log.stdout.into_raw())
+    };
+    let state_before = get_state();
+
+    let output = work_dir.run_jj(["op", "restore", "--dry-run", &op_id]);
+    let stdout = output.stdout.normalized();
+    let stderr = output.stderr.normalized();
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.starts_with(&format!("Would restore to operation: {op_id} ")),
+        "{stderr}"
+    );
+    assert!(
+        stderr.ends_with("Dry-run requested, not restoring.\n"),
+        "{stderr}"
+    );
+    assert!(
+        stdout.contains("Changed working copy default@:"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("+ qpvuntsm e8849ae1 (empty) (no description set)"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("(empty) changed"), "{stdout}");
+    assert!(
+        stdout.contains("Changed local bookmarks:\nfoo:\n"),
+        "{stdout}"
+    );
+
+    // No operation is recorded and the repo is left as it was
+    assert_eq!(get_state(), state_before);
+    insta::assert_snapshot!(state_before.0, @r"
+    describe commit e8849ae12c709f2321908879bc724fdb2ab8a781
+    create bookmark foo pointing to commit e8849ae12c709f2321908879bc724fdb2ab8a781
+    add workspace 'default'
+    ");
+}
+
 #[test]
 fn test_op_log_parents() {
     let test_env = TestEnvironment::default();
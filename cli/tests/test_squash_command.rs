@@ -17,6 +17,7 @@ use std::path::PathBuf;
 use crate::common::CommandOutput;
 use crate::common::TestEnvironment;
 use crate::common::TestWorkDir;
+use crate::common::create_commit;
 
 #[test]
 fn test_squash() {
@@ -120,11 +121,28 @@ fn test_squash() {
     ◆  000000000000 (empty)
     [EOF]
     ");
-    let output = work_dir.run_jj(["squash"]);
+    let summary_config =
+        r#"--config=templates.commit_summary='separate(" ", commit_id.short(), bookmarks)'"#;
+    let output = work_dir.run_jj(["squash", summary_config]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
     Error: Cannot squash merge commits without a specified destination
-    Hint: Use `--into` to specify which parent to squash into
+    Hint: Merge commit e05d4caaf6ce has these parents:
+      22be6c4e01da c
+      9bb7863cfc78 d
+    Hint: Use `--into 22be6c4e01da` or `--into 9bb7863cfc78` to specify which parent to squash into
+    [EOF]
+    [exit status: 1]
+    ");
+    // `--into @-` is ambiguous for the same reason
+    let output = work_dir.run_jj(["squash", "--into=@-", summary_config]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Revset `@-` resolved to more than one revision
+    Hint: Merge commit e05d4caaf6ce has these parents:
+      22be6c4e01da c
+      9bb7863cfc78 d
+    Hint: Use `--into 22be6c4e01da` or `--into 9bb7863cfc78` to specify which parent to squash into
     [EOF]
     [exit status: 1]
     ");
@@ -135,13 +153,13 @@ fn test_squash() {
     let output = work_dir.run_jj(["squash"]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
-    Working copy  (@) now at: xlzxqlsl 91a81249 (empty) (no description set)
-    Parent commit (@-)      : nmzmmopx 9155baf5 e | (no description set)
+    Working copy  (@) now at: uuuvxpvw 52038a5f (empty) (no description set)
+    Parent commit (@-)      : nmzmmopx c593fb4d e | (no description set)
     [EOF]
     ");
     insta::assert_snapshot!(get_log_output(&work_dir), @r"
-    @  91a81249954f (empty)
-    ○    9155baf5ced1 e
+    @  52038a5f14fc (empty)
+    ○    c593fb4da385 e
     ├─╮
     │ ○  9bb7863cfc78 d
     ○ │  22be6c4e01da c
@@ -270,6 +288,30 @@ fn test_squash_partial() {
     [EOF]
     ");
 
+    // --interactive-tool=<name> overrides the configured diff editor and implies
+    // --interactive
+    work_dir.run_jj(["op", "restore", &start_op_id]).success();
+    std::fs::write(&edit_script, "reset file1").unwrap();
+    work_dir
+        .run_jj([
+            "squash",
+            "-r",
+            "b",
+            "--config=ui.diff-editor='false'",
+            "--interactive-tool=fake-diff-editor",
+        ])
+        .success();
+    let output = work_dir.run_jj(["file", "show", "file1", "-r", "a"]);
+    insta::assert_snapshot!(output, @r"
+    a
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["file", "show", "file2", "-r", "a"]);
+    insta::assert_snapshot!(output, @r"
+    b
+    [EOF]
+    ");
+
     // Can squash only some changes in non-interactive mode
     work_dir.run_jj(["op", "restore", &start_op_id]).success();
     // Clear the script so we know it won't be used even without -i
@@ -278,14 +320,14 @@ fn test_squash_partial() {
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
     Rebased 2 descendant commits
-    Working copy  (@) now at: mzvwutvl 72ff256c c | (no description set)
-    Parent commit (@-)      : kkmpptxz dd056a92 b | (no description set)
+    Working copy  (@) now at: mzvwutvl f8db02e0 c | (no description set)
+    Parent commit (@-)      : kkmpptxz bdb08166 b | (no description set)
     [EOF]
     ");
     insta::assert_snapshot!(get_log_output(&work_dir), @r"
-    @  72ff256cd290 c
-    ○  dd056a925eb3 b
-    ○  cf083f1d9ccf a
+    @  f8db02e0ec8b c
+    ○  bdb081664a91 b
+    ○  3d7de48af818 a
     ◆  000000000000 (empty)
     [EOF]
     ");
@@ -339,17 +381,17 @@ fn test_squash_partial() {
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
     Rebased 1 descendant commits
-    Working copy  (@) now at: mzvwutvl 69c58f86 c | (no description set)
-    Parent commit (@-)      : kkmpptxz 0f38c564 b | (no description set)
+    Working copy  (@) now at: mzvwutvl 1797ef03 c | (no description set)
+    Parent commit (@-)      : kkmpptxz ed998c7b b | (no description set)
     [EOF]
     ");
     let output = work_dir.run_jj(["log", "-s"]);
     insta::assert_snapshot!(output, @r"
-    @  mzvwutvl test.user@example.com 2001-02-03 08:05:36 c 69c58f86
+    @  mzvwutvl test.user@example.com 2001-02-03 08:05:40 c 1797ef03
     │  (no description set)
     │  M file1
     │  M file2
-    ○  kkmpptxz test.user@example.com 2001-02-03 08:05:36 b 0f38c564
+    ○  kkmpptxz test.user@example.com 2001-02-03 08:05:40 b ed998c7b
     │  (no description set)
     │  M file1
     │  M file2
@@ -416,6 +458,212 @@ fn test_squash_keep_emptied() {
     ");
 }
 
+#[test]
+fn test_squash_delete_emptied_bookmarks() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "a"])
+        .success();
+    work_dir.write_file("file1", "a\n");
+    work_dir.run_jj(["new"]).success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "b"])
+        .success();
+    work_dir.write_file("file1", "b\n");
+    work_dir.run_jj(["new"]).success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "c"])
+        .success();
+    work_dir.write_file("file2", "c\n");
+    work_dir.run_jj(["new", "a"]).success();
+    let setup_opid = work_dir.current_operation_id();
+    let get_bookmark_names = || work_dir.run_jj(["bookmark", "list", "-T", r#"name ++ "\n""#]);
+
+    // By default, the bookmark of the abandoned source is moved
+    let output = work_dir.run_jj(["squash", "--from=c", "--into=b"]);
+    insta::assert_snapshot!(output, @"");
+    insta::assert_snapshot!(get_bookmark_names(), @r"
+    a
+    b
+    c
+    [EOF]
+    ");
+
+    // With --delete-emptied-bookmarks, it is deleted instead
+    work_dir.run_jj(["op", "restore", &setup_opid]).success();
+    let output = work_dir.run_jj([
+        "squash",
+        "--from=c",
+        "--into=b",
+        "--delete-emptied-bookmarks",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Deleted bookmarks: c
+    [EOF]
+    ");
+    insta::assert_snapshot!(get_bookmark_names(), @r"
+    a
+    b
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["file", "show", "-r=b", "file2"]);
+    insta::assert_snapshot!(output, @r"
+    c
+    [EOF]
+    ");
+}
+
+#[test]
+fn test_squash_no_rebase_descendants() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+    create_commit(&work_dir, "a", &[]);
+    create_commit(&work_dir, "b", &["a"]);
+    create_commit(&work_dir, "c", &["b"]);
+    let template =
+        r#"separate(" ", description.first_line(), bookmarks, if(divergent, "divergent"))"#;
+
+    let output = work_dir.run_jj([
+        "squash",
+        "--from=b",
+        "--into=a",
+        "-u",
+        "--no-rebase-descendants",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Warning: Left 1 descendant commits on top of the old revisions
+    Hint: The destination revision is now divergent with its old version. Use `jj rebase` to move the descendants onto the new revision.
+    [EOF]
+    ");
+    // The descendant is left on the old revisions, and the destination is
+    // divergent
+    insta::assert_snapshot!(work_dir.run_jj(["log", "-T", template]), @r"
+    @  c c
+    ○  b
+    ○  a divergent
+    │ ○  a a b divergent
+    ├─╯
+    ◆
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["file", "show", "-r=bookmarks(exact:a)", "b"]);
+    insta::assert_snapshot!(output, @r"
+    b
+    [EOF]
+    ");
+
+    // Cannot be combined with inserting a new destination commit
+    let output = work_dir.run_jj([
+        "squash",
+        "--from=c",
+        "--onto=root()",
+        "--no-rebase-descendants",
+    ]);
+    assert!(output.stderr.raw().contains("cannot be used with"));
+}
+
+#[test]
+fn test_squash_paths_from_file() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    work_dir.write_file("file1", "a\n");
+    work_dir.write_file("file2", "a\n");
+    work_dir.write_file("file3", "a\n");
+    work_dir.run_jj(["new"]).success();
+    work_dir.write_file("file1", "b\n");
+    work_dir.write_file("file2", "b\n");
+    work_dir.write_file("file3", "b\n");
+    let paths_file = test_env.env_root().join("paths.txt");
+    std::fs::write(&paths_file, "# comment\n\nfile1\nnonexistent\n").unwrap();
+    let empty_file = test_env.env_root().join("empty.txt");
+    std::fs::write(&empty_file, "# comment\n\n").unwrap();
+
+    // Filesets from the file are combined with the positional ones
+    let output = work_dir
+        .run_jj(["squash", "--paths-from-file", "../paths.txt", "file2"])
+        .success()
+        .normalize_stderr_with(|stderr| {
+            stderr
+                .lines()
+                .filter(|line| line.starts_with("Warning:"))
+                .map(|line| format!("{line}\n"))
+                .collect()
+        });
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Warning: No matching entries for paths: nonexistent
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["diff", "--summary"]);
+    insta::assert_snapshot!(output, @r"
+    M file3
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["file", "show", "-r", "@-", "file1", "file2", "file3"]);
+    insta::assert_snapshot!(output, @r"
+    b
+    b
+    a
+    [EOF]
+    ");
+
+    // An empty file without positional filesets is an error
+    let output = work_dir.run_jj(["squash", "--paths-from-file", "../empty.txt"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: No filesets found in ../empty.txt
+    [EOF]
+    [exit status: 1]
+    ");
+}
+
+#[test]
+fn test_squash_binary_files() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    work_dir.write_file("file", "a\n");
+    work_dir.write_file("binary", b"a\0");
+    work_dir.run_jj(["new"]).success();
+    work_dir.write_file("file", "b\n");
+    work_dir.write_file("binary", b"b\0");
+    let start_op_id = work_dir.current_operation_id();
+
+    // Binary files are left in the source
+    work_dir.run_jj(["squash", "--binary=skip"]).success();
+    let output = work_dir.run_jj(["diff", "--summary"]);
+    insta::assert_snapshot!(output, @r"
+    M binary
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["file", "show", "-r", "@-", "file"]);
+    insta::assert_snapshot!(output, @r"
+    b
+    [EOF]
+    ");
+
+    // By default, binary files are moved along with the other files
+    work_dir.run_jj(["op", "restore", &start_op_id]).success();
+    work_dir.run_jj(["squash"]).success();
+    let output = work_dir.run_jj(["diff", "--summary"]);
+    insta::assert_snapshot!(output, @"");
+    let output = work_dir.run_jj(["diff", "--summary", "-r", "@-"]);
+    insta::assert_snapshot!(output, @r"
+    A binary
+    A file
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_squash_from_to() {
     let test_env = TestEnvironment::default();
@@ -582,6 +830,8 @@ fn test_squash_from_to() {
     let output = work_dir.run_jj(["squash", "--from", "e::f", "--into", "d"]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
+    Warning: The source revisions include the working-copy commit
+    Hint: Use `--no-working-copy` to exclude it from the source revisions.
     Working copy  (@) now at: pkstwlsy 76baa567 (empty) (no description set)
     Parent commit (@-)      : vruxwmqv 415e4069 d e f | (no description set)
     [EOF]
@@ -606,6 +856,81 @@ fn test_squash_from_to() {
     ");
 }
 
+#[test]
+fn test_squash_from_including_working_copy() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "d"])
+        .success();
+    work_dir.write_file("file1", "d\n");
+    work_dir.run_jj(["new"]).success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "e"])
+        .success();
+    work_dir.write_file("file2", "e\n");
+    work_dir.run_jj(["new"]).success();
+    work_dir.write_file("file3", "f\n");
+    work_dir.run_jj(["debug", "snapshot"]).success();
+    let setup_opid = work_dir.current_operation_id();
+    let warnings_only = |stderr: String| -> String {
+        stderr
+            .lines()
+            .filter(|line| line.starts_with("Warning:") || line.starts_with("Hint:"))
+            .map(|line| format!("{line}\n"))
+            .collect()
+    };
+
+    // The working copy is squashed along with the other sources by default
+    let output = work_dir
+        .run_jj(["squash", "--from", "e::", "--into", "d"])
+        .success()
+        .normalize_stderr_with(warnings_only);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Warning: The source revisions include the working-copy commit
+    Hint: Use `--no-working-copy` to exclude it from the source revisions.
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["diff", "--summary"]);
+    insta::assert_snapshot!(output, @"");
+    let output = work_dir.run_jj(["file", "show", "-r", "d", "file3"]);
+    insta::assert_snapshot!(output, @r"
+    f
+    [EOF]
+    ");
+
+    // The working copy can be excluded from the sources
+    work_dir.run_jj(["op", "restore", &setup_opid]).success();
+    let output = work_dir
+        .run_jj([
+            "squash",
+            "--from",
+            "e::",
+            "--into",
+            "d",
+            "--no-working-copy",
+        ])
+        .success()
+        .normalize_stderr_with(warnings_only);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["diff", "--summary"]);
+    insta::assert_snapshot!(output, @r"
+    A file3
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["file", "show", "-r", "d", "file2"]);
+    insta::assert_snapshot!(output, @r"
+    e
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_squash_from_to_partial() {
     let mut test_env = TestEnvironment::default();
@@ -811,6 +1136,133 @@ fn test_squash_from_to_partial() {
     ");
 }
 
+#[test]
+fn test_squash_into_multiple() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    // Create history like this:
+    // D
+    // |
+    // C
+    // |
+    // B
+    // |
+    // A
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "a"])
+        .success();
+    work_dir.write_file("file1", "a\n");
+    work_dir.write_file("file2", "a\n");
+    work_dir.run_jj(["new"]).success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "b"])
+        .success();
+    work_dir.write_file("file1", "b\n");
+    work_dir.run_jj(["new"]).success();
+    work_dir
+        .run_jj(["bookmark", "create", "-r@", "c"])
+        .success();
+    work_dir.write_file("file2", "c\n");
+    work_dir.run_jj(["new", "-m=d"]).success();
+    work_dir.write_file("file1", "d\n");
+    work_dir.write_file("file2", "d\n");
+    work_dir.write_file("file3", "d\n");
+    let get_log_output_with_summary = || {
+        let template = r#"separate(" ", local_bookmarks, description.first_line()) ++ "\n""#;
+        work_dir.run_jj([
+            "log",
+            "--no-graph",
+            "-r=::@ ~ root()",
+            "-T",
+            template,
+            "--summary",
+        ])
+    };
+
+    // Every changed path must be modified by one of the destinations
+    let output = work_dir.run_jj(["squash", "--into=b", "--into=c"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: No destination modifies file3
+    Hint: Each path must already be modified by one of the destinations
+    [EOF]
+    [exit status: 1]
+    ");
+
+    // Only a single source is supported
+    let output = work_dir.run_jj(["squash", "--from=@|a", "--into=b", "--into=c"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Cannot squash more than one revision into multiple destinations
+    [EOF]
+    [exit status: 1]
+    ");
+
+    // The description can't be set
+    let output = work_dir.run_jj(["squash", "--into=b", "--into=c", "-m=new"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Cannot set the description when squashing into multiple destinations
+    Hint: Use `jj describe` to update the descriptions afterwards
+    [EOF]
+    [exit status: 1]
+    ");
+
+    // Each path is moved into the destination that modifies it
+    work_dir
+        .run_jj(["squash", "--into=b", "--into=c", "file1", "file2"])
+        .success();
+    insta::assert_snapshot!(get_log_output_with_summary(), @r"
+    d
+    A file3
+    c
+    M file2
+    b
+    M file1
+    a
+    A file1
+    A file2
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["file", "show", "-r=b", "file1"]);
+    insta::assert_snapshot!(output, @r"
+    d
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["file", "show", "-r=c", "file2"]);
+    insta::assert_snapshot!(output, @r"
+    d
+    [EOF]
+    ");
+
+    // The source is abandoned once all of its changes have been moved
+    work_dir.run_jj(["new", "-m=e"]).success();
+    work_dir.write_file("file1", "e\n");
+    work_dir.write_file("file2", "e\n");
+    work_dir
+        .run_jj(["squash", "--into=b", "--into=c"])
+        .success();
+    let output = work_dir.run_jj(["log", "--no-graph", "-r=@-", "-T=description"]);
+    insta::assert_snapshot!(output, @r"
+    d
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["file", "show", "-r=c", "file1", "file2"]);
+    insta::assert_snapshot!(output, @r"
+    e
+    e
+    [EOF]
+    ");
+    // The description of the abandoned source is moved to the last destination
+    let output = work_dir.run_jj(["log", "--no-graph", "-r=b|c", "-T=description"]);
+    insta::assert_snapshot!(output, @r"
+    e
+    [EOF]
+    ");
+}
+
 #[test]
 fn test_squash_from_multiple() {
     let test_env = TestEnvironment::default();
@@ -918,6 +1370,8 @@ fn test_squash_from_multiple() {
     let output = work_dir.run_jj(["squash", "--from=b|c|f", "--into=e"]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
+    Warning: The source revisions include the working-copy commit
+    Hint: Use `--no-working-copy` to exclude it from the source revisions.
     Rebased 1 descendant commits
     Working copy  (@) now at: xznxytkn ec32238b (empty) (no description set)
     Parent commit (@-)      : yostqsxw 5298eef6 e f | (no description set)
@@ -1083,6 +1537,8 @@ fn test_squash_from_multiple_partial() {
     let output = work_dir.run_jj(["squash", "--from=b|c|f", "--into=e", "file1"]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
+    Warning: The source revisions include the working-copy commit
+    Hint: Use `--no-working-copy` to exclude it from the source revisions.
     Rebased 1 descendant commits
     Working copy  (@) now at: kpqxywon b5a40c15 f | (no description set)
     Parent commit (@-)      : yostqsxw 5dea187c e | (no description set)
@@ -1202,6 +1658,29 @@ fn test_squash_from_multiple_partial_no_op() {
     Nothing changed.
     [EOF]
     ");
+
+    // With --verbose, the reason is explained
+    let output = work_dir.run_jj([
+        "squash",
+        "--from=@-+ ~ @",
+        "--into=@",
+        "--verbose",
+        "nonexistent",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Warning: No matching entries for paths: nonexistent
+    2 source commits matched but none touched the requested paths
+    Nothing changed.
+    [EOF]
+    ");
+    let output = work_dir.run_jj(["squash", "--from=none()", "--into=@", "--verbose"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    No source commits matched
+    Nothing changed.
+    [EOF]
+    ");
     insta::assert_snapshot!(get_log_output(&work_dir), @r"
     @  fdb92bc249a0 d
     │ ○  0dc8cb72859d c
@@ -1306,6 +1785,30 @@ fn test_squash_description() {
     JJ: Lines starting with "JJ:" (like this one) will be removed.
     "#);
 
+    // Leaving the combined description empty aborts the squash
+    work_dir.run_jj(["op", "restore", &setup_opid3]).success();
+    let restored_opid = work_dir.current_operation_id();
+    std::fs::write(&edit_script, "write\n").unwrap();
+    let output = work_dir.run_jj(["squash"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Aborted: empty description
+    Hint: Use --allow-empty-description to squash with an empty description
+    [EOF]
+    [exit status: 1]
+    ");
+    assert_eq!(work_dir.current_operation_id(), restored_opid);
+    insta::assert_snapshot!(get_description(&work_dir, "@-"), @r"
+    destination
+    [EOF]
+    ");
+
+    // ...unless --allow-empty-description is set
+    work_dir
+        .run_jj(["squash", "--allow-empty-description"])
+        .success();
+    insta::assert_snapshot!(get_description(&work_dir, "@-"), @"");
+
     // An explicit description on the command-line overrides prevents launching an
     // editor
     work_dir.run_jj(["op", "restore", &setup_opid3]).success();
@@ -1334,6 +1837,37 @@ fn test_squash_description() {
     [EOF]
     ");
 
+    // A description read from a file also includes the trailers
+    work_dir.run_jj(["op", "restore", &setup_opid3]).success();
+    std::fs::write(
+        test_env.env_root().join("message"),
+        "subject\n\nfirst line of body\nsecond line of body\n",
+    )
+    .unwrap();
+    work_dir
+        .run_jj([
+            "squash",
+            "--config",
+            r#"templates.commit_trailers='"CC: " ++ committer.email()'"#,
+            "--message-from-file=../message",
+        ])
+        .success();
+    insta::assert_snapshot!(get_description(&work_dir, "@-"), @r"
+    subject
+
+    first line of body
+    second line of body
+
+    CC: test.user@example.com
+    [EOF]
+    ");
+
+    // --message-from-file can't be combined with other description sources
+    let output = work_dir.run_jj(["squash", "--message-from-file=../message", "-m=custom"]);
+    assert!(output.stderr.raw().contains("cannot be used with"));
+    let output = work_dir.run_jj(["squash", "--message-from-file=../message", "-u"]);
+    assert!(output.stderr.raw().contains("cannot be used with"));
+
     // If the source's *content* doesn't become empty, then the source remains and
     // both descriptions are unchanged
     work_dir.run_jj(["op", "restore", &setup_opid3]).success();
@@ -1389,7 +1923,7 @@ fn test_squash_description() {
     work_dir.run_jj(["op", "restore", &setup_opid3]).success();
     work_dir.run_jj(["describe", "-m", ""]).success();
     insta::assert_snapshot!(get_log_output_with_description(&work_dir), @r"
-    @  97f34efed913
+    @  9fd6605d0f9a
     ○  e650dfcd7312 destination
     ◆  000000000000
     [EOF]
@@ -1416,8 +1950,8 @@ fn test_squash_description() {
         .run_jj(["describe", "-r", "@-", "-m", ""])
         .success();
     insta::assert_snapshot!(get_log_output_with_description(&work_dir), @r"
-    @  40d544a674fa source
-    ○  0cd53b79bf29
+    @  7ce5b3a58427 source
+    ○  b9442a4ce005
     ◆  000000000000
     [EOF]
     ");
@@ -1441,8 +1975,8 @@ fn test_squash_description() {
         .run_jj(["describe", "-r", "..", "-m", ""])
         .success();
     insta::assert_snapshot!(get_log_output_with_description(&work_dir), @r"
-    @  40803d8a1ce7
-    ○  dd5a6e210d71
+    @  73911c418fea
+    ○  65b61d25cce5
     ◆  000000000000
     [EOF]
     ");
@@ -1462,8 +1996,8 @@ fn test_squash_description() {
         .run_jj(["describe", "-r", "@-", "-m", ""])
         .success();
     insta::assert_snapshot!(get_log_output_with_description(&work_dir), @r"
-    @  7ce5b3a58427 source
-    ○  b9442a4ce005
+    @  29d552341390 source
+    ○  35aa4be67c03
     ◆  000000000000
     [EOF]
     ");
@@ -1606,6 +2140,165 @@ fn test_squash_use_destination_message() {
     ");
 }
 
+#[test]
+fn test_squash_from_op() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    work_dir.run_jj(["describe", "-m=wc"]).success();
+    work_dir
+        .run_jj(["new", "--no-edit", "root()", "-m=sibling"])
+        .success();
+    let new_op_id = work_dir.current_operation_id();
+    work_dir.write_file("file1", "a\n");
+    work_dir.run_jj(["debug", "snapshot"]).success();
+    let snapshot_op_id = work_dir.current_operation_id();
+    work_dir.write_file("file2", "b\n");
+    work_dir.run_jj(["debug", "snapshot"]).success();
+    let get_files = |revision: &str| {
+        let template = r#"separate(" ", description.first_line(), diff.files().map(|e| e.path()))"#;
+        work_dir.run_jj(["log", "--no-graph", "-r", revision, "-T", template])
+    };
+    insta::assert_snapshot!(get_files("@"), @"wc file1 file2[EOF]");
+    insta::assert_snapshot!(get_files("description(sibling)"), @"sibling[EOF]");
+
+    // Only the changes snapshotted by the operation are moved
+    work_dir
+        .run_jj([
+            "squash",
+            "--from-op",
+            &snapshot_op_id,
+            "--into=description(sibling)",
+        ])
+        .success();
+    insta::assert_snapshot!(get_files("@"), @"wc file2[EOF]");
+    insta::assert_snapshot!(get_files("description(sibling)"), @"sibling file1[EOF]");
+
+    // The changes can't be squashed into the same revision
+    let output = work_dir.run_jj(["squash", "--from-op", &snapshot_op_id]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Error: Cannot squash the snapshotted changes into the revision they were snapshotted into
+    [EOF]
+    [exit status: 1]
+    ");
+
+    // The operation must have snapshotted the working copy
+    let output = work_dir.run_jj([
+        "squash",
+        "--from-op",
+        &new_op_id,
+        "--into=description(sibling)",
+    ]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        output
+            .stderr
+            .raw()
+            .contains("didn't snapshot the working copy of this workspace")
+    );
+}
+
+#[test]
+fn test_squash_keep_author() {
+    let test_env = TestEnvironment::default();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    work_dir.write_file("file1", "a\n");
+    work_dir
+        .run_jj([
+            "describe",
+            "--config=user.email=destination@example.com",
+            "--reset-author",
+            "-m=destination",
+        ])
+        .success();
+    work_dir
+        .run_jj(["new", "--config=user.email=source@example.com", "-m=source"])
+        .success();
+    work_dir.write_file("file2", "b\n");
+    work_dir.run_jj(["new", "-m=other"]).success();
+    work_dir.write_file("file3", "c\n");
+    let get_authors = || {
+        let template = r#"separate(" ", description.first_line(), author.email()) ++ "\n""#;
+        work_dir.run_jj(["log", "-T", template])
+    };
+    // Test the setup
+    insta::assert_snapshot!(get_authors(), @r"
+    @  other test.user@example.com
+    ○  source source@example.com
+    ○  destination destination@example.com
+    ◆
+    [EOF]
+    ");
+    let setup_opid = work_dir.current_operation_id();
+
+    // The destination's author is kept by default
+    work_dir
+        .run_jj(["squash", "--from=@-", "--into=@--", "-m=squashed"])
+        .success();
+    insta::assert_snapshot!(get_authors(), @r"
+    @  other test.user@example.com
+    ○  squashed destination@example.com
+    ◆
+    [EOF]
+    ");
+
+    work_dir.run_jj(["op", "restore", &setup_opid]).success();
+    work_dir
+        .run_jj([
+            "squash",
+            "--from=@-",
+            "--into=@--",
+            "-m=squashed",
+            "--keep-author=destination",
+        ])
+        .success();
+    insta::assert_snapshot!(get_authors(), @r"
+    @  other test.user@example.com
+    ○  squashed destination@example.com
+    ◆
+    [EOF]
+    ");
+
+    work_dir.run_jj(["op", "restore", &setup_opid]).success();
+    work_dir
+        .run_jj([
+            "squash",
+            "--from=@-",
+            "--into=@--",
+            "-m=squashed",
+            "--keep-author=source",
+        ])
+        .success();
+    insta::assert_snapshot!(get_authors(), @r"
+    @  other test.user@example.com
+    ○  squashed source@example.com
+    ◆
+    [EOF]
+    ");
+
+    // The source's author is ambiguous with multiple sources
+    work_dir.run_jj(["op", "restore", &setup_opid]).success();
+    let output = work_dir.run_jj([
+        "squash",
+        "--from=@|@-",
+        "--into=@--",
+        "-m=squashed",
+        "--keep-author=source",
+    ]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    Warning: The source revisions include the working-copy commit
+    Hint: Use `--no-working-copy` to exclude it from the source revisions.
+    Error: Cannot keep the author of the source with multiple source revisions
+    [EOF]
+    [exit status: 1]
+    ");
+}
+
 #[test]
 fn test_squash_option_exclusion() {
     let test_env = TestEnvironment::default();
@@ -1778,6 +2471,8 @@ fn test_squash_to_new_commit() {
     ]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
+    Warning: The source revisions include the working-copy commit
+    Hint: Use `--no-working-copy` to exclude it from the source revisions.
     Created new commit yqosqzyt 12bb5aa1 file 3&4
     Rebased 2 descendant commits
     Working copy  (@) now at: spxsnpux 5b7a2ac3 (empty) (no description set)
@@ -1811,6 +2506,8 @@ fn test_squash_to_new_commit() {
     ]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
+    Warning: The source revisions include the working-copy commit
+    Hint: Use `--no-working-copy` to exclude it from the source revisions.
     Created new commit znkkpsqq 71efbc99 file 3&4
     Rebased 1 descendant commits
     Working copy  (@) now at: uuzqqzqu 4a07118a (empty) (no description set)
@@ -1844,6 +2541,8 @@ fn test_squash_to_new_commit() {
     ]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
+    Warning: The source revisions include the working-copy commit
+    Hint: Use `--no-working-copy` to exclude it from the source revisions.
     Created new commit wqnwkozp e70a59b7 file 3&4
     Working copy  (@) now at: mouksmqu ecd9569d (empty) (no description set)
     Parent commit (@-)      : rlvkpnrz 27974c44 file2
@@ -1877,6 +2576,8 @@ fn test_squash_to_new_commit() {
     ]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
+    Warning: The source revisions include the working-copy commit
+    Hint: Use `--no-working-copy` to exclude it from the source revisions.
     Created new commit nkmrtpmo dc2faadd file 3&4
     Rebased 1 descendant commits
     Working copy  (@) now at: ruktrxxu 6d045de7 (empty) (no description set)
@@ -1910,6 +2611,8 @@ fn test_squash_to_new_commit() {
     ]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
+    Warning: The source revisions include the working-copy commit
+    Hint: Use `--no-working-copy` to exclude it from the source revisions.
     Created new commit xtnwkqum 342eb9be file 3&4
     Rebased 1 descendant commits
     Working copy  (@) now at: pqrnrkux 4f456097 (empty) (no description set)
@@ -1945,6 +2648,8 @@ fn test_squash_to_new_commit() {
     ]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
+    Warning: The source revisions include the working-copy commit
+    Hint: Use `--no-working-copy` to exclude it from the source revisions.
     Created new commit wvuyspvk 8a940ae6 file 3&4
     Working copy  (@) now at: pkynqtxp 09bb6d70 (empty) (no description set)
     Parent commit (@-)      : rlvkpnrz 27974c44 file2
@@ -2231,6 +2936,8 @@ fn test_squash_to_new_commit() {
     ]);
     insta::assert_snapshot!(output, @r"
     ------- stderr -------
+    Warning: The source revisions include the working-copy commit
+    Hint: Use `--no-working-copy` to exclude it from the source revisions.
     Created new commit pyoswmwk d5aa6638 file 3&4
     Rebased 1 descendant commits
     Working copy  (@) now at: yqnpwwmq 68513612 (empty) (no description set)
@@ -2375,6 +3082,66 @@ fn test_squash_with_editor_and_empty_message() {
     ");
 }
 
+#[test]
+fn test_squash_describe_after() {
+    let mut test_env = TestEnvironment::default();
+    let edit_script = test_env.set_up_fake_editor();
+    test_env.run_jj_in(".", ["git", "init", "repo"]).success();
+    let work_dir = test_env.work_dir("repo");
+
+    work_dir.run_jj(["describe", "-m", "destination"]).success();
+    work_dir.write_file("file1", "a\n");
+    work_dir.run_jj(["new"]).success();
+    work_dir.write_file("file1", "b\n");
+
+    // The source description is empty, so the destination description would be
+    // used without asking. The editor is seeded with it.
+    std::fs::write(
+        &edit_script,
+        ["dump editor", "write\npolished description"].join("\0"),
+    )
+    .unwrap();
+    work_dir.run_jj(["squash", "--describe-after"]).success();
+    insta::assert_snapshot!(
+        std::fs::read_to_string(test_env.env_root().join("editor")).unwrap(), @r#"
+    destination
+
+    JJ: Change ID: qpvuntsm
+    JJ: This commit contains the following changes:
+    JJ:     A file1
+    JJ:
+    JJ: Lines starting with "JJ:" (like this one) will be removed.
+    "#);
+    insta::assert_snapshot!(get_description(&work_dir, "@-"), @r"
+    polished description
+    [EOF]
+    ");
+
+    // Incompatible with explicit messages
+    let output = work_dir.run_jj(["squash", "--describe-after", "-m", "foo"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    error: the argument '--describe-after' cannot be used with '--message <MESSAGE>'
+
+    Usage: jj squash --describe-after [FILESETS]...
+
+    For more information, try '--help'.
+    [EOF]
+    [exit status: 2]
+    ");
+    let output = work_dir.run_jj(["squash", "--describe-after", "-u"]);
+    insta::assert_snapshot!(output, @r"
+    ------- stderr -------
+    error: the argument '--describe-after' cannot be used with '--use-destination-message'
+
+    Usage: jj squash --describe-after [FILESETS]...
+
+    For more information, try '--help'.
+    [EOF]
+    [exit status: 2]
+    ");
+}
+
 #[must_use]
 fn get_description(work_dir: &TestWorkDir, rev: &str) -> CommandOutput {
     work_dir.run_jj(["log", "--no-graph", "-T", "description", "-r", rev])
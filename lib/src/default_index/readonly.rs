@@ -610,31 +610,85 @@ impl CommitIndexSegment for ReadonlyCommitIndexSegment {
 
 /// Commit index backend which stores data on local disk.
 #[derive(Clone, Debug)]
-pub struct DefaultReadonlyIndex(CompositeIndex);
+pub struct DefaultReadonlyIndex {
+    composite: CompositeIndex,
+    /// Number of missing or corrupt index files which caused this index to be
+    /// rebuilt when it was loaded.
+    num_reindexed_segments: u32,
+    /// Whether this index was rebuilt by the current process.
+    freshly_reindexed: bool,
+}
 
 impl DefaultReadonlyIndex {
     pub(super) fn from_segment(
         commits: Arc<ReadonlyCommitIndexSegment>,
         changed_paths: CompositeChangedPathIndex,
     ) -> Self {
-        Self(CompositeIndex::from_readonly(commits, changed_paths))
+        Self {
+            composite: CompositeIndex::from_readonly(commits, changed_paths),
+            num_reindexed_segments: 0,
+            freshly_reindexed: false,
+        }
+    }
+
+    /// Records that this index was rebuilt by an earlier process because of
+    /// missing or corrupt index files.
+    pub(super) fn with_num_reindexed_segments(self, num_reindexed_segments: u32) -> Self {
+        Self {
+            num_reindexed_segments,
+            ..self
+        }
+    }
+
+    /// Records that this index has just been rebuilt because of missing or
+    /// corrupt index files.
+    pub(super) fn with_freshly_reindexed(self, num_reindexed_segments: u32) -> Self {
+        Self {
+            num_reindexed_segments,
+            freshly_reindexed: true,
+            ..self
+        }
+    }
+
+    /// Number of missing or corrupt index files which caused this index to be
+    /// rebuilt when it was loaded. The count is preserved when the rebuilt
+    /// index is loaded again.
+    pub fn num_reindexed_segments(&self) -> u32 {
+        self.num_reindexed_segments
+    }
+
+    /// Returns true if this index was rebuilt while being loaded by the
+    /// current process.
+    pub fn is_freshly_reindexed(&self) -> bool {
+        self.freshly_reindexed
     }
 
     pub(super) fn readonly_commits(&self) -> &Arc<ReadonlyCommitIndexSegment> {
-        self.0.readonly_commits().expect("must have readonly")
+        self.composite
+            .readonly_commits()
+            .expect("must have readonly")
     }
 
     pub(super) fn changed_paths(&self) -> &CompositeChangedPathIndex {
-        self.0.changed_paths()
+        self.composite.changed_paths()
     }
 
     pub(super) fn has_id_impl(&self, commit_id: &CommitId) -> bool {
-        self.0.commits().has_id(commit_id)
+        self.composite.commits().has_id(commit_id)
     }
 
     /// Returns the number of all indexed commits.
     pub fn num_commits(&self) -> u32 {
-        self.0.commits().num_commits()
+        self.composite.commits().num_commits()
+    }
+
+    /// Returns the range of commit positions covered by the changed-path index,
+    /// or `None` if the changed-path index is disabled.
+    pub fn changed_path_commits_range(&self) -> Option<Range<u32>> {
+        let changed_paths = self.changed_paths();
+        changed_paths
+            .start_commit_pos()
+            .map(|GlobalCommitPosition(start)| start..(start + changed_paths.num_commits()))
     }
 
     /// Collects statistics of indexed commits and segments.
@@ -662,9 +716,7 @@ impl DefaultReadonlyIndex {
         commit_levels.reverse();
 
         let changed_paths = self.changed_paths();
-        let changed_path_commits_range = changed_paths
-            .start_commit_pos()
-            .map(|GlobalCommitPosition(start)| start..(start + changed_paths.num_commits()));
+        let changed_path_commits_range = self.changed_path_commits_range();
         let changed_path_levels = changed_paths
             .readonly_segments()
             .iter()
@@ -685,12 +737,13 @@ impl DefaultReadonlyIndex {
             commit_levels,
             changed_path_commits_range,
             changed_path_levels,
+            num_reindexed_segments: self.num_reindexed_segments,
         }
     }
 
     /// Looks up generation of the specified commit.
     pub fn generation_number(&self, commit_id: &CommitId) -> Option<u32> {
-        let entry = self.0.commits().entry_by_id(commit_id)?;
+        let entry = self.composite.commits().entry_by_id(commit_id)?;
         Some(entry.generation_number())
     }
 
@@ -711,20 +764,21 @@ impl DefaultReadonlyIndex {
 
 impl AsCompositeIndex for DefaultReadonlyIndex {
     fn as_composite(&self) -> &CompositeIndex {
-        &self.0
+        &self.composite
     }
 }
 
 impl Index for DefaultReadonlyIndex {
     fn shortest_unique_commit_id_prefix_len(&self, commit_id: &CommitId) -> IndexResult<usize> {
-        self.0.shortest_unique_commit_id_prefix_len(commit_id)
+        self.composite
+            .shortest_unique_commit_id_prefix_len(commit_id)
     }
 
     fn resolve_commit_id_prefix(
         &self,
         prefix: &HexPrefix,
     ) -> IndexResult<PrefixResolution<CommitId>> {
-        self.0.resolve_commit_id_prefix(prefix)
+        self.composite.resolve_commit_id_prefix(prefix)
     }
 
     fn has_id(&self, commit_id: &CommitId) -> IndexResult<bool> {
@@ -732,26 +786,26 @@ impl Index for DefaultReadonlyIndex {
     }
 
     fn is_ancestor(&self, ancestor_id: &CommitId, descendant_id: &CommitId) -> IndexResult<bool> {
-        self.0.is_ancestor(ancestor_id, descendant_id)
+        self.composite.is_ancestor(ancestor_id, descendant_id)
     }
 
     fn common_ancestors(&self, set1: &[CommitId], set2: &[CommitId]) -> IndexResult<Vec<CommitId>> {
-        self.0.common_ancestors(set1, set2)
+        self.composite.common_ancestors(set1, set2)
     }
 
     fn all_heads_for_gc(&self) -> IndexResult<Box<dyn Iterator<Item = CommitId> + '_>> {
-        self.0.all_heads_for_gc()
+        self.composite.all_heads_for_gc()
     }
 
     fn heads(&self, candidates: &mut dyn Iterator<Item = &CommitId>) -> IndexResult<Vec<CommitId>> {
-        self.0.heads(candidates)
+        self.composite.heads(candidates)
     }
 
     fn changed_paths_in_commit(
         &self,
         commit_id: &CommitId,
     ) -> IndexResult<Option<Box<dyn Iterator<Item = RepoPathBuf> + '_>>> {
-        self.0.changed_paths_in_commit(commit_id)
+        self.composite.changed_paths_in_commit(commit_id)
     }
 
     fn evaluate_revset(
@@ -759,7 +813,7 @@ impl Index for DefaultReadonlyIndex {
         expression: &ResolvedExpression,
         store: &Arc<Store>,
     ) -> Result<Box<dyn Revset + '_>, RevsetEvaluationError> {
-        self.0.evaluate_revset(expression, store)
+        self.composite.evaluate_revset(expression, store)
     }
 }
 
@@ -809,6 +863,9 @@ pub struct IndexStats {
     pub commit_levels: Vec<CommitIndexLevelStats>,
     pub changed_path_commits_range: Option<Range<u32>>,
     pub changed_path_levels: Vec<ChangedPathIndexLevelStats>,
+    /// Number of missing or corrupt index files which caused the index to be
+    /// rebuilt when it was loaded.
+    pub num_reindexed_segments: u32,
 }
 
 #[derive(Clone, Debug)]
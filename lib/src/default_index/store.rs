@@ -32,6 +32,7 @@ use thiserror::Error;
 
 use super::changed_path::ChangedPathIndexSegmentId;
 use super::changed_path::CompositeChangedPathIndex;
+use super::changed_path::ReadonlyChangedPathIndexSegment;
 use super::changed_path::collect_changed_paths;
 use super::composite::AsCompositeIndex as _;
 use super::composite::CommitIndexSegmentId;
@@ -177,11 +178,119 @@ impl DefaultIndexStore {
         self.dir.join("changed_paths")
     }
 
-    fn load_index_at_operation(
+    /// Directory for markers of operations which had to be reindexed when
+    /// loaded.
+    fn reindexed_ops_dir(&self) -> PathBuf {
+        self.dir.join("reindexed_ops")
+    }
+
+    /// Returns true if loading the index at the given operation triggered
+    /// reindexing because of missing or corrupt index files.
+    pub fn is_reindexed_at_operation(&self, op_id: &OperationId) -> bool {
+        self.reindexed_ops_dir().join(op_id.hex()).is_file()
+    }
+
+    /// Returns the number of missing or corrupt index files recorded when the
+    /// given operation was reindexed.
+    fn num_reindexed_segments_at_operation(&self, op_id: &OperationId) -> u32 {
+        let path = self.reindexed_ops_dir().join(op_id.hex());
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|data| data.trim().parse().ok())
+            .unwrap_or(0)
+    }
+
+    fn record_reindexed_operation(
         &self,
         op_id: &OperationId,
-        lengths: FieldLengths,
+        num_reindexed_segments: u32,
+    ) -> Result<(), PathError> {
+        let dir = self.reindexed_ops_dir();
+        file_util::create_or_reuse_dir(&dir).context(&dir)?;
+        let path = dir.join(op_id.hex());
+        fs::write(&path, num_reindexed_segments.to_string()).context(&path)
+    }
+
+    /// Counts the given operation and its ancestors which have no associated
+    /// index files, up to the nearest indexed ancestor.
+    fn count_unindexed_operations(
+        &self,
+        operation: &Operation,
+    ) -> Result<u32, DefaultIndexStoreError> {
+        let op_links_dir = self.op_links_dir();
+        let legacy_operations_dir = self.legacy_operations_dir();
+        let mut count = 0;
+        for op in op_walk::walk_ancestors(slice::from_ref(operation)) {
+            let op = op?;
+            if op_links_dir.join(op.id().hex()).is_file()
+                || legacy_operations_dir.join(op.id().hex()).is_file()
+            {
+                break;
+            }
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Counts the index segment files associated with the given operation
+    /// which are missing or corrupt.
+    ///
+    /// A commit segment file can't be told apart from its ancestors, so a
+    /// broken chain of commit segments is counted once.
+    fn count_unloadable_segments(&self, op_id: &OperationId, lengths: FieldLengths) -> u32 {
+        let Ok((commit_segment_id, _, changed_path_segment_ids)) = self.read_operation_link(op_id)
+        else {
+            return 1;
+        };
+        let commit_segments_dir = self.commit_segments_dir();
+        let changed_path_segments_dir = self.changed_path_segments_dir();
+        let num_commit_segments =
+            ReadonlyCommitIndexSegment::load(&commit_segments_dir, commit_segment_id, lengths)
+                .is_err();
+        let num_changed_path_segments = changed_path_segment_ids
+            .into_iter()
+            .filter(|id| {
+                ReadonlyChangedPathIndexSegment::load(&changed_path_segments_dir, id.clone())
+                    .is_err()
+            })
+            .count();
+        u32::from(num_commit_segments) + u32::try_from(num_changed_path_segments).unwrap()
+    }
+
+    /// Rebuilds index for the given `operation` which couldn't be loaded
+    /// because of `num_reindexed_segments` missing or corrupt index files, and
+    /// records that the operation was reindexed.
+    fn reindex_at_operation(
+        &self,
+        operation: &Operation,
+        store: &Arc<Store>,
+        num_reindexed_segments: u32,
     ) -> Result<DefaultReadonlyIndex, DefaultIndexStoreError> {
+        let index = self.build_index_at_operation(operation, store).block_on()?;
+        // The marker is informational, so failing to write it shouldn't
+        // prevent the repo from being loaded.
+        if let Err(err) = self.record_reindexed_operation(operation.id(), num_reindexed_segments) {
+            tracing::warn!(
+                op_id = %operation.id().hex(),
+                %err,
+                "failed to record reindexed operation"
+            );
+        }
+        Ok(index.with_freshly_reindexed(num_reindexed_segments))
+    }
+
+    /// Reads the index segment ids associated with the given operation.
+    fn read_operation_link(
+        &self,
+        op_id: &OperationId,
+    ) -> Result<
+        (
+            CommitIndexSegmentId,
+            Option<GlobalCommitPosition>,
+            Vec<ChangedPathIndexSegmentId>,
+        ),
+        DefaultIndexStoreError,
+    > {
         let commit_segment_id;
         let changed_path_start_commit_pos;
         let changed_path_segment_ids;
@@ -219,7 +328,20 @@ impl DefaultIndexStore {
             }
             Err(err) => return Err(DefaultIndexStoreError::LoadAssociation(err)),
         };
+        Ok((
+            commit_segment_id,
+            changed_path_start_commit_pos,
+            changed_path_segment_ids,
+        ))
+    }
 
+    fn load_index_at_operation(
+        &self,
+        op_id: &OperationId,
+        lengths: FieldLengths,
+    ) -> Result<DefaultReadonlyIndex, DefaultIndexStoreError> {
+        let (commit_segment_id, changed_path_start_commit_pos, changed_path_segment_ids) =
+            self.read_operation_link(op_id)?;
         let commits = ReadonlyCommitIndexSegment::load(
             &self.commit_segments_dir(),
             commit_segment_id,
@@ -237,7 +359,9 @@ impl DefaultIndexStore {
         } else {
             CompositeChangedPathIndex::null()
         };
-        Ok(DefaultReadonlyIndex::from_segment(commits, changed_paths))
+        let num_reindexed_segments = self.num_reindexed_segments_at_operation(op_id);
+        Ok(DefaultReadonlyIndex::from_segment(commits, changed_paths)
+            .with_num_reindexed_segments(num_reindexed_segments))
     }
 
     /// Rebuilds index for the given `operation`.
@@ -487,6 +611,32 @@ impl DefaultIndexStore {
         Ok(index)
     }
 
+    /// Drops changed-path index for the specified operation.
+    ///
+    /// Descendant operations will no longer index changed paths until the
+    /// index is built again. Segment files are left on disk since they may be
+    /// referenced by other operations.
+    #[tracing::instrument(skip(self, store))]
+    pub fn drop_changed_path_index_at_operation(
+        &self,
+        op_id: &OperationId,
+        store: &Arc<Store>,
+    ) -> Result<DefaultReadonlyIndex, DefaultIndexStoreError> {
+        let field_lengths = FieldLengths {
+            commit_id: store.commit_id_length(),
+            change_id: store.change_id_length(),
+        };
+        let index = self.load_index_at_operation(op_id, field_lengths)?;
+        let commits = index.readonly_commits().clone();
+        let index = DefaultReadonlyIndex::from_segment(commits, CompositeChangedPathIndex::null());
+        self.associate_index_with_operation(&index, op_id)
+            .map_err(|source| DefaultIndexStoreError::AssociateIndex {
+                op_id: op_id.to_owned(),
+                source,
+            })?;
+        Ok(index)
+    }
+
     fn save_mutable_index(
         &self,
         index: DefaultMutableIndex,
@@ -570,9 +720,37 @@ impl IndexStore for DefaultIndexStore {
             Err(DefaultIndexStoreError::LoadAssociation(PathError { source: error, .. }))
                 if error.kind() == io::ErrorKind::NotFound =>
             {
-                self.build_index_at_operation(op, store).block_on()
+                tracing::warn!(
+                    op_id = %op.id().hex(),
+                    "operation link file not found, indexing operation"
+                );
+                let num_reindexed_segments = self
+                    .count_unindexed_operations(op)
+                    .map_err(|err| IndexStoreError::Read(err.into()))?;
+                self.reindex_at_operation(op, store, num_reindexed_segments)
+            }
+            Err(DefaultIndexStoreError::LoadAssociation(PathError {
+                source: error,
+                path,
+            })) if error.kind() == io::ErrorKind::InvalidData => {
+                // The operation link file would be picked up again by
+                // build_index_at_operation(), so reindex from scratch.
+                tracing::warn!(
+                    op_id = %op.id().hex(),
+                    path = %path.display(),
+                    %error,
+                    "operation link file is corrupt, reindexing"
+                );
+                self.reinit()
+                    .map_err(|err| IndexStoreError::Read(err.into()))?;
+                self.reindex_at_operation(op, store, 1)
             }
             Err(DefaultIndexStoreError::LoadIndex(err)) if err.is_corrupt_or_not_found() => {
+                tracing::warn!(
+                    op_id = %op.id().hex(),
+                    error = %err,
+                    "index segment file is missing or corrupt, reindexing"
+                );
                 // If the index was corrupt (maybe it was written in a different format),
                 // we just reindex.
                 match &err {
@@ -590,9 +768,12 @@ impl IndexStore for DefaultIndexStore {
                         eprintln!("{err} (maybe the format has changed): {error}. Reindexing...");
                     }
                 }
+                let num_reindexed_segments = self
+                    .count_unloadable_segments(op.id(), field_lengths)
+                    .max(1);
                 self.reinit()
                     .map_err(|err| IndexStoreError::Read(err.into()))?;
-                self.build_index_at_operation(op, store).block_on()
+                self.reindex_at_operation(op, store, num_reindexed_segments)
             }
             result => result,
         }
@@ -21,8 +21,12 @@ use std::collections::HashSet;
 use std::default::Default;
 use std::fs::File;
 use std::num::NonZeroU32;
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Instant;
 
 use bstr::BStr;
 use bstr::BString;
@@ -35,6 +39,7 @@ use thiserror::Error;
 use crate::backend::BackendError;
 use crate::backend::BackendResult;
 use crate::backend::CommitId;
+use crate::backend::MillisSinceEpoch;
 use crate::backend::TreeValue;
 use crate::commit::Commit;
 use crate::file_util::IoResultExt as _;
@@ -141,7 +146,7 @@ impl<'a: 'b, 'b> Borrow<RemoteRefSymbol<'b>> for RemoteRefKey<'a> {
 /// It is often the case that we need only parts of the refspec,
 /// Passing strings around and repeatedly parsing them is sub-optimal, confusing
 /// and error prone
-#[derive(Debug, Hash, PartialEq, Eq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub(crate) struct RefSpec {
     forced: bool,
     // Source and destination may be fully-qualified ref name, glob pattern, or
@@ -191,7 +196,7 @@ impl RefSpec {
 }
 
 /// Representation of a negative Git refspec
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 #[repr(transparent)]
 pub(crate) struct NegativeRefSpec {
     source: String,
@@ -2003,10 +2008,15 @@ fn remove_remote_refs(mut_repo: &mut MutableRepo, remote: &RemoteName) {
     }
 }
 
+/// Renames the Git remote `old_remote_name` to `new_remote_name`.
+///
+/// If `rename_refs` is false, the remote-tracking refs are left under the old
+/// remote name.
 pub fn rename_remote(
     mut_repo: &mut MutableRepo,
     old_remote_name: &RemoteName,
     new_remote_name: &RemoteName,
+    rename_refs: bool,
 ) -> Result<(), GitRemoteManagementError> {
     let mut git_repo = get_git_repo(mut_repo.store())?;
 
@@ -2052,11 +2062,13 @@ pub fn rename_remote(
     remove_remote_git_config_sections(&mut config, old_remote_name)?;
     save_git_config(&config).map_err(GitRemoteManagementError::GitConfigSaveError)?;
 
-    rename_remote_git_refs(&mut git_repo, old_remote_name, new_remote_name)
-        .map_err(GitRemoteManagementError::from_git)?;
+    if rename_refs {
+        rename_remote_git_refs(&mut git_repo, old_remote_name, new_remote_name)
+            .map_err(GitRemoteManagementError::from_git)?;
 
-    if old_remote_name != REMOTE_NAME_FOR_LOCAL_GIT_REPO {
-        rename_remote_refs(mut_repo, old_remote_name, new_remote_name);
+        if old_remote_name != REMOTE_NAME_FOR_LOCAL_GIT_REPO {
+            rename_remote_refs(mut_repo, old_remote_name, new_remote_name);
+        }
     }
 
     Ok(())
@@ -2117,10 +2129,15 @@ where
     let mut new_remote = remote.repo().remote_at(url)?;
     // Copy the existing data from `remote`.
     //
-    // We don’t copy the push URL, as there does not seem to be any way to reliably
-    // detect whether one is present with the current API, and `jj git remote
-    // set-url` refuses to work with them anyway.
+    // The push URL is copied only if one is configured. Otherwise, pushing
+    // keeps falling back to the new fetch URL.
     new_remote = new_remote.with_fetch_tags(remote.fetch_tags());
+    if let Some(push_url) = remote_configured_push_url(&remote) {
+        // The generic error type has to be specified because it would be
+        // inferred from the `E` bound of this function.
+        new_remote = new_remote
+            .with_push_url_without_url_rewrite::<_, std::convert::Infallible>(push_url.clone())?;
+    }
     for direction in [gix::remote::Direction::Fetch, gix::remote::Direction::Push] {
         new_remote
             .replace_refspecs(
@@ -2135,13 +2152,24 @@ where
     Ok(new_remote)
 }
 
-pub fn set_remote_url(
-    store: &Store,
-    remote_name: &RemoteName,
-    new_remote_url: &str,
-) -> Result<(), GitRemoteManagementError> {
-    let git_repo = get_git_repo(store)?;
+/// Returns the push URL of the remote if one is configured.
+///
+/// `gix::Remote::url()` falls back to the fetch URL, so the config is checked
+/// for an explicit `pushurl` entry.
+fn remote_configured_push_url<'a>(remote: &'a gix::Remote) -> Option<&'a gix::Url> {
+    let name = remote.name()?;
+    remote.repo().config_snapshot().plumbing().string_by(
+        "remote",
+        Some(name.as_bstr()),
+        "pushurl",
+    )?;
+    remote.url(gix::remote::Direction::Push)
+}
 
+fn find_remote_to_update<'repo>(
+    git_repo: &'repo gix::Repository,
+    remote_name: &RemoteName,
+) -> Result<gix::Remote<'repo>, GitRemoteManagementError> {
     validate_remote_name(remote_name)?;
 
     let Some(result) = git_repo.try_find_remote_without_url_rewrite(remote_name.as_str()) else {
@@ -2149,22 +2177,60 @@ pub fn set_remote_url(
             remote_name.to_owned(),
         ));
     };
-    let mut remote = result.map_err(GitRemoteManagementError::from_git)?;
+    result.map_err(GitRemoteManagementError::from_git)
+}
 
-    if remote.url(gix::remote::Direction::Push) != remote.url(gix::remote::Direction::Fetch) {
-        return Err(GitRemoteManagementError::NonstandardConfiguration(
-            remote_name.to_owned(),
-        ));
-    }
+/// Sets the URL used to fetch from the remote. A separately configured push
+/// URL is preserved.
+///
+/// Returns the previous fetch URL.
+pub fn set_remote_url(
+    store: &Store,
+    remote_name: &RemoteName,
+    new_remote_url: &str,
+) -> Result<Option<BString>, GitRemoteManagementError> {
+    let git_repo = get_git_repo(store)?;
+    let remote = find_remote_to_update(&git_repo, remote_name)?;
+    let old_remote_url = remote
+        .url(gix::remote::Direction::Fetch)
+        .map(|url| url.to_bstring());
 
-    remote = gix_remote_with_fetch_url(remote, new_remote_url)
+    let mut remote = gix_remote_with_fetch_url(remote, new_remote_url)
         .map_err(GitRemoteManagementError::from_git)?;
 
     let mut config = git_repo.config_snapshot().clone();
     save_remote(&mut config, remote_name, &mut remote)?;
     save_git_config(&config).map_err(GitRemoteManagementError::GitConfigSaveError)?;
 
-    Ok(())
+    Ok(old_remote_url)
+}
+
+/// Sets a separate URL used to push to the remote.
+///
+/// Returns the previous push URL, which is the fetch URL if no push URL was
+/// configured.
+pub fn set_remote_push_url(
+    store: &Store,
+    remote_name: &RemoteName,
+    new_push_url: &str,
+) -> Result<Option<BString>, GitRemoteManagementError> {
+    let git_repo = get_git_repo(store)?;
+    let remote = find_remote_to_update(&git_repo, remote_name)?;
+    let old_push_url = remote
+        .url(gix::remote::Direction::Push)
+        .map(|url| url.to_bstring());
+
+    let new_push_url =
+        gix::Url::from_bytes(new_push_url.into()).map_err(GitRemoteManagementError::from_git)?;
+    let mut remote = remote
+        .with_push_url_without_url_rewrite(new_push_url)
+        .map_err(GitRemoteManagementError::from_git)?;
+
+    let mut config = git_repo.config_snapshot().clone();
+    save_remote(&mut config, remote_name, &mut remote)?;
+    save_git_config(&config).map_err(GitRemoteManagementError::GitConfigSaveError)?;
+
+    Ok(old_push_url)
 }
 
 fn rename_remote_refs(
@@ -2204,6 +2270,25 @@ pub enum GitFetchError {
     Subprocess(#[from] GitSubprocessError),
 }
 
+impl GitFetchError {
+    /// Returns true if the fetch may succeed if retried.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::NoSuchRemote(_) | Self::RemoteName(_) => false,
+            Self::Subprocess(err) => err.is_retryable(),
+        }
+    }
+
+    /// Returns true if the fetch failed while communicating with the remote,
+    /// e.g. because the remote couldn't be reached.
+    pub fn is_transport_error(&self) -> bool {
+        match self {
+            Self::NoSuchRemote(_) | Self::RemoteName(_) => false,
+            Self::Subprocess(err) => err.is_transport_error(),
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum GitDefaultRefspecError {
     #[error("No git remote named '{}'", .0.as_symbol())]
@@ -2218,7 +2303,7 @@ struct FetchedBranches {
 }
 
 /// Represents the refspecs to fetch from a remote
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ExpandedFetchRefSpecs {
     // NB: branch names need not necessarily map 1:1 with refspecs below:
     // for example, we can have negative refspecs in which case there will not
@@ -2457,60 +2542,26 @@ impl<'a> GitFetch<'a> {
         remote_name: &RemoteName,
         ExpandedFetchRefSpecs {
             expected_branch_names,
-            refspecs: mut remaining_refspecs,
+            refspecs,
             negative_refspecs,
         }: ExpandedFetchRefSpecs,
         mut callbacks: RemoteCallbacks,
         depth: Option<NonZeroU32>,
+        shallow_since: Option<MillisSinceEpoch>,
         fetch_tags_override: Option<FetchTagsOverride>,
     ) -> Result<(), GitFetchError> {
-        validate_remote_name(remote_name)?;
-
-        // check the remote exists
-        if self
-            .git_repo
-            .try_find_remote(remote_name.as_str())
-            .is_none()
-        {
-            return Err(GitFetchError::NoSuchRemote(remote_name.to_owned()));
-        }
-
-        if remaining_refspecs.is_empty() {
-            // Don't fall back to the base refspecs.
-            return Ok(());
-        }
-
-        let mut branches_to_prune = Vec::new();
-        // git unfortunately errors out if one of the many refspecs is not found
-        //
-        // our approach is to filter out failures and retry,
-        // until either all have failed or an attempt has succeeded
-        //
-        // even more unfortunately, git errors out one refspec at a time,
-        // meaning that the below cycle runs in O(#failed refspecs)
-        while let Some(failing_refspec) = self.git_ctx.spawn_fetch(
+        self.check_remote(remote_name)?;
+        fetch_refspecs(
+            &self.git_ctx,
+            self.git_settings,
             remote_name,
-            &remaining_refspecs,
+            refspecs,
             &negative_refspecs,
             &mut callbacks,
             depth,
+            shallow_since,
             fetch_tags_override,
-        )? {
-            tracing::debug!(failing_refspec, "failed to fetch ref");
-            remaining_refspecs.retain(|r| r.source.as_ref() != Some(&failing_refspec));
-
-            if let Some(branch_name) = failing_refspec.strip_prefix("refs/heads/") {
-                branches_to_prune.push(format!(
-                    "{remote_name}/{branch_name}",
-                    remote_name = remote_name.as_str()
-                ));
-            }
-        }
-
-        // Even if git fetch has --prune, if a branch is not found it will not be
-        // pruned on fetch
-        self.git_ctx.spawn_branch_prune(&branches_to_prune)?;
-
+        )?;
         self.fetched.push(FetchedBranches {
             remote: remote_name.to_owned(),
             branches: expected_branch_names,
@@ -2518,6 +2569,80 @@ impl<'a> GitFetch<'a> {
         Ok(())
     }
 
+    /// Performs `git fetch` from multiple remotes concurrently, running at
+    /// most `max_concurrent` fetches at a time.
+    ///
+    /// Returns the result of each request in the order of `requests`. Like
+    /// `fetch()`, the successfully fetched remotes are imported by calling
+    /// `import_refs()`. No progress is reported.
+    #[tracing::instrument(skip(self, requests))]
+    pub fn fetch_concurrently(
+        &mut self,
+        requests: &[(&RemoteName, ExpandedFetchRefSpecs)],
+        max_concurrent: NonZeroUsize,
+        depth: Option<NonZeroU32>,
+        shallow_since: Option<MillisSinceEpoch>,
+        fetch_tags_override: Option<FetchTagsOverride>,
+    ) -> Vec<Result<(), GitFetchError>> {
+        let mut results = requests
+            .iter()
+            .map(|&(remote_name, _)| self.check_remote(remote_name))
+            .collect_vec();
+        let pending = Mutex::new(
+            requests
+                .iter()
+                .zip(&mut results)
+                .filter(|(_, result)| result.is_ok()),
+        );
+        let git_ctx = &self.git_ctx;
+        let git_settings = self.git_settings;
+        thread::scope(|scope| {
+            for _ in 0..max_concurrent.get().min(requests.len()) {
+                scope.spawn(|| {
+                    loop {
+                        // Release the lock before fetching
+                        let next = pending.lock().unwrap().next();
+                        let Some(((remote_name, expanded), result)) = next else {
+                            break;
+                        };
+                        *result = fetch_refspecs(
+                            git_ctx,
+                            git_settings,
+                            remote_name,
+                            expanded.refspecs.clone(),
+                            &expanded.negative_refspecs,
+                            &mut RemoteCallbacks::default(),
+                            depth,
+                            shallow_since,
+                            fetch_tags_override,
+                        );
+                    }
+                });
+            }
+        });
+        for ((remote_name, expanded), result) in requests.iter().zip(&results) {
+            if result.is_ok() {
+                self.fetched.push(FetchedBranches {
+                    remote: (*remote_name).to_owned(),
+                    branches: expanded.expected_branch_names.clone(),
+                });
+            }
+        }
+        results
+    }
+
+    fn check_remote(&self, remote_name: &RemoteName) -> Result<(), GitFetchError> {
+        validate_remote_name(remote_name)?;
+        if self
+            .git_repo
+            .try_find_remote(remote_name.as_str())
+            .is_none()
+        {
+            return Err(GitFetchError::NoSuchRemote(remote_name.to_owned()));
+        }
+        Ok(())
+    }
+
     /// Queries remote for the default branch name.
     #[tracing::instrument(skip(self))]
     pub fn get_default_branch(
@@ -2546,6 +2671,8 @@ impl<'a> GitFetch<'a> {
     #[tracing::instrument(skip(self))]
     pub fn import_refs(&mut self) -> Result<GitImportStats, GitImportError> {
         tracing::debug!("import_refs");
+        // Shallow fetch may have changed the list of shallow commits.
+        get_git_backend(self.mut_repo.store())?.refresh_shallow_root_ids();
         let import_stats =
             import_some_refs(
                 self.mut_repo,
@@ -2571,6 +2698,72 @@ impl<'a> GitFetch<'a> {
     }
 }
 
+/// Runs `git fetch` from a single remote, updating the remote-tracking
+/// branches in the git repo.
+#[expect(clippy::too_many_arguments)]
+fn fetch_refspecs(
+    git_ctx: &GitSubprocessContext,
+    git_settings: &GitSettings,
+    remote_name: &RemoteName,
+    mut remaining_refspecs: Vec<RefSpec>,
+    negative_refspecs: &[NegativeRefSpec],
+    callbacks: &mut RemoteCallbacks,
+    depth: Option<NonZeroU32>,
+    shallow_since: Option<MillisSinceEpoch>,
+    fetch_tags_override: Option<FetchTagsOverride>,
+) -> Result<(), GitFetchError> {
+    if remaining_refspecs.is_empty() {
+        // Don't fall back to the base refspecs.
+        return Ok(());
+    }
+
+    // The timeout applies to the whole fetch from this remote, including
+    // retries.
+    let fetch_timeout = git_settings.fetch_timeout;
+    let deadline = fetch_timeout.map(|timeout| Instant::now() + timeout);
+    let mut branches_to_prune = Vec::new();
+    // git unfortunately errors out if one of the many refspecs is not found
+    //
+    // our approach is to filter out failures and retry,
+    // until either all have failed or an attempt has succeeded
+    //
+    // even more unfortunately, git errors out one refspec at a time,
+    // meaning that the below cycle runs in O(#failed refspecs)
+    while let Some(failing_refspec) = git_ctx
+        .spawn_fetch(
+            remote_name,
+            &remaining_refspecs,
+            negative_refspecs,
+            callbacks,
+            depth,
+            shallow_since,
+            fetch_tags_override,
+            git_settings.fetch_jobs,
+            deadline.map(|deadline| deadline.saturating_duration_since(Instant::now())),
+        )
+        .map_err(|err| match (err, fetch_timeout) {
+            // Report the configured timeout rather than the remaining time
+            (GitSubprocessError::Timeout(_), Some(timeout)) => GitSubprocessError::Timeout(timeout),
+            (err, _) => err,
+        })?
+    {
+        tracing::debug!(failing_refspec, "failed to fetch ref");
+        remaining_refspecs.retain(|r| r.source.as_ref() != Some(&failing_refspec));
+
+        if let Some(branch_name) = failing_refspec.strip_prefix("refs/heads/") {
+            branches_to_prune.push(format!(
+                "{remote_name}/{branch_name}",
+                remote_name = remote_name.as_str()
+            ));
+        }
+    }
+
+    // Even if git fetch has --prune, if a branch is not found it will not be
+    // pruned on fetch
+    git_ctx.spawn_branch_prune(&branches_to_prune)?;
+    Ok(())
+}
+
 #[derive(Error, Debug)]
 pub enum GitPushError {
     #[error("No git remote named '{}'", .0.as_symbol())]
@@ -2608,16 +2801,7 @@ pub fn push_branches(
 ) -> Result<GitPushStats, GitPushError> {
     validate_remote_name(remote)?;
 
-    let ref_updates = targets
-        .branch_updates
-        .iter()
-        .map(|(name, update)| GitRefUpdate {
-            qualified_name: format!("refs/heads/{name}", name = name.as_str()).into(),
-            expected_current_target: update.old_target.clone(),
-            new_target: update.new_target.clone(),
-        })
-        .collect_vec();
-
+    let ref_updates = branch_push_ref_updates(targets);
     let push_stats = push_updates(mut_repo, git_settings, remote, &ref_updates, callbacks)?;
     tracing::debug!(?push_stats);
 
@@ -2644,12 +2828,55 @@ pub fn push_branches(
     Ok(push_stats)
 }
 
+fn branch_push_ref_updates(targets: &GitBranchPushTargets) -> Vec<GitRefUpdate> {
+    targets
+        .branch_updates
+        .iter()
+        .map(|(name, update)| GitRefUpdate {
+            qualified_name: format!("refs/heads/{name}", name = name.as_str()).into(),
+            expected_current_target: update.old_target.clone(),
+            new_target: update.new_target.clone(),
+        })
+        .collect_vec()
+}
+
 /// Pushes the specified Git refs without updating the repo view.
 pub fn push_updates(
     repo: &dyn Repo,
     git_settings: &GitSettings,
     remote_name: &RemoteName,
     updates: &[GitRefUpdate],
+    callbacks: RemoteCallbacks,
+) -> Result<GitPushStats, GitPushError> {
+    push_updates_impl(repo, git_settings, remote_name, updates, false, callbacks)
+}
+
+/// Checks whether the specified branches could be pushed, without sending
+/// anything to the remote.
+///
+/// The remote is contacted to verify that the branches are still at their
+/// expected locations, so the returned stats report the same rejections as
+/// [`push_branches()`] would.
+pub fn dry_run_push_branches(
+    repo: &dyn Repo,
+    git_settings: &GitSettings,
+    remote: &RemoteName,
+    targets: &GitBranchPushTargets,
+    callbacks: RemoteCallbacks,
+) -> Result<GitPushStats, GitPushError> {
+    validate_remote_name(remote)?;
+    let ref_updates = branch_push_ref_updates(targets);
+    let push_stats = push_updates_impl(repo, git_settings, remote, &ref_updates, true, callbacks)?;
+    tracing::debug!(?push_stats);
+    Ok(push_stats)
+}
+
+fn push_updates_impl(
+    repo: &dyn Repo,
+    git_settings: &GitSettings,
+    remote_name: &RemoteName,
+    updates: &[GitRefUpdate],
+    dry_run: bool,
     mut callbacks: RemoteCallbacks,
 ) -> Result<GitPushStats, GitPushError> {
     let mut qualified_remote_refs_expected_locations = HashMap::new();
@@ -2687,7 +2914,7 @@ pub fn push_updates(
         .map(|full_refspec| RefToPush::new(full_refspec, &qualified_remote_refs_expected_locations))
         .collect();
 
-    let mut push_stats = git_ctx.spawn_push(remote_name, &refs_to_push, &mut callbacks)?;
+    let mut push_stats = git_ctx.spawn_push(remote_name, &refs_to_push, dry_run, &mut callbacks)?;
     push_stats.pushed.sort();
     push_stats.rejected.sort();
     push_stats.remote_rejected.sort();
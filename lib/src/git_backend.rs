@@ -40,7 +40,6 @@ use gix::bstr::BString;
 use gix::objs::CommitRefIter;
 use gix::objs::WriteTo as _;
 use itertools::Itertools as _;
-use once_cell::sync::OnceCell as OnceLock;
 use pollster::FutureExt as _;
 use prost::Message as _;
 use smallvec::SmallVec;
@@ -170,7 +169,7 @@ pub struct GitBackend {
     root_commit_id: CommitId,
     root_change_id: ChangeId,
     empty_tree_id: TreeId,
-    shallow_root_ids: OnceLock<Vec<CommitId>>,
+    shallow_root_ids: Mutex<Option<Arc<[CommitId]>>>,
     extra_metadata_store: TableStore,
     cached_extra_metadata: Mutex<Option<Arc<ReadonlyTable>>>,
     git_executable: PathBuf,
@@ -197,7 +196,7 @@ impl GitBackend {
             root_commit_id,
             root_change_id,
             empty_tree_id,
-            shallow_root_ids: OnceLock::new(),
+            shallow_root_ids: Mutex::new(None),
             extra_metadata_store,
             cached_extra_metadata: Mutex::new(None),
             git_executable: git_settings.executable_path,
@@ -351,23 +350,32 @@ impl GitBackend {
         self.base_repo.work_dir()
     }
 
-    fn shallow_root_ids(&self, git_repo: &gix::Repository) -> BackendResult<&[CommitId]> {
+    fn shallow_root_ids(&self, git_repo: &gix::Repository) -> BackendResult<Arc<[CommitId]>> {
         // The list of shallow roots is cached by gix, but it's still expensive
         // to stat file on every read_object() call. Refreshing shallow roots is
-        // also bad for consistency reasons.
-        self.shallow_root_ids
-            .get_or_try_init(|| {
+        // also bad for consistency reasons, so it's done only when requested.
+        let mut locked_ids = self.shallow_root_ids.lock().unwrap();
+        match locked_ids.as_ref() {
+            Some(commit_ids) => Ok(commit_ids.clone()),
+            None => {
                 let maybe_oids = git_repo
                     .shallow_commits()
                     .map_err(|err| BackendError::Other(err.into()))?;
-                let commit_ids = maybe_oids.map_or(vec![], |oids| {
+                let commit_ids: Arc<[CommitId]> = maybe_oids.map_or(Arc::from([]), |oids| {
                     oids.iter()
                         .map(|oid| CommitId::from_bytes(oid.as_bytes()))
                         .collect()
                 });
+                *locked_ids = Some(commit_ids.clone());
                 Ok(commit_ids)
-            })
-            .map(AsRef::as_ref)
+            }
+        }
+    }
+
+    /// Discards the cached list of shallow commits, which may be updated by
+    /// `git fetch`.
+    pub fn refresh_shallow_root_ids(&self) {
+        *self.shallow_root_ids.lock().unwrap() = None;
     }
 
     fn cached_extra_metadata_table(&self) -> BackendResult<Arc<ReadonlyTable>> {
@@ -445,7 +453,7 @@ impl GitBackend {
             &mut mut_table,
             &table_lock,
             &head_ids,
-            self.shallow_root_ids(&locked_repo)?,
+            &self.shallow_root_ids(&locked_repo)?,
         )?;
         self.save_extra_metadata_table(mut_table, &table_lock)
     }
@@ -22,12 +22,16 @@ use std::process::Child;
 use std::process::Command;
 use std::process::Output;
 use std::process::Stdio;
+use std::sync::mpsc;
 use std::thread;
+use std::time::Duration;
+use std::time::Instant;
 
 use bstr::ByteSlice as _;
 use itertools::Itertools as _;
 use thiserror::Error;
 
+use crate::backend::MillisSinceEpoch;
 use crate::git::FetchTagsOverride;
 use crate::git::GitPushStats;
 use crate::git::NegativeRefSpec;
@@ -72,8 +76,25 @@ pub enum GitSubprocessError {
          {MINIMUM_GIT_VERSION})"
     )]
     UnsupportedGitOption(String),
+    #[error("Git process failed to connect to the remote: {0}")]
+    Connection(String),
     #[error("Git process failed: {0}")]
     External(String),
+    #[error("Git process timed out after {0:?}")]
+    Timeout(Duration),
+}
+
+impl GitSubprocessError {
+    /// Returns true if the operation may succeed if retried.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Timeout(_))
+    }
+
+    /// Returns true if the git process couldn't connect to the remote, or the
+    /// connection was lost.
+    pub fn is_transport_error(&self) -> bool {
+        matches!(self, Self::Connection(_) | Self::Timeout(_))
+    }
 }
 
 /// Context for creating Git subprocesses
@@ -161,6 +182,7 @@ impl<'a> GitSubprocessContext<'a> {
     ///
     /// This returns a fully qualified ref that wasn't fetched successfully
     /// Note that git only returns one failed ref at a time
+    #[expect(clippy::too_many_arguments)]
     pub(crate) fn spawn_fetch(
         &self,
         remote_name: &RemoteName,
@@ -168,13 +190,21 @@ impl<'a> GitSubprocessContext<'a> {
         negative_refspecs: &[NegativeRefSpec],
         callbacks: &mut RemoteCallbacks<'_>,
         depth: Option<NonZeroU32>,
+        shallow_since: Option<MillisSinceEpoch>,
         fetch_tags_override: Option<FetchTagsOverride>,
+        jobs: Option<NonZeroU32>,
+        timeout: Option<Duration>,
     ) -> Result<Option<String>, GitSubprocessError> {
         if refspecs.is_empty() {
             return Ok(None);
         }
         let mut command = self.create_command();
         command.stdout(Stdio::piped());
+        // index-pack resolves the deltas of the received pack using this many
+        // threads
+        if let Some(jobs) = jobs {
+            command.arg("-c").arg(format!("pack.threads={jobs}"));
+        }
         // attempt to prune stale refs with --prune
         // --no-write-fetch-head ensures our request is invisible to other parties
         command.args(["fetch", "--prune", "--no-write-fetch-head"]);
@@ -184,6 +214,13 @@ impl<'a> GitSubprocessContext<'a> {
         if let Some(d) = depth {
             command.arg(format!("--depth={d}"));
         }
+        if let Some(MillisSinceEpoch(millis)) = shallow_since {
+            // "@<seconds> <offset>" is parsed as a raw timestamp by Git.
+            command.arg(format!(
+                "--shallow-since=@{seconds} +0000",
+                seconds = millis.div_euclid(1000)
+            ));
+        }
         match fetch_tags_override {
             Some(FetchTagsOverride::AllTags) => {
                 command.arg("--tags");
@@ -201,7 +238,7 @@ impl<'a> GitSubprocessContext<'a> {
                 .chain(negative_refspecs.iter().map(|x| x.to_git_format())),
         );
 
-        let output = wait_with_progress(self.spawn_cmd(command)?, callbacks)?;
+        let output = wait_with_progress(self.spawn_cmd(command)?, callbacks, timeout)?;
 
         parse_git_fetch_output(output)
     }
@@ -255,6 +292,9 @@ impl<'a> GitSubprocessContext<'a> {
     /// All pushes are forced, using --force-with-lease to perform a test&set
     /// operation on the remote repository
     ///
+    /// If `dry_run` is set, the remote is still consulted to check the leases,
+    /// but nothing is sent to it.
+    ///
     /// Return tuple with
     ///     1. refs that failed to push
     ///     2. refs that succeeded to push
@@ -262,6 +302,7 @@ impl<'a> GitSubprocessContext<'a> {
         &self,
         remote_name: &RemoteName,
         references: &[RefToPush],
+        dry_run: bool,
         callbacks: &mut RemoteCallbacks<'_>,
     ) -> Result<GitPushStats, GitSubprocessError> {
         let mut command = self.create_command();
@@ -272,6 +313,9 @@ impl<'a> GitSubprocessContext<'a> {
         // https://github.com/jj-vcs/jj/issues/3577 and https://github.com/jj-vcs/jj/issues/405
         // offer more context
         command.args(["push", "--porcelain", "--no-verify"]);
+        if dry_run {
+            command.arg("--dry-run");
+        }
         if callbacks.progress.is_some() {
             command.arg("--progress");
         }
@@ -289,7 +333,7 @@ impl<'a> GitSubprocessContext<'a> {
                 .map(|r| r.refspec.to_git_format_not_forced()),
         );
 
-        let output = wait_with_progress(self.spawn_cmd(command)?, callbacks)?;
+        let output = wait_with_progress(self.spawn_cmd(command)?, callbacks, None)?;
 
         parse_git_push_output(output)
     }
@@ -325,6 +369,33 @@ fn parse_no_such_remote(stderr: &[u8]) -> Option<String> {
         .map(|remote| remote.to_str_lossy().into_owned())
 }
 
+/// Parse errors from failing to connect to the remote, or losing the
+/// connection
+///
+/// Returns the first line describing the failure
+///
+/// Depending on the transport, git prints e.g.
+/// `fatal: unable to access '<url>': Could not resolve host: <host>`
+/// or
+/// `<host>[0: <address>]: errno=Connection refused`
+/// or
+/// `fatal: early EOF`
+fn parse_connection_error(stderr: &[u8]) -> Option<String> {
+    const MESSAGES: [&str; 7] = [
+        "Could not resolve host",
+        "unable to look up",
+        "Failed to connect to",
+        "Connection refused",
+        "Connection timed out",
+        "Connection reset",
+        "early EOF",
+    ];
+    stderr
+        .lines()
+        .find(|line| MESSAGES.iter().any(|message| line.contains_str(message)))
+        .map(|line| line.to_str_lossy().into_owned())
+}
+
 /// Parse error from refspec not present on the remote
 ///
 /// This returns
@@ -399,6 +470,10 @@ fn parse_git_fetch_output(output: Output) -> Result<Option<String>, GitSubproces
         return Err(GitSubprocessError::NoSuchRepository(remote));
     }
 
+    if let Some(message) = parse_connection_error(&output.stderr) {
+        return Err(GitSubprocessError::Connection(message));
+    }
+
     if let Some(refspec) = parse_no_remote_ref(&output.stderr) {
         return Ok(Some(refspec));
     }
@@ -441,6 +516,10 @@ fn parse_git_remote_show_output(output: Output) -> Result<Output, GitSubprocessE
         return Err(GitSubprocessError::NoSuchRepository(remote));
     }
 
+    if let Some(message) = parse_connection_error(&output.stderr) {
+        return Err(GitSubprocessError::Connection(message));
+    }
+
     Err(external_git_error(&output.stderr))
 }
 
@@ -576,6 +655,10 @@ fn parse_git_push_output(output: Output) -> Result<GitPushStats, GitSubprocessEr
         return Err(GitSubprocessError::NoSuchRepository(remote));
     }
 
+    if let Some(message) = parse_connection_error(&output.stderr) {
+        return Err(GitSubprocessError::Connection(message));
+    }
+
     if output
         .stderr
         .lines()
@@ -608,10 +691,16 @@ fn wait_with_output(child: Child) -> Result<Output, GitSubprocessError> {
 /// ```
 ///
 /// The returned `stderr` content does not include sideband messages.
+///
+/// If `timeout` is specified, the process is killed once it expires.
 fn wait_with_progress(
     mut child: Child,
     callbacks: &mut RemoteCallbacks<'_>,
+    timeout: Option<Duration>,
 ) -> Result<Output, GitSubprocessError> {
+    if let Some(timeout) = timeout {
+        return wait_with_progress_and_timeout(child, callbacks, timeout);
+    }
     let (stdout, stderr) = thread::scope(|s| -> io::Result<_> {
         drop(child.stdin.take());
         let mut child_stdout = child.stdout.take().expect("stdout should be piped");
@@ -634,6 +723,106 @@ fn wait_with_progress(
     })
 }
 
+/// Like [`wait_with_progress()`], but kills the process if it doesn't finish
+/// within the `timeout`.
+///
+/// The pipes are read by detached threads because they may be kept open by the
+/// remote helpers spawned by git even after git itself is killed.
+fn wait_with_progress_and_timeout(
+    mut child: Child,
+    callbacks: &mut RemoteCallbacks<'_>,
+    timeout: Duration,
+) -> Result<Output, GitSubprocessError> {
+    let deadline = Instant::now() + timeout;
+    drop(child.stdin.take());
+    let mut child_stdout = child.stdout.take().expect("stdout should be piped");
+    let mut child_stderr = child.stderr.take().expect("stderr should be piped");
+    let (stdout_tx, stdout_rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        let result = child_stdout.read_to_end(&mut buf).map(|_| buf);
+        stdout_tx.send(result).ok();
+    });
+    let (stderr_tx, stderr_rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut buf = [0; 8192];
+        loop {
+            let result = match child_stderr.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => Ok(buf[..n].to_vec()),
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => Err(err),
+            };
+            let is_err = result.is_err();
+            if stderr_tx.send(result).is_err() || is_err {
+                break;
+            }
+        }
+    });
+
+    let stderr_reader = ChannelReader {
+        rx: stderr_rx,
+        deadline,
+        chunk: vec![],
+        pos: 0,
+    };
+    let result = read_to_end_with_progress(stderr_reader, callbacks).and_then(|stderr| {
+        match stdout_rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok(stdout) => Ok((stdout?, stderr)),
+            Err(mpsc::RecvTimeoutError::Timeout) => Err(io::ErrorKind::TimedOut.into()),
+            Err(mpsc::RecvTimeoutError::Disconnected) => panic!("reader thread wouldn't panic"),
+        }
+    });
+    let (stdout, stderr) = match result {
+        Ok(output) => output,
+        Err(err) if err.kind() == io::ErrorKind::TimedOut => {
+            // Only the git process is killed. Its helper processes should exit
+            // once their pipes to git are closed.
+            if let Err(err) = child.kill() {
+                tracing::warn!(?err, "failed to kill git subprocess");
+            }
+            child.wait().map_err(GitSubprocessError::Wait)?;
+            return Err(GitSubprocessError::Timeout(timeout));
+        }
+        Err(err) => return Err(GitSubprocessError::Wait(err)),
+    };
+    let status = child.wait().map_err(GitSubprocessError::Wait)?;
+    Ok(Output {
+        status,
+        stdout,
+        stderr,
+    })
+}
+
+/// Reader of the chunks sent by a reader thread, which fails with
+/// [`io::ErrorKind::TimedOut`] once the `deadline` passes.
+struct ChannelReader {
+    rx: mpsc::Receiver<io::Result<Vec<u8>>>,
+    deadline: Instant,
+    chunk: Vec<u8>,
+    pos: usize,
+}
+
+impl Read for ChannelReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pos == self.chunk.len() {
+            let timeout = self.deadline.saturating_duration_since(Instant::now());
+            match self.rx.recv_timeout(timeout) {
+                Ok(chunk) => {
+                    self.chunk = chunk?;
+                    self.pos = 0;
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => return Err(io::ErrorKind::TimedOut.into()),
+                Err(mpsc::RecvTimeoutError::Disconnected) => return Ok(0),
+            }
+        }
+        let len = buf.len().min(self.chunk.len() - self.pos);
+        buf[..len].copy_from_slice(&self.chunk[self.pos..self.pos + len]);
+        self.pos += len;
+        Ok(len)
+    }
+}
+
 #[derive(Default)]
 struct GitProgress {
     // (frac, total)
@@ -794,6 +983,14 @@ fatal: Could not read from remote repository.
 Please make sure you have the correct access rights
 and the repository exists. "###;
     const SAMPLE_NO_REMOTE_REF_ERROR: &[u8] = b"fatal: couldn't find remote ref refs/heads/noexist";
+    const SAMPLE_CONNECTION_REFUSED_ERROR: &[u8] = b"fatal: unable to connect to 127.0.0.1:
+127.0.0.1[0: 127.0.0.1]: errno=Connection refused
+";
+    const SAMPLE_EARLY_EOF_ERROR: &[u8] =
+        b"fetch-pack: unexpected disconnect while reading sideband packet
+fatal: early EOF
+fatal: fetch-pack: invalid index-pack output
+";
     const SAMPLE_NO_REMOTE_TRACKING_BRANCH_ERROR: &[u8] =
         b"error: remote-tracking branch 'bookmark' not found";
     const SAMPLE_PUSH_REFS_PORCELAIN_OUTPUT: &[u8] = b"To origin
@@ -831,6 +1028,25 @@ Done";
         assert_eq!(parse_no_such_remote(SAMPLE_OK_STDERR), None);
     }
 
+    #[test]
+    fn test_parse_connection_error() {
+        assert_eq!(
+            parse_connection_error(SAMPLE_CONNECTION_REFUSED_ERROR),
+            Some("127.0.0.1[0: 127.0.0.1]: errno=Connection refused".to_string())
+        );
+        assert_eq!(
+            parse_connection_error(SAMPLE_EARLY_EOF_ERROR),
+            Some("fatal: early EOF".to_string())
+        );
+        assert_eq!(parse_connection_error(SAMPLE_NO_SUCH_REMOTE_ERROR), None);
+        assert_eq!(parse_connection_error(SAMPLE_NO_REMOTE_REF_ERROR), None);
+        assert_eq!(
+            parse_connection_error(SAMPLE_PUSH_REFS_PORCELAIN_OUTPUT),
+            None
+        );
+        assert_eq!(parse_connection_error(SAMPLE_OK_STDERR), None);
+    }
+
     #[test]
     fn test_parse_no_remote_ref() {
         assert_eq!(parse_no_remote_ref(SAMPLE_NO_SUCH_REPOSITORY_ERROR), None);
@@ -35,6 +35,9 @@ use std::path::PathBuf;
 use std::slice;
 use std::sync::Arc;
 use std::sync::OnceLock;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering as AtomicOrdering;
 use std::sync::mpsc::Sender;
 use std::sync::mpsc::channel;
 use std::time::UNIX_EPOCH;
@@ -1111,39 +1114,46 @@ impl TreeState {
         let (untracked_paths_tx, untracked_paths_rx) = channel();
         let (deleted_files_tx, deleted_files_rx) = channel();
 
-        trace_span!("traverse filesystem").in_scope(|| -> Result<(), SnapshotError> {
-            let snapshotter = FileSnapshotter {
-                tree_state: self,
-                current_tree: &self.tree,
-                matcher: &matcher,
-                start_tracking_matcher,
-                force_tracking_matcher,
-                // Move tx sides so they'll be dropped at the end of the scope.
-                tree_entries_tx,
-                file_states_tx,
-                untracked_paths_tx,
-                deleted_files_tx,
-                error: OnceLock::new(),
-                progress,
-                max_new_file_size,
-            };
-            let directory_to_visit = DirectoryToVisit {
-                dir: RepoPathBuf::root(),
-                disk_dir: self.working_copy_path.clone(),
-                git_ignore: base_ignores.clone(),
-                file_states: self.file_states.all(),
-            };
-            // Here we use scope as a queue of per-directory jobs.
-            rayon::scope(|scope| {
-                snapshotter.spawn_ok(scope, |scope| {
-                    snapshotter.visit_directory(directory_to_visit, scope)
+        let (scanned_files, hashed_files, hashed_bytes) = trace_span!("traverse filesystem")
+            .in_scope(|| -> Result<_, SnapshotError> {
+                let snapshotter = FileSnapshotter {
+                    tree_state: self,
+                    current_tree: &self.tree,
+                    matcher: &matcher,
+                    start_tracking_matcher,
+                    force_tracking_matcher,
+                    // Move tx sides so they'll be dropped at the end of the scope.
+                    tree_entries_tx,
+                    file_states_tx,
+                    untracked_paths_tx,
+                    deleted_files_tx,
+                    error: OnceLock::new(),
+                    progress,
+                    max_new_file_size,
+                    scanned_files: AtomicUsize::new(0),
+                    hashed_files: AtomicUsize::new(0),
+                    hashed_bytes: AtomicU64::new(0),
+                };
+                let directory_to_visit = DirectoryToVisit {
+                    dir: RepoPathBuf::root(),
+                    disk_dir: self.working_copy_path.clone(),
+                    git_ignore: base_ignores.clone(),
+                    file_states: self.file_states.all(),
+                };
+                // Here we use scope as a queue of per-directory jobs.
+                rayon::scope(|scope| {
+                    snapshotter.spawn_ok(scope, |scope| {
+                        snapshotter.visit_directory(directory_to_visit, scope)
+                    });
                 });
-            });
-            snapshotter.into_result()
-        })?;
+                snapshotter.into_result()
+            })?;
 
         let stats = SnapshotStats {
             untracked_paths: untracked_paths_rx.into_iter().collect(),
+            scanned_files,
+            hashed_files,
+            hashed_bytes,
         };
         let mut tree_builder = MergedTreeBuilder::new(self.tree.clone());
         trace_span!("process tree entries").in_scope(|| {
@@ -1274,6 +1284,9 @@ struct FileSnapshotter<'a> {
     error: OnceLock<SnapshotError>,
     progress: Option<&'a SnapshotProgress<'a>>,
     max_new_file_size: u64,
+    scanned_files: AtomicUsize,
+    hashed_files: AtomicUsize,
+    hashed_bytes: AtomicU64,
 }
 
 impl FileSnapshotter<'_> {
@@ -1292,11 +1305,16 @@ impl FileSnapshotter<'_> {
         });
     }
 
-    /// Extracts the result of the snapshot.
-    fn into_result(self) -> Result<(), SnapshotError> {
+    /// Extracts the result of the snapshot, and the numbers of scanned files,
+    /// hashed files, and hashed bytes.
+    fn into_result(self) -> Result<(usize, usize, u64), SnapshotError> {
         match self.error.into_inner() {
             Some(err) => Err(err),
-            None => Ok(()),
+            None => Ok((
+                self.scanned_files.into_inner(),
+                self.hashed_files.into_inner(),
+                self.hashed_bytes.into_inner(),
+            )),
         }
     }
 
@@ -1488,6 +1506,7 @@ impl FileSnapshotter<'_> {
         maybe_current_file_state: Option<&FileState>,
         mut new_file_state: FileState,
     ) -> Result<(), SnapshotError> {
+        self.scanned_files.fetch_add(1, AtomicOrdering::Relaxed);
         let update = self.get_updated_tree_value(
             &path,
             disk_path,
@@ -1577,6 +1596,11 @@ impl FileSnapshotter<'_> {
             } else {
                 new_file_state.file_type.clone()
             };
+            if let FileType::Normal { .. } = new_file_type {
+                self.hashed_files.fetch_add(1, AtomicOrdering::Relaxed);
+                self.hashed_bytes
+                    .fetch_add(new_file_state.size, AtomicOrdering::Relaxed);
+            }
             let new_tree_values = match new_file_type {
                 FileType::Normal { executable } => self
                     .write_path_to_store(
@@ -31,6 +31,7 @@ use crate::backend::CommitId;
 use crate::backend::MillisSinceEpoch;
 use crate::backend::Timestamp;
 use crate::content_hash::ContentHash;
+use crate::content_hash::DigestUpdate;
 use crate::merge::Merge;
 use crate::object_id::HexPrefix;
 use crate::object_id::ObjectId as _;
@@ -280,7 +281,7 @@ impl View {
 }
 
 /// Represents the state of the remote repo.
-#[derive(ContentHash, Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct RemoteView {
     // TODO: Do we need to support tombstones for remote bookmarks? For example, if the bookmark
     // has been deleted locally and you pull from a remote, maybe it should make a difference
@@ -288,6 +289,25 @@ pub struct RemoteView {
     // the bookmark if the bookmark's state on the remote was just not known.
     pub bookmarks: BTreeMap<RefNameBuf, RemoteRef>,
     pub tags: BTreeMap<RefNameBuf, RemoteRef>,
+    /// Map of new bookmark names to old names, for bookmarks renamed locally
+    /// that should also be renamed on the remote by the next push.
+    pub pending_bookmark_renames: BTreeMap<RefNameBuf, RefNameBuf>,
+}
+
+impl ContentHash for RemoteView {
+    fn hash(&self, state: &mut impl DigestUpdate) {
+        let Self {
+            bookmarks,
+            tags,
+            pending_bookmark_renames,
+        } = self;
+        bookmarks.hash(state);
+        tags.hash(state);
+        // Not hashed if empty so that the ids of existing views are preserved.
+        if !pending_bookmark_renames.is_empty() {
+            pending_bookmark_renames.hash(state);
+        }
+    }
 }
 
 /// Iterates pair of local and remote refs by name.
@@ -527,18 +547,21 @@ mod tests {
                     "bookmark2".into() => git_bookmark2_remote_ref.clone(),
                 },
                 tags: btreemap! {},
+                pending_bookmark_renames: btreemap! {},
             },
             "remote1".into() => RemoteView {
                 bookmarks: btreemap! {
                     "bookmark1".into() => remote1_bookmark1_remote_ref.clone(),
                 },
                 tags: btreemap! {},
+                pending_bookmark_renames: btreemap! {},
             },
             "remote2".into() => RemoteView {
                 bookmarks: btreemap! {
                     "bookmark2".into() => remote2_bookmark2_remote_ref.clone(),
                 },
                 tags: btreemap! {},
+                pending_bookmark_renames: btreemap! {},
             },
         };
         assert_eq!(
@@ -593,6 +616,7 @@ mod tests {
                     "bookmark1".into() => remote1_bookmark1_remote_ref.clone(),
                 },
                 tags: btreemap! {},
+                pending_bookmark_renames: btreemap! {},
             },
         };
         assert_eq!(
@@ -146,6 +146,16 @@ pub struct RemoteView {
     pub bookmarks: ::prost::alloc::vec::Vec<RemoteRef>,
     #[prost(message, repeated, tag = "3")]
     pub tags: ::prost::alloc::vec::Vec<RemoteRef>,
+    /// Bookmarks to be renamed on the remote by the next push.
+    #[prost(message, repeated, tag = "4")]
+    pub pending_bookmark_renames: ::prost::alloc::vec::Vec<PendingBookmarkRename>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingBookmarkRename {
+    #[prost(string, tag = "1")]
+    pub new_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub old_name: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Operation {
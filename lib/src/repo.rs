@@ -1726,6 +1726,18 @@ impl MutableRepo {
         Ok(())
     }
 
+    /// Records that the remote bookmark `symbol` should be created by renaming
+    /// `old_name` on the remote by the next push. If `old_name` is `None`, the
+    /// pending rename will be removed.
+    pub fn set_pending_bookmark_rename(
+        &mut self,
+        symbol: RemoteRefSymbol<'_>,
+        old_name: Option<&RefName>,
+    ) {
+        self.view_mut()
+            .set_pending_bookmark_rename(symbol, old_name);
+    }
+
     /// Stops tracking the specified remote bookmark.
     pub fn untrack_remote_bookmark(&mut self, symbol: RemoteRefSymbol<'_>) {
         let mut remote_ref = self.get_remote_bookmark(symbol);
@@ -1904,6 +1916,28 @@ impl MutableRepo {
             self.merge_remote_tag(symbol, base_ref, other_ref)?;
         }
 
+        // Pending renames changed by the other side take precedence.
+        let remote_names: HashSet<&RemoteName> = itertools::chain(
+            base.store_view().remote_views.keys(),
+            other.store_view().remote_views.keys(),
+        )
+        .map(|name| name.as_ref())
+        .collect();
+        for remote in remote_names {
+            let base_renames: BTreeMap<_, _> = base.pending_bookmark_renames(remote).collect();
+            let other_renames: BTreeMap<_, _> = other.pending_bookmark_renames(remote).collect();
+            let names: HashSet<&RefName> =
+                itertools::chain(base_renames.keys(), other_renames.keys())
+                    .copied()
+                    .collect();
+            for name in names {
+                let other_old_name = other_renames.get(name).copied();
+                if base_renames.get(name).copied() != other_old_name {
+                    self.set_pending_bookmark_rename(name.to_remote_symbol(remote), other_old_name);
+                }
+            }
+        }
+
         let new_git_head_target = merge_ref_targets(
             self.index(),
             self.view().git_head(),
@@ -152,6 +152,7 @@ pub enum RevsetCommitRef {
     ChangeId(HexPrefix),
     CommitId(HexPrefix),
     Bookmarks(StringExpression),
+    ConflictedBookmarks,
     RemoteBookmarks {
         bookmark: StringExpression,
         remote: StringExpression,
@@ -411,6 +412,10 @@ impl<St: ExpressionState<CommitRef = RevsetCommitRef>> RevsetExpression<St> {
         Arc::new(Self::CommitRef(RevsetCommitRef::Bookmarks(expression)))
     }
 
+    pub fn conflicted_bookmarks() -> Arc<Self> {
+        Arc::new(Self::CommitRef(RevsetCommitRef::ConflictedBookmarks))
+    }
+
     pub fn remote_bookmarks(
         bookmark: StringExpression,
         remote: StringExpression,
@@ -889,14 +894,26 @@ static BUILTIN_FUNCTION_MAP: LazyLock<HashMap<&str, RevsetFunction>> = LazyLock:
         Ok(RevsetExpression::commit_id_prefix(prefix))
     });
     map.insert("bookmarks", |diagnostics, function, _context| {
-        let ([], [opt_arg]) = function.expect_arguments()?;
+        let ([], [opt_arg, remote_opt_arg]) = function.expect_named_arguments(&["", "remote"])?;
         let expr = if let Some(arg) = opt_arg {
             expect_string_expression(diagnostics, arg)?
         } else {
             StringExpression::all()
         };
-        Ok(RevsetExpression::bookmarks(expr))
+        if let Some(remote_arg) = remote_opt_arg {
+            let remote_expr = expect_string_expression(diagnostics, remote_arg)?;
+            Ok(RevsetExpression::remote_bookmarks(expr, remote_expr, None))
+        } else {
+            Ok(RevsetExpression::bookmarks(expr))
+        }
     });
+    map.insert(
+        "conflicted_bookmarks",
+        |_diagnostics, function, _context| {
+            function.expect_no_arguments()?;
+            Ok(RevsetExpression::conflicted_bookmarks())
+        },
+    );
     map.insert("remote_bookmarks", |diagnostics, function, context| {
         parse_remote_bookmarks_arguments(
             diagnostics,
@@ -1060,7 +1077,7 @@ static BUILTIN_FUNCTION_MAP: LazyLock<HashMap<&str, RevsetFunction>> = LazyLock:
         function.expect_no_arguments()?;
         Ok(RevsetExpression::is_empty())
     });
-    map.insert("files", |diagnostics, function, context| {
+    let files_function: RevsetFunction = |diagnostics, function, context| {
         let ctx = context.workspace.as_ref().ok_or_else(|| {
             RevsetParseError::with_span(
                 RevsetParseErrorKind::FsPathWithoutWorkspace,
@@ -1070,7 +1087,9 @@ static BUILTIN_FUNCTION_MAP: LazyLock<HashMap<&str, RevsetFunction>> = LazyLock:
         let [arg] = function.expect_exact_arguments()?;
         let expr = expect_fileset_expression(diagnostics, arg, ctx.path_converter)?;
         Ok(RevsetExpression::filter(RevsetFilterPredicate::File(expr)))
-    });
+    };
+    map.insert("files", files_function);
+    map.insert("touches", files_function);
     map.insert("diff_contains", |diagnostics, function, context| {
         let ([text_arg], [files_opt_arg]) = function.expect_arguments()?;
         let text = expect_string_expression(diagnostics, text_arg)?;
@@ -2882,6 +2901,16 @@ fn resolve_commit_ref(
                 .collect();
             Ok(commit_ids)
         }
+        RevsetCommitRef::ConflictedBookmarks => {
+            let commit_ids = repo
+                .view()
+                .local_bookmarks()
+                .filter(|(_, target)| target.has_conflict())
+                .flat_map(|(_, target)| target.added_ids())
+                .cloned()
+                .collect();
+            Ok(commit_ids)
+        }
         RevsetCommitRef::RemoteBookmarks {
             bookmark,
             remote,
@@ -3806,6 +3835,9 @@ mod tests {
         insta::assert_debug_snapshot!(
             parse("bookmarks()").unwrap(),
             @r#"CommitRef(Bookmarks(Pattern(Substring(""))))"#);
+        insta::assert_debug_snapshot!(
+            parse("conflicted_bookmarks()").unwrap(),
+            @"CommitRef(ConflictedBookmarks)");
         // Default argument for tags() is ""
         insta::assert_debug_snapshot!(
             parse("tags()").unwrap(),
@@ -4264,6 +4296,16 @@ mod tests {
             },
         )
         "#);
+        insta::assert_debug_snapshot!(
+            parse("bookmarks(foo, remote=bar)").unwrap(), @r#"
+        CommitRef(
+            RemoteBookmarks {
+                bookmark: Pattern(Substring("foo")),
+                remote: Pattern(Substring("bar")),
+                remote_ref_state: None,
+            },
+        )
+        "#);
         insta::assert_debug_snapshot!(
             parse("tracked_remote_bookmarks(foo, remote=bar)").unwrap(), @r#"
         CommitRef(
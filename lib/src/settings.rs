@@ -14,10 +14,12 @@
 
 #![expect(missing_docs)]
 
+use std::num::NonZeroU32;
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Duration;
 
 use chrono::DateTime;
 use rand::prelude::*;
@@ -63,6 +65,11 @@ pub struct GitSettings {
     pub executable_path: PathBuf,
     pub write_change_id_header: bool,
     pub colocate: bool,
+    /// Maximum time to spend fetching from a single remote.
+    pub fetch_timeout: Option<Duration>,
+    /// Number of threads Git uses to index the objects received from a single
+    /// remote. Uses Git's default if unset.
+    pub fetch_jobs: Option<NonZeroU32>,
 }
 
 impl GitSettings {
@@ -73,6 +80,11 @@ impl GitSettings {
             executable_path: settings.get("git.executable-path")?,
             write_change_id_header: settings.get("git.write-change-id-header")?,
             colocate: settings.get("git.colocate")?,
+            fetch_timeout: settings
+                .get::<u64>("git.fetch-timeout")
+                .optional()?
+                .map(Duration::from_secs),
+            fetch_jobs: None,
         })
     }
 }
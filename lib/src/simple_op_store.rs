@@ -758,6 +758,16 @@ fn remote_views_to_proto(
             name: name.into(),
             bookmarks: remote_refs_to_proto(&view.bookmarks),
             tags: remote_refs_to_proto(&view.tags),
+            pending_bookmark_renames: view
+                .pending_bookmark_renames
+                .iter()
+                .map(
+                    |(new_name, old_name)| crate::protos::simple_op_store::PendingBookmarkRename {
+                        new_name: new_name.into(),
+                        old_name: old_name.into(),
+                    },
+                )
+                .collect(),
         })
         .collect()
 }
@@ -772,6 +782,11 @@ fn remote_views_from_proto(
             let view = RemoteView {
                 bookmarks: remote_refs_from_proto(proto.bookmarks)?,
                 tags: remote_refs_from_proto(proto.tags)?,
+                pending_bookmark_renames: proto
+                    .pending_bookmark_renames
+                    .into_iter()
+                    .map(|rename| (rename.new_name.into(), rename.old_name.into()))
+                    .collect(),
             };
             Ok((name, view))
         })
@@ -988,6 +1003,7 @@ mod tests {
                         "v1.0".into() => tracked_remote_ref(&tag_v1_origin_target),
                         "deleted".into() => new_remote_ref(&tag_deleted_origin_target),
                     },
+                    pending_bookmark_renames: btreemap! {},
                 },
             },
             git_refs: btreemap! {
@@ -1135,6 +1151,7 @@ mod tests {
                     "main".into() => tracked_remote_ref(&main_target),
                 },
                 tags: btreemap! {},
+                pending_bookmark_renames: btreemap! {},
             },
         };
         let proto = crate::protos::simple_op_store::View {
@@ -1159,6 +1176,7 @@ mod tests {
                         tags: btreemap! {
                             "v1.0".into() => tracked_remote_ref(&v1_target),
                         },
+                        pending_bookmark_renames: btreemap! {},
                     },
                 }
             );
@@ -1202,12 +1220,14 @@ mod tests {
                     "bookmark1".into() => tracked_remote_ref(&git_bookmark1_target),
                 },
                 tags: btreemap! {},
+                pending_bookmark_renames: btreemap! {},
             },
             "remote1".into() => RemoteView {
                 bookmarks: btreemap! {
                     "bookmark1".into() => tracked_remote_ref(&remote1_bookmark1_target),
                 },
                 tags: btreemap! {},
+                pending_bookmark_renames: btreemap! {},
             },
             "remote2".into() => RemoteView {
                 bookmarks: btreemap! {
@@ -1216,6 +1236,7 @@ mod tests {
                     "bookmark4".into() => tracked_remote_ref(&remote2_bookmark4_target),
                 },
                 tags: btreemap! {},
+                pending_bookmark_renames: btreemap! {},
             },
         };
 
@@ -259,6 +259,46 @@ impl View {
         }
     }
 
+    /// Iterates over `(new_name, old_name)`s of the bookmarks to be renamed on
+    /// the specified remote by the next push, in lexicographical order.
+    pub fn pending_bookmark_renames(
+        &self,
+        remote_name: &RemoteName,
+    ) -> impl Iterator<Item = (&RefName, &RefName)> + use<'_> {
+        let maybe_remote_view = self.data.remote_views.get(remote_name);
+        maybe_remote_view
+            .map(|remote_view| {
+                remote_view
+                    .pending_bookmark_renames
+                    .iter()
+                    .map(|(new_name, old_name)| (new_name.as_ref(), old_name.as_ref()))
+            })
+            .into_iter()
+            .flatten()
+    }
+
+    /// Records that the remote bookmark `symbol` should be created by renaming
+    /// `old_name` on the remote. If `old_name` is `None`, the pending rename
+    /// will be removed.
+    pub fn set_pending_bookmark_rename(
+        &mut self,
+        symbol: RemoteRefSymbol<'_>,
+        old_name: Option<&RefName>,
+    ) {
+        if let Some(old_name) = old_name {
+            let remote_view = self
+                .data
+                .remote_views
+                .entry(symbol.remote.to_owned())
+                .or_default();
+            remote_view
+                .pending_bookmark_renames
+                .insert(symbol.name.to_owned(), old_name.to_owned());
+        } else if let Some(remote_view) = self.data.remote_views.get_mut(symbol.remote) {
+            remote_view.pending_bookmark_renames.remove(symbol.name);
+        }
+    }
+
     /// Iterates over `(name, {local_ref, remote_ref})`s for every bookmark
     /// present locally and/or on the specified remote, in lexicographical
     /// order.
@@ -530,7 +570,11 @@ impl View {
             local_bookmarks.values().flat_map(ref_target_ids),
             local_tags.values().flat_map(ref_target_ids),
             remote_views.values().flat_map(|remote_view| {
-                let op_store::RemoteView { bookmarks, tags } = remote_view;
+                let op_store::RemoteView {
+                    bookmarks,
+                    tags,
+                    pending_bookmark_renames: _,
+                } = remote_view;
                 itertools::chain(bookmarks.values(), tags.values())
                     .flat_map(|remote_ref| ref_target_ids(&remote_ref.target))
             }),
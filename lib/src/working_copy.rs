@@ -240,6 +240,13 @@ pub type SnapshotProgress<'a> = dyn Fn(&RepoPath) + 'a + Sync;
 pub struct SnapshotStats {
     /// List of new (previously untracked) files which are still untracked.
     pub untracked_paths: BTreeMap<RepoPathBuf, UntrackedReason>,
+    /// Number of present files which were scanned.
+    pub scanned_files: usize,
+    /// Number of files whose contents were read and hashed because they may
+    /// have changed.
+    pub hashed_files: usize,
+    /// Total size in bytes of the files whose contents were hashed.
+    pub hashed_bytes: u64,
 }
 
 /// Reason why the new path isn't tracked.
@@ -153,6 +153,7 @@ fn git_fetch(
         fetch_refspecs,
         git::RemoteCallbacks::default(),
         None,
+        None,
         fetch_tags_override,
     )?;
     let default_branch = git_fetch.get_default_branch(remote_name)?;
@@ -4946,7 +4947,7 @@ fn test_remote_rename_refs() {
     let commit_foobar_a = empty_git_commit(&git_repo, "refs/remotes/foobar/a", &[]);
 
     let mut tx = repo.start_transaction();
-    git::rename_remote(tx.repo_mut(), "foo".as_ref(), "bar".as_ref()).unwrap();
+    git::rename_remote(tx.repo_mut(), "foo".as_ref(), "bar".as_ref(), true).unwrap();
     let repo = &tx.commit("rename").unwrap();
 
     let git_repo = get_git_repo(repo);
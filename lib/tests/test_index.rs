@@ -700,6 +700,35 @@ fn test_reindex_corrupt_segment_files() {
 
     let repo = test_env.load_repo_at_head(&settings, test_repo.repo_path());
     assert!(index_has_id(repo.index(), commit_a.id()));
+    assert_eq!(as_readonly_index(&repo).stats().num_reindexed_segments, 1);
+    assert!(as_readonly_index(&repo).is_freshly_reindexed());
+
+    // The rebuilt index is loaded as usual, but remembers why it was rebuilt
+    let repo = test_env.load_repo_at_head(&settings, test_repo.repo_path());
+    assert!(index_has_id(repo.index(), commit_a.id()));
+    assert_eq!(as_readonly_index(&repo).stats().num_reindexed_segments, 1);
+    assert!(!as_readonly_index(&repo).is_freshly_reindexed());
+}
+
+#[test]
+fn test_reindex_corrupt_operation_link_file() {
+    let settings = testutils::user_settings();
+    let test_repo = TestRepo::init();
+    let test_env = &test_repo.env;
+    let repo = &test_repo.repo;
+
+    let mut tx = repo.start_transaction();
+    let commit_a = write_random_commit(tx.repo_mut());
+    let repo = tx.commit("test").unwrap();
+    assert!(index_has_id(repo.index(), commit_a.id()));
+
+    // Corrupt the operation link file
+    let op_links_dir = test_repo.repo_path().join("index").join("op_links");
+    fs::write(op_links_dir.join(repo.op_id().hex()), b"\xff").unwrap();
+
+    let repo = test_env.load_repo_at_head(&settings, test_repo.repo_path());
+    assert!(index_has_id(repo.index(), commit_a.id()));
+    assert_eq!(as_readonly_index(&repo).stats().num_reindexed_segments, 1);
 }
 
 #[test]
@@ -750,6 +779,36 @@ fn test_reindex_from_merged_operation() {
     let repo = repo.reload_at(operation_to_reload).unwrap();
     let index = as_readonly_index(&repo);
     assert_eq!(index.num_commits(), 4);
+    assert_eq!(index.num_reindexed_segments(), 2);
+}
+
+#[test]
+fn test_reindex_records_operation() {
+    let test_repo = TestRepo::init();
+    let repo = &test_repo.repo;
+
+    let mut tx = repo.start_transaction();
+    write_random_commit(tx.repo_mut());
+    let repo = tx.commit("test").unwrap();
+    let first_op_id = repo.op_id().clone();
+    let mut tx = repo.start_transaction();
+    write_random_commit(tx.repo_mut());
+    let repo = tx.commit("test").unwrap();
+    let second_op_id = repo.op_id().clone();
+    let default_index_store: &DefaultIndexStore = repo.index_store().downcast_ref().unwrap();
+    assert!(!default_index_store.is_reindexed_at_operation(&first_op_id));
+    assert!(!default_index_store.is_reindexed_at_operation(&second_op_id));
+
+    let op_links_dir = test_repo.repo_path().join("index").join("op_links");
+    let legacy_operations_dir = test_repo.repo_path().join("index").join("operations");
+    fs::remove_file(op_links_dir.join(second_op_id.hex())).unwrap();
+    fs::remove_file(legacy_operations_dir.join(second_op_id.hex())).unwrap();
+
+    // Only the operation whose index was missing is recorded
+    let repo = repo.reload_at(repo.operation()).unwrap();
+    let default_index_store: &DefaultIndexStore = repo.index_store().downcast_ref().unwrap();
+    assert!(!default_index_store.is_reindexed_at_operation(&first_op_id));
+    assert!(default_index_store.is_reindexed_at_operation(&second_op_id));
 }
 
 #[test]
@@ -2544,6 +2544,62 @@ fn test_evaluate_expression_bookmarks() {
     );
 }
 
+#[test]
+fn test_evaluate_expression_conflicted_bookmarks() {
+    let test_repo = TestRepo::init();
+    let repo = &test_repo.repo;
+
+    let mut tx = repo.start_transaction();
+    let mut_repo = tx.repo_mut();
+
+    let commit1 = write_random_commit(mut_repo);
+    let commit2 = write_random_commit(mut_repo);
+    let commit3 = write_random_commit(mut_repo);
+    let commit4 = write_random_commit(mut_repo);
+
+    // Can get conflicted bookmarks when there are none
+    assert_eq!(
+        resolve_commit_ids(mut_repo, "conflicted_bookmarks()"),
+        vec![]
+    );
+    // Non-conflicted bookmarks aren't included
+    mut_repo.set_local_bookmark_target(
+        "bookmark1".as_ref(),
+        RefTarget::normal(commit1.id().clone()),
+    );
+    assert_eq!(
+        resolve_commit_ids(mut_repo, "conflicted_bookmarks()"),
+        vec![]
+    );
+    // All added targets of a conflicted bookmark are included
+    mut_repo.set_local_bookmark_target(
+        "bookmark2".as_ref(),
+        RefTarget::from_legacy_form(
+            [commit1.id().clone()],
+            [commit2.id().clone(), commit3.id().clone()],
+        ),
+    );
+    assert_eq!(
+        resolve_commit_ids(mut_repo, "conflicted_bookmarks()"),
+        vec![commit3.id().clone(), commit2.id().clone()]
+    );
+    // Conflicted remote bookmarks aren't included
+    mut_repo.set_remote_bookmark(
+        remote_symbol("bookmark3", "origin"),
+        RemoteRef {
+            target: RefTarget::from_legacy_form(
+                [commit1.id().clone()],
+                [commit2.id().clone(), commit4.id().clone()],
+            ),
+            state: RemoteRefState::Tracked,
+        },
+    );
+    assert_eq!(
+        resolve_commit_ids(mut_repo, "conflicted_bookmarks()"),
+        vec![commit3.id().clone(), commit2.id().clone()]
+    );
+}
+
 #[test]
 fn test_evaluate_expression_remote_bookmarks() {
     let test_repo = TestRepo::init();
@@ -2644,6 +2700,26 @@ fn test_evaluate_expression_remote_bookmarks() {
             commit1.id().clone(),
         ]
     );
+    // bookmarks() can also select remote bookmarks by remote
+    assert_eq!(
+        resolve_commit_ids(mut_repo, "bookmarks(remote=exact:origin)"),
+        vec![commit1.id().clone()]
+    );
+    assert_eq!(
+        resolve_commit_ids(mut_repo, "bookmarks(remote=glob:'pri*')"),
+        vec![commit2.id().clone()]
+    );
+    assert_eq!(
+        resolve_commit_ids(mut_repo, "bookmarks(bookmark2, remote=glob:'*')"),
+        vec![commit2.id().clone()]
+    );
+    assert_eq!(
+        resolve_commit_ids(
+            mut_repo,
+            "remote_bookmarks(remote=exact:origin) ~ bookmarks()"
+        ),
+        vec![commit1.id().clone()]
+    );
     // Can filter bookmarks by tracked and untracked
     assert_eq!(
         resolve_commit_ids(mut_repo, "tracked_remote_bookmarks()"),
@@ -4359,6 +4435,30 @@ fn test_evaluate_expression_file(indexed: bool) {
         vec![commit2.id().clone()]
     );
 
+    // touches() is a synonym of files()
+    assert_eq!(
+        resolve_commit_ids_in_workspace(
+            mut_repo,
+            r#"touches("added_clean_clean"|"added_modified_clean")"#,
+            &test_workspace.workspace,
+            Some(test_workspace.workspace.workspace_root()),
+        ),
+        vec![commit2.id().clone(), commit1.id().clone()]
+    );
+    assert_eq!(
+        resolve_commit_ids_in_workspace(
+            mut_repo,
+            r#"touches(glob:"added_modified_*")"#,
+            &test_workspace.workspace,
+            Some(test_workspace.workspace.workspace_root()),
+        ),
+        vec![
+            commit3.id().clone(),
+            commit2.id().clone(),
+            commit1.id().clone()
+        ]
+    );
+
     // empty() revset, which is identical to ~file(".")
     assert_eq!(
         resolve_commit_ids(mut_repo, &format!("{}:: & empty()", commit1.id())),
@@ -4600,6 +4700,10 @@ fn test_evaluate_expression_file_merged_parents(indexed: bool) {
     let commit3 = create_commit(vec![commit1.id().clone()], tree3);
     let commit4 = create_commit(vec![commit2.id().clone(), commit3.id().clone()], tree4);
 
+    // New commits should be indexed incrementally if the index is enabled
+    let changed_paths = mut_repo.index().changed_paths_in_commit(commit4.id());
+    assert_eq!(changed_paths.unwrap().is_some(), indexed);
+
     let query = |revset_str: &str| {
         resolve_commit_ids_in_workspace(
             mut_repo,
@@ -4626,6 +4730,17 @@ fn test_evaluate_expression_file_merged_parents(indexed: bool) {
             commit1.id().clone(),
         ]
     );
+    assert_eq!(query("touches('file1')"), query("files('file1')"));
+    assert_eq!(query("touches('file2')"), query("files('file2')"));
+    assert_eq!(
+        query("touches('file1' | 'file2')"),
+        vec![
+            commit4.id().clone(),
+            commit3.id().clone(),
+            commit2.id().clone(),
+            commit1.id().clone(),
+        ]
+    );
 
     assert_eq!(
         query("diff_contains(regex:'[1234]', 'file1')"),
@@ -163,6 +163,26 @@ pub fn write_commit(
     .detach()
 }
 
+pub fn write_annotated_tag(
+    repo: &gix::Repository,
+    name: &str,
+    target: gix::ObjectId,
+    message: &str,
+) -> gix::ObjectId {
+    let signature = signature();
+    repo.tag(
+        name,
+        target,
+        gix::object::Kind::Commit,
+        Some(signature.to_ref(&mut TimeBuf::default())),
+        message,
+        gix::refs::transaction::PreviousValue::MustNotExist,
+    )
+    .unwrap()
+    .id()
+    .detach()
+}
+
 pub fn set_head_to_id(repo: &gix::Repository, target: gix::ObjectId) {
     repo.edit_reference(gix::refs::transaction::RefEdit {
         change: gix::refs::transaction::Change::Update {